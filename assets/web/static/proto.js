@@ -198,6 +198,10 @@ function updateCell(cellID, data, offset) {
                     // Mq
                     locStyle = 'loc mq';
                     break;
+                case 3:
+                    // Contradiction
+                    locStyle = 'loc contradiction';
+                    break;
                 default:
                     throw 'unexpected LocationStyle variant';
             }
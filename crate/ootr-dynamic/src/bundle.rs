@@ -0,0 +1,132 @@
+use {
+    std::{
+        collections::{
+            HashMap,
+            HashSet,
+        },
+        fmt,
+        fs::File,
+        io::{
+            self,
+            BufReader,
+        },
+        path::Path,
+        sync::Arc,
+    },
+    wheel::FromArc,
+    ootr::{
+        access::LogicHelper,
+        bundle::{
+            self,
+            Bundle,
+            BundleRegion,
+        },
+        item::Item,
+        region::{
+            EntrancePair,
+            Region,
+        },
+        setting::SettingInfo,
+    },
+};
+
+/// An implementation of `ootr::Rando` which loads all data from a bundle exported using `oottracker-export-rando-data`, so it works without Python.
+///
+/// Unlike the Python-based `Rando`, this is `Send` and `Sync`, so it can be shared between threads and swapped out at runtime.
+#[derive(Debug, Clone)]
+pub struct BundleRando {
+    version: String,
+    entrances: Arc<Vec<EntrancePair>>,
+    escaped_items: Arc<HashMap<String, Item>>,
+    get_item_ids: Arc<HashMap<String, u16>>,
+    item_table: Arc<HashMap<String, Item>>,
+    logic_helpers: Arc<HashMap<String, LogicHelper>>,
+    logic_tricks: Arc<HashSet<String>>,
+    regions: Arc<Vec<Arc<Region<Self>>>>,
+    glitched_regions: Arc<Vec<Arc<Region<Self>>>>,
+    setting_infos: Arc<HashMap<String, SettingInfo>>,
+    starting_items: Arc<HashMap<String, Item>>,
+    vanilla_items: Arc<HashMap<String, Item>>,
+}
+
+impl BundleRando {
+    /// Reads the bundle at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<BundleRando, BundleRandoErr> {
+        let bundle = serde_json::from_reader::<_, Bundle>(BufReader::new(File::open(path)?))?;
+        BundleRando::from_bundle(bundle)
+    }
+
+    pub fn from_bundle(bundle: Bundle) -> Result<BundleRando, BundleRandoErr> {
+        fn convert_regions(regions: Vec<BundleRegion>) -> Arc<Vec<Arc<Region<BundleRando>>>> {
+            Arc::new(regions.into_iter().map(|BundleRegion { name, dungeon, scene, hint, time_passes, events, locations, exits }| Arc::new(Region {
+                name, dungeon, scene, hint, time_passes, events, locations, exits,
+            })).collect())
+        }
+
+        let Bundle { format_version, version, entrances, escaped_items, get_item_ids, item_table, logic_helpers, logic_tricks, regions, glitched_regions, setting_infos, starting_items, vanilla_items } = bundle;
+        if format_version != bundle::FORMAT_VERSION { return Err(BundleRandoErr::FormatVersion(format_version)) }
+        Ok(BundleRando {
+            version,
+            entrances: Arc::new(entrances),
+            escaped_items: Arc::new(escaped_items),
+            get_item_ids: Arc::new(get_item_ids),
+            item_table: Arc::new(item_table),
+            logic_helpers: Arc::new(logic_helpers),
+            logic_tricks: Arc::new(logic_tricks),
+            regions: convert_regions(regions),
+            glitched_regions: convert_regions(glitched_regions),
+            setting_infos: Arc::new(setting_infos),
+            starting_items: Arc::new(starting_items),
+            vanilla_items: Arc::new(vanilla_items),
+        })
+    }
+
+    /// The randomizer's own version string, e.g. `6.2.205 R-1`.
+    pub fn version(&self) -> &str { &self.version }
+}
+
+#[derive(Debug, FromArc, Clone)]
+pub enum BundleRandoErr {
+    FormatVersion(u8),
+    #[from_arc]
+    Io(Arc<io::Error>),
+    ItemNotFound,
+    #[from_arc]
+    Json(Arc<serde_json::Error>),
+}
+
+impl fmt::Display for BundleRandoErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleRandoErr::FormatVersion(version) => write!(f, "rando data bundle has format version {} but this version of the tracker expects format version {}, export the bundle again", version, bundle::FORMAT_VERSION),
+            BundleRandoErr::Io(e) => write!(f, "I/O error: {}", e),
+            BundleRandoErr::ItemNotFound => write!(f, "no such item"),
+            BundleRandoErr::Json(e) => write!(f, "JSON error: {}", e),
+        }
+    }
+}
+
+impl ootr::RandoErr for BundleRandoErr {
+    const ITEM_NOT_FOUND: BundleRandoErr = BundleRandoErr::ItemNotFound;
+}
+
+impl ootr::Rando for BundleRando {
+    type Err = BundleRandoErr;
+    type RegionName = String;
+
+    fn entrances(&self) -> Result<Arc<Vec<EntrancePair>>, BundleRandoErr> { Ok(Arc::clone(&self.entrances)) }
+    fn escaped_items(&self) -> Result<Arc<HashMap<String, Item>>, BundleRandoErr> { Ok(Arc::clone(&self.escaped_items)) }
+    fn get_item_ids(&self) -> Result<Arc<HashMap<String, u16>>, BundleRandoErr> { Ok(Arc::clone(&self.get_item_ids)) }
+    fn item_table(&self) -> Result<Arc<HashMap<String, Item>>, BundleRandoErr> { Ok(Arc::clone(&self.item_table)) }
+    fn logic_helpers(&self) -> Result<Arc<HashMap<String, LogicHelper>>, BundleRandoErr> { Ok(Arc::clone(&self.logic_helpers)) }
+    fn logic_tricks(&self) -> Result<Arc<HashSet<String>>, BundleRandoErr> { Ok(Arc::clone(&self.logic_tricks)) }
+
+    fn regions(&self, glitched: bool) -> Result<Arc<Vec<Arc<Region<Self>>>>, BundleRandoErr> {
+        Ok(Arc::clone(if glitched { &self.glitched_regions } else { &self.regions }))
+    }
+
+    fn root() -> String { format!("Root") }
+    fn setting_infos(&self) -> Result<Arc<HashMap<String, SettingInfo>>, BundleRandoErr> { Ok(Arc::clone(&self.setting_infos)) }
+    fn starting_items(&self) -> Result<Arc<HashMap<String, Item>>, BundleRandoErr> { Ok(Arc::clone(&self.starting_items)) }
+    fn vanilla_items(&self) -> Result<Arc<HashMap<String, Item>>, BundleRandoErr> { Ok(Arc::clone(&self.vanilla_items)) }
+}
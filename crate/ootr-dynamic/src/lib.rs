@@ -1,10 +1,12 @@
 #![deny(rust_2018_idioms, unused, unused_crate_dependencies, unused_import_braces, unused_lifetimes, unused_qualifications, warnings)]
 #![forbid(unsafe_code)]
 
-use {
+use semver::Version;
+#[cfg(feature = "python")] use {
     std::{
         cell::RefCell,
         collections::{
+            BTreeMap,
             HashMap,
             HashSet,
         },
@@ -25,45 +27,86 @@ use {
         sync::Arc,
     },
     itertools::Itertools as _,
-    pyo3::prelude::*,
-    semver::Version,
+    pyo3::{
+        prelude::*,
+        types::PyDict,
+    },
     serde::de::DeserializeOwned,
     wheel::FromArc,
     ootr::{
+        access::{
+            LogicHelper,
+            ParseError,
+            RuleParser,
+        },
         item::Item,
-        region::Region,
+        region::{
+            EntranceInfo,
+            EntrancePair,
+            Region,
+        },
+        setting::{
+            SettingChoice,
+            SettingInfo,
+            SettingType,
+            SettingValue,
+        },
     },
     crate::region::{
         RawRegion,
         parse_dungeon_info,
     },
 };
+pub use crate::bundle::{
+    BundleRando,
+    BundleRandoErr,
+};
 
-mod region;
+mod bundle;
+#[cfg(feature = "python")] mod region;
 
+#[cfg(feature = "python")]
 pub struct Rando<'p> {
     py: Python<'p>,
     path: PathBuf,
+    entrances: RefCell<Option<Arc<Vec<EntrancePair>>>>,
     escaped_items: RefCell<Option<Arc<HashMap<String, Item>>>>,
+    get_item_ids: RefCell<Option<Arc<HashMap<String, u16>>>>,
     item_table: RefCell<Option<Arc<HashMap<String, Item>>>>,
+    logic_helpers: RefCell<Option<Arc<HashMap<String, LogicHelper>>>>,
     logic_tricks: RefCell<Option<Arc<HashSet<String>>>>,
-    regions: RefCell<Option<Arc<Vec<Arc<Region<Self>>>>>>, //TODO glitched support
-    setting_infos: RefCell<Option<Arc<HashSet<String>>>>,
+    regions: RefCell<Option<Arc<Vec<Arc<Region<Self>>>>>>,
+    glitched_regions: RefCell<Option<Arc<Vec<Arc<Region<Self>>>>>>,
+    setting_infos: RefCell<Option<Arc<HashMap<String, SettingInfo>>>>,
+    starting_items: RefCell<Option<Arc<HashMap<String, Item>>>>,
+    vanilla_items: RefCell<Option<Arc<HashMap<String, Item>>>>,
 }
 
+#[cfg(feature = "python")]
 impl<'p> Rando<'p> {
     pub fn new(py: Python<'p>, path: impl AsRef<Path>) -> Rando<'p> {
         Rando {
             py,
             path: path.as_ref().to_owned(),
+            entrances: RefCell::default(),
             escaped_items: RefCell::default(),
+            get_item_ids: RefCell::default(),
             item_table: RefCell::default(),
+            logic_helpers: RefCell::default(),
             logic_tricks: RefCell::default(),
             regions: RefCell::default(),
+            glitched_regions: RefCell::default(),
             setting_infos: RefCell::default(),
+            starting_items: RefCell::default(),
+            vanilla_items: RefCell::default(),
         }
     }
 
+    /// The randomizer's version string, e.g. `6.2.205 R-1`.
+    pub fn version(&self) -> Result<String, RandoErr> {
+        Ok(self.import("version")?.getattr("__version__")?.extract()?)
+    }
+
     /// Imports and returns the given Python module from the randomizer codebase.
     fn import(&self, module: &str) -> PyResult<&'p PyModule> {
         let sys = self.py.import("sys")?;
@@ -72,6 +115,7 @@ impl<'p> Rando<'p> {
     }
 }
 
+#[cfg(feature = "python")]
 impl<'p> fmt::Debug for Rando<'p> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         //TODO f.debug_struct("Rando").field("path", path).finish_non_exhaustive() (https://github.com/rust-lang/rust/issues/67364)
@@ -81,41 +125,88 @@ impl<'p> fmt::Debug for Rando<'p> {
     }
 }
 
+#[cfg(feature = "python")]
 #[derive(Debug, FromArc, Clone)]
 pub enum RandoErr {
+    EntranceName(String),
     #[from_arc]
     Io(Arc<io::Error>),
     InvalidLogicHelper,
     ItemNotFound,
+    LogicHelperParse {
+        name: String,
+        rule: String,
+        error: ParseError,
+    },
     NonJsonRegionFile(String),
     NonUnicodeRegionFilename,
     #[from_arc]
     Py(Arc<PyErr>),
+    RuleParse {
+        region: String,
+        name: String,
+        rule: String,
+        error: ParseError,
+    },
     UnknownRegionFilename(String),
 }
 
+#[cfg(feature = "python")]
 impl fmt::Display for RandoErr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            RandoErr::EntranceName(name) => write!(f, "entrance {:?} is not of the form \"Region -> Region\"", name),
             RandoErr::Io(e) => write!(f, "I/O error: {}", e),
-            RandoErr::InvalidLogicHelper => write!(f, "multiple ( found in logic helper"),
+            RandoErr::InvalidLogicHelper => write!(f, "invalid logic helper signature"),
             RandoErr::ItemNotFound => write!(f, "no such item"),
+            RandoErr::LogicHelperParse { name, rule, error } => write!(f, "failed to parse logic helper {} ({:?}): {}", name, rule, error),
             RandoErr::NonJsonRegionFile(name) => write!(f, "expected region filename ending in .json but found {}", name),
             RandoErr::NonUnicodeRegionFilename => write!(f, "non-Unicode region filename"),
             RandoErr::Py(e) => write!(f, "Python error: {}", e),
+            RandoErr::RuleParse { region, name, rule, error } => write!(f, "failed to parse access rule for {} in region {} ({:?}): {}", name, region, rule, error),
             RandoErr::UnknownRegionFilename(name) => write!(f, "unexpected region filename: {}", name),
         }
     }
 }
 
+#[cfg(feature = "python")]
 impl ootr::RandoErr for RandoErr {
     const ITEM_NOT_FOUND: RandoErr = RandoErr::ItemNotFound;
 }
 
+#[cfg(feature = "python")]
 impl<'p> ootr::Rando for Rando<'p> {
     type Err = RandoErr;
     type RegionName = String;
 
+    fn entrances(&self) -> Result<Arc<Vec<EntrancePair>>, RandoErr> {
+        /// Converts one direction of an entry in the randomizer's entrance table, returning `None` if it has no entrance index (e.g. grotto exits).
+        fn parse_entrance(entrance: &PyAny) -> Result<Option<EntranceInfo>, RandoErr> {
+            let (name, data) = entrance.extract::<(String, &PyDict)>()?;
+            let index = match data.get_item("index") {
+                Some(index) => index.extract()?,
+                None => return Ok(None), //TODO also support grottos, which use the `entrance` and `grotto_id` keys instead
+            };
+            let (from, to) = name.split_once(" -> ").ok_or_else(|| RandoErr::EntranceName(name.clone()))?;
+            Ok(Some(EntranceInfo { from: from.to_owned(), to: to.to_owned(), index }))
+        }
+
+        if self.entrances.borrow().is_none() {
+            let mut entrances = Vec::default();
+            for entry in self.import("EntranceShuffle")?.getattr("entrance_shuffle_table")?.iter()? {
+                let entry = entry?;
+                let Some(forward) = parse_entrance(entry.get_item(1)?)? else { continue };
+                entrances.push(EntrancePair {
+                    kind: entry.get_item(0)?.extract()?,
+                    reverse: if entry.len()? > 2 { parse_entrance(entry.get_item(2)?)? } else { None },
+                    forward,
+                });
+            }
+            *self.entrances.borrow_mut() = Some(Arc::new(entrances));
+        }
+        Ok(Arc::clone(self.entrances.borrow().as_ref().expect("just inserted")))
+    }
+
     fn escaped_items(&self) -> Result<Arc<HashMap<String, Item>>, RandoErr> {
         if self.escaped_items.borrow().is_none() {
             let items = self.import("RuleParser")?
@@ -143,6 +234,23 @@ impl<'p> ootr::Rando for Rando<'p> {
         Ok(Arc::clone(self.escaped_items.borrow().as_ref().expect("just inserted")))
     }
 
+    fn get_item_ids(&self) -> Result<Arc<HashMap<String, u16>>, RandoErr> {
+        if self.get_item_ids.borrow().is_none() {
+            let ids = self.import("ItemList")?
+                .getattr("item_table")?
+                .call_method0("items")?
+                .iter()?
+                .filter_map(|elt| match elt.and_then(|elt| elt.extract::<(String, (&PyAny, &PyAny, Option<u16>, &PyAny))>()) {
+                    Ok((name, (_, _, Some(get_item_id), _))) => Some(Ok((name, get_item_id))),
+                    Ok((_, (_, _, None, _))) => None, // events have no get-item ID
+                    Err(e) => Some(Err(e)),
+                })
+                .try_collect::<_, _, PyErr>()?;
+            *self.get_item_ids.borrow_mut() = Some(Arc::new(ids));
+        }
+        Ok(Arc::clone(self.get_item_ids.borrow().as_ref().expect("just inserted")))
+    }
+
     fn item_table(&self) -> Result<Arc<HashMap<String, Item>>, RandoErr> {
         if self.item_table.borrow().is_none() {
             let items = self.import("ItemList")?
@@ -166,6 +274,30 @@ impl<'p> ootr::Rando for Rando<'p> {
         Ok(Arc::clone(self.item_table.borrow().as_ref().expect("just inserted")))
     }
 
+    fn logic_helpers(&self) -> Result<Arc<HashMap<String, LogicHelper>>, RandoErr> {
+        if self.logic_helpers.borrow().is_none() {
+            let parser = RuleParser::new(self)?;
+            let raw_helpers = read_json_lenient_sync::<_, BTreeMap<String, String>>(BufReader::new(File::open(self.path.join("data").join("LogicHelpers.json"))?))?;
+            let mut helpers = HashMap::default();
+            for (signature, rule) in raw_helpers {
+                let (name, params) = if let Some((name, params)) = signature.split_once('(') {
+                    let params = params.strip_suffix(')').ok_or(RandoErr::InvalidLogicHelper)?;
+                    if params.contains('(') { return Err(RandoErr::InvalidLogicHelper) }
+                    (name.to_owned(), params.split(',').map(|param| param.trim().to_owned()).filter(|param| !param.is_empty()).collect_vec())
+                } else {
+                    (signature, Vec::default())
+                };
+                let body = match parser.parse_helper_body(&rule, &params) {
+                    Ok(body) => body,
+                    Err(error) => return Err(RandoErr::LogicHelperParse { name, rule, error }),
+                };
+                helpers.insert(name, LogicHelper { params, body });
+            }
+            *self.logic_helpers.borrow_mut() = Some(Arc::new(helpers));
+        }
+        Ok(Arc::clone(self.logic_helpers.borrow().as_ref().expect("just inserted")))
+    }
+
     fn logic_tricks(&self) -> Result<Arc<HashSet<String>>, RandoErr> {
         if self.logic_tricks.borrow().is_none() {
             let mut tricks = HashSet::default();
@@ -177,9 +309,11 @@ impl<'p> ootr::Rando for Rando<'p> {
         Ok(Arc::clone(self.logic_tricks.borrow().as_ref().expect("just inserted")))
     }
 
-    fn regions(&self) -> Result<Arc<Vec<Arc<Region<Self>>>>, RandoErr> {
-        if self.regions.borrow().is_none() {
-            let world_path = self.path.join("data").join("World"); //TODO glitched support
+    fn regions(&self, glitched: bool) -> Result<Arc<Vec<Arc<Region<Self>>>>, RandoErr> {
+        let cache = if glitched { &self.glitched_regions } else { &self.regions };
+        if cache.borrow().is_none() {
+            let parser = RuleParser::new(self)?;
+            let world_path = self.path.join("data").join(if glitched { "Glitched World" } else { "World" });
             let mut regions = Vec::default();
             for region_path in fs::read_dir(world_path)? {
                 let region_path = region_path?;
@@ -190,37 +324,140 @@ impl<'p> ootr::Rando for Rando<'p> {
                 for raw_region in read_json_lenient_sync::<_, Vec<RawRegion>>(BufReader::new(region_file))? {
                     let name = raw_region.region_name.clone();
                     //assert_eq!(dungeon.map(|(dungeon, _)| dungeon.to_string().replace('\'', "")), raw_region.dungeon);
+                    let parse_rules = |rules: BTreeMap<String, String>| rules.into_iter()
+                        .map(|(target, rule)| match parser.parse(&rule) {
+                            Ok(expr) => Ok((target, expr)),
+                            Err(error) => Err(RandoErr::RuleParse { region: name.clone(), name: target, rule, error }),
+                        })
+                        .collect::<Result<HashMap<_, _>, _>>();
                     regions.push(Arc::new(Region {
                         dungeon,
                         scene: raw_region.scene,
                         hint: raw_region.hint,
                         time_passes: raw_region.time_passes,
-                        events: raw_region.events.into_keys().collect(),
-                        locations: raw_region.locations.into_keys().collect(),
-                        exits: raw_region.exits.into_keys().collect(),
+                        events: parse_rules(raw_region.events)?,
+                        locations: parse_rules(raw_region.locations)?,
+                        exits: parse_rules(raw_region.exits)?,
                         name,
                     }));
                 }
             }
-            *self.regions.borrow_mut() = Some(Arc::new(regions));
+            *cache.borrow_mut() = Some(Arc::new(regions));
         }
-        Ok(Arc::clone(self.regions.borrow().as_ref().expect("just inserted")))
+        Ok(Arc::clone(cache.borrow().as_ref().expect("just inserted")))
     }
 
     fn root() -> String { format!("Root") }
 
-    fn setting_infos(&self) -> Result<Arc<HashSet<String>>, RandoErr> {
+    fn setting_infos(&self) -> Result<Arc<HashMap<String, SettingInfo>>, RandoErr> {
         if self.setting_infos.borrow().is_none() {
-            let mut settings = HashSet::default();
-            for setting in self.import("SettingsList")?.getattr("setting_infos")?.iter()? {
-                settings.insert(setting?.getattr("name")?.extract()?);
+            let mut settings = HashMap::default();
+            for (index, setting) in self.import("SettingsList")?.getattr("setting_infos")?.iter()?.enumerate() {
+                let setting = parse_setting_info(index, setting?)?;
+                settings.insert(setting.name.clone(), setting);
             }
             *self.setting_infos.borrow_mut() = Some(Arc::new(settings));
         }
         Ok(Arc::clone(self.setting_infos.borrow().as_ref().expect("just inserted")))
     }
+
+    fn starting_items(&self) -> Result<Arc<HashMap<String, Item>>, RandoErr> {
+        if self.starting_items.borrow().is_none() {
+            let item_table = self.item_table()?;
+            let mut starting_items = HashMap::default();
+            for (name, entry) in self.import("StartingItems")?.getattr("everything")?.downcast::<PyDict>().map_err(PyErr::from)? {
+                let item_name = entry.getattr("itemname")?.extract::<String>()?;
+                starting_items.insert(name.extract()?, item_table.get(&item_name).ok_or(RandoErr::ItemNotFound)?.clone());
+            }
+            *self.starting_items.borrow_mut() = Some(Arc::new(starting_items));
+        }
+        Ok(Arc::clone(self.starting_items.borrow().as_ref().expect("just inserted")))
+    }
+
+    fn vanilla_items(&self) -> Result<Arc<HashMap<String, Item>>, RandoErr> {
+        if self.vanilla_items.borrow().is_none() {
+            let item_table = self.item_table()?;
+            let mut vanilla_items = HashMap::default();
+            for (name, entry) in self.import("LocationList")?.getattr("location_table")?.downcast::<PyDict>().map_err(PyErr::from)? {
+                // entries are (type, scene, default, addresses, vanilla_item, filter_tags)
+                let Some(item_name) = entry.get_item(4)?.extract::<Option<String>>()? else { continue };
+                vanilla_items.insert(name.extract()?, item_table.get(&item_name).ok_or(RandoErr::ItemNotFound)?.clone());
+            }
+            *self.vanilla_items.borrow_mut() = Some(Arc::new(vanilla_items));
+        }
+        Ok(Arc::clone(self.vanilla_items.borrow().as_ref().expect("just inserted")))
+    }
+}
+
+/// Converts an instance of the randomizer's `SettingInfo` class.
+#[cfg(feature = "python")]
+fn parse_setting_info(index: usize, setting: &PyAny) -> PyResult<SettingInfo> {
+    /// Reads an optional attribute which may be missing or `None`.
+    fn opt_attr<'a, T: FromPyObject<'a>>(obj: &'a PyAny, name: &str) -> PyResult<Option<T>> {
+        if !obj.hasattr(name)? { return Ok(None) }
+        let value = obj.getattr(name)?;
+        if value.is_none() { Ok(None) } else { value.extract().map(Some) }
+    }
+
+    /// Reads a parameter of a `Scale` setting, stored as an attribute in some randomizer versions and as a GUI parameter in others.
+    fn scale_param(setting: &PyAny, attr: &str, gui_param: &str) -> PyResult<Option<i64>> {
+        if let Some(param) = opt_attr(setting, attr)? { return Ok(Some(param)) }
+        Ok(match opt_attr::<&PyDict>(setting, "gui_params")? {
+            Some(gui_params) => match gui_params.get_item(gui_param) {
+                Some(param) => param.extract().ok(),
+                None => None,
+            },
+            None => None,
+        })
+    }
+
+    let ty = match &*setting.getattr("type")?.getattr("__name__")?.extract::<String>()? {
+        "bool" => SettingType::Bool,
+        "int" => SettingType::Int {
+            min: scale_param(setting, "minimum", "min")?,
+            max: scale_param(setting, "maximum", "max")?,
+            step: scale_param(setting, "step", "step")?.unwrap_or(1),
+        },
+        "str" => SettingType::Str,
+        "list" => SettingType::List,
+        _ => SettingType::Other,
+    };
+    let choices = if let SettingType::Str | SettingType::List = ty {
+        match opt_attr::<&PyAny>(setting, "choices")? {
+            Some(choices) => if let Ok(choices) = choices.downcast::<PyDict>() {
+                choices.iter()
+                    .map(|(value, display_name)| Ok(SettingChoice { value: value.extract()?, display_name: display_name.extract()? }))
+                    .collect::<PyResult<_>>()?
+            } else {
+                choices.iter()?
+                    .map(|value| {
+                        let value = value?.extract::<String>()?;
+                        Ok(SettingChoice { display_name: value.clone(), value })
+                    })
+                    .collect::<PyResult<_>>()?
+            },
+            None => Vec::default(),
+        }
+    } else {
+        Vec::default()
+    };
+    let default = match ty {
+        SettingType::Bool => opt_attr(setting, "default")?.map(SettingValue::Bool),
+        SettingType::Int { .. } => opt_attr(setting, "default")?.map(SettingValue::Int),
+        SettingType::Str => opt_attr(setting, "default")?.map(SettingValue::Str),
+        SettingType::List => opt_attr(setting, "default")?.map(SettingValue::List),
+        SettingType::Other => None,
+    };
+    Ok(SettingInfo {
+        name: setting.getattr("name")?.extract()?,
+        display_name: opt_attr(setting, "gui_text")?,
+        shared: opt_attr(setting, "shared")?.unwrap_or(false),
+        bitwidth: opt_attr(setting, "bitwidth")?.unwrap_or(0),
+        index, ty, choices, default,
+    })
 }
 
+#[cfg(feature = "python")]
 fn read_json_lenient_sync<R: BufRead, T: DeserializeOwned>(mut reader: R) -> io::Result<T> {
     let mut buf = String::default();
     let mut line_buf = String::default();
@@ -240,18 +477,24 @@ pub fn version() -> Version {
     Version::parse(env!("CARGO_PKG_VERSION")).expect("failed to parse current version")
 }
 
+#[cfg(feature = "python")]
 #[test]
 fn load_rando_data() -> Result<(), RandoErr> {
     use ootr::Rando as _;
 
     Python::with_gil(|py| {
         let rando = Rando::new(py, "C:\\Users\\fenhl\\AppData\\Local\\Fenhl\\RSL\\cache\\ootr-latest");
+        rando.entrances()?;
         rando.escaped_items()?;
+        rando.get_item_ids()?;
         rando.item_table()?;
         rando.logic_helpers()?;
         rando.logic_tricks()?;
-        rando.regions()?;
+        rando.regions(false)?;
+        rando.regions(true)?;
         rando.setting_infos()?;
+        rando.starting_items()?;
+        rando.vanilla_items()?;
         Ok(())
     })
 }
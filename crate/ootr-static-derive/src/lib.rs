@@ -3,11 +3,12 @@
 
 use {
     std::{
-        collections::HashSet,
-        fs,
+        collections::HashMap,
+        env,
+        fs::File,
         io::{
             self,
-            Cursor,
+            BufReader,
         },
         sync::Arc,
     },
@@ -16,12 +17,9 @@ use {
         Casing as _,
     },
     derive_more::From,
-    directories::ProjectDirs,
-    graphql_client::GraphQLQuery,
     itertools::Itertools as _,
     proc_macro::TokenStream,
     proc_macro2::Span,
-    pyo3::prelude::*,
     quote::quote,
     quote_value::QuoteValue,
     syn::{
@@ -29,11 +27,24 @@ use {
         Ident,
         parse_macro_input,
     },
+    ootr::bundle::{
+        self,
+        Bundle,
+        BundleRegion,
+    },
+};
+#[cfg(feature = "python")] use {
+    std::{
+        fs,
+        io::Cursor,
+    },
+    directories::ProjectDirs,
+    graphql_client::GraphQLQuery,
+    pyo3::prelude::*,
     zip::{
         ZipArchive,
         result::ZipError,
     },
-    ootr::Rando as _,
 };
 
 #[proc_macro]
@@ -44,6 +55,7 @@ pub fn version(_: TokenStream) -> TokenStream {
     })
 }
 
+#[cfg(feature = "python")]
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "../../assets/graphql/github-schema.graphql",
@@ -54,16 +66,28 @@ struct DevRVersionQuery;
 
 #[derive(Debug, From)]
 enum Error {
+    #[from(ignore)]
+    BundleVersion(u8),
     Io(io::Error),
+    Json(serde_json::Error),
+    #[cfg(feature = "python")]
     MissingHomeDir,
+    /// Neither the `OOTR_DATA_BUNDLE` environment variable nor the `python` feature was set.
+    #[cfg(not(feature = "python"))]
+    NoBundle,
+    #[cfg(feature = "python")]
     Rando(ootr_dynamic::RandoErr),
+    #[cfg(feature = "python")]
     Reqwest(reqwest::Error),
+    #[cfg(feature = "python")]
     Zip(ZipError),
 }
 
 /// A wrapper type around `Check<ootr_dynamic::Rando>` that's quoted as if it were a `Check<ootr_static::Rando>`
+#[cfg(feature = "python")]
 struct CheckWrapper<'a>(&'a ootr::check::Check<ootr_dynamic::Rando<'a>>);
 
+#[cfg(feature = "python")]
 impl<'a> QuoteValue for CheckWrapper<'a> {
     fn quote(&self) -> proc_macro2::TokenStream {
         match self.0 {
@@ -87,12 +111,12 @@ impl<'a> QuoteValue for CheckWrapper<'a> {
     }
 }
 
-/// A wrapper type around `Arc<Region<ootr_dynamic::Rando>>` that's quoted as if it were an `Arc<Region<ootr_static::Rando>>`
-struct RegionWrapper<'a>(&'a Arc<ootr::region::Region<ootr_dynamic::Rando<'a>>>);
+/// A wrapper type around a `BundleRegion` that's quoted as if it were an `Arc<Region<ootr_static::Rando>>`
+struct RegionWrapper<'a>(&'a BundleRegion);
 
 impl<'a> QuoteValue for RegionWrapper<'a> {
     fn quote(&self) -> proc_macro2::TokenStream {
-        let ootr::region::Region { ref name, ref dungeon, ref scene, ref hint, ref time_passes, ref events, ref locations, ref exits } = **self.0;
+        let BundleRegion { ref name, ref dungeon, ref scene, ref hint, ref time_passes, ref events, ref locations, ref exits } = *self.0;
         let name = (&name[..]).quote(); // quote as &'static str
         let dungeon = dungeon.quote();
         let scene = scene.quote();
@@ -100,7 +124,7 @@ impl<'a> QuoteValue for RegionWrapper<'a> {
         let time_passes = time_passes.quote();
         let events = events.quote();
         let locations = locations.quote();
-        let exits = exits.iter().map(|name| &name[..]).collect::<HashSet<_>>().quote();
+        let exits = exits.iter().map(|(name, rule)| (&name[..], rule.clone())).collect::<HashMap<_, _>>().quote(); // quote names as &'static str
         quote! {
             ::std::sync::Arc::new(
                 ::ootr::region::Region {
@@ -127,7 +151,9 @@ pub fn derive_rando(input: TokenStream) -> TokenStream {
     }))
 }
 
-fn derive_rando_inner(ty: Ident) -> Result<proc_macro2::TokenStream, Error> {
+/// Downloads the latest Dev-R version of the randomizer if it isn't cached yet, and extracts its data using Python.
+#[cfg(feature = "python")]
+fn bundle_from_python() -> Result<Bundle, Error> {
     let project_dirs = ProjectDirs::from("net", "Fenhl", "RSL").ok_or(Error::MissingHomeDir)?; // re-use rando copy from https://github.com/fenhl/plando-random-settings/tree/riir
     let cache_dir = project_dirs.cache_dir();
     let client = reqwest::blocking::Client::builder()
@@ -145,17 +171,40 @@ fn derive_rando_inner(ty: Ident) -> Result<proc_macro2::TokenStream, Error> {
         ZipArchive::new(Cursor::new(rando_download))?.extract(&cache_dir)?;
         fs::rename(cache_dir.join("OoT-Randomizer-Dev-R"), &rando_path)?;
     }
-    let data = Python::with_gil(|py| {
+    Python::with_gil(|py| {
         let rando = ootr_dynamic::Rando::new(py, rando_path);
-        let data = vec![
-            ("escaped_items", quote!(HashMap<String, Item>), rando.escaped_items()?.quote()),
-            ("item_table", quote!(HashMap<String, Item>), rando.item_table()?.quote()),
-            ("logic_tricks", quote!(HashSet<String>), rando.logic_tricks()?.quote()),
-            ("regions", quote!(Vec<Arc<Region<#ty>>>), Arc::new(rando.regions()?.iter().map(RegionWrapper).collect_vec()).quote()),
-            ("setting_infos", quote!(HashSet<String>), rando.setting_infos()?.quote()),
-        ];
-        Ok::<_, Error>(data)
-    })?;
+        Ok(Bundle::from_rando(&rando, rando.version()?)?)
+    })
+}
+
+#[cfg(not(feature = "python"))]
+fn bundle_from_python() -> Result<Bundle, Error> {
+    Err(Error::NoBundle)
+}
+
+fn derive_rando_inner(ty: Ident) -> Result<proc_macro2::TokenStream, Error> {
+    // builds without network access or Python can point this at a bundle exported using `oottracker-export-rando-data`
+    let bundle = if let Some(bundle_path) = env::var_os("OOTR_DATA_BUNDLE") {
+        let bundle = serde_json::from_reader::<_, Bundle>(BufReader::new(File::open(bundle_path)?))?;
+        if bundle.format_version != bundle::FORMAT_VERSION { return Err(Error::BundleVersion(bundle.format_version)) }
+        bundle
+    } else {
+        bundle_from_python()?
+    };
+    let Bundle { format_version: _, version, entrances, escaped_items, get_item_ids, item_table, logic_helpers, logic_tricks, regions, glitched_regions, setting_infos, starting_items, vanilla_items } = bundle;
+    let data = vec![
+        ("entrances", quote!(Vec<EntrancePair>), Arc::new(entrances).quote()),
+        ("escaped_items", quote!(HashMap<String, Item>), Arc::new(escaped_items).quote()),
+        ("get_item_ids", quote!(HashMap<String, u16>), Arc::new(get_item_ids).quote()),
+        ("item_table", quote!(HashMap<String, Item>), Arc::new(item_table).quote()),
+        ("logic_helpers", quote!(HashMap<String, LogicHelper>), Arc::new(logic_helpers).quote()),
+        ("logic_tricks", quote!(HashSet<String>), Arc::new(logic_tricks).quote()),
+        ("setting_infos", quote!(HashMap<String, SettingInfo>), Arc::new(setting_infos).quote()),
+        ("starting_items", quote!(HashMap<String, Item>), Arc::new(starting_items).quote()),
+        ("vanilla_items", quote!(HashMap<String, Item>), Arc::new(vanilla_items).quote()),
+    ];
+    let regions = Arc::new(regions.iter().map(RegionWrapper).collect_vec()).quote();
+    let glitched_regions = Arc::new(glitched_regions.iter().map(RegionWrapper).collect_vec()).quote();
     let screaming_idents = data.iter()
         .map(|(name, _, _)| Ident::new(&name.to_case(Case::ScreamingSnake), Span::call_site()))
         .collect_vec();
@@ -172,11 +221,22 @@ fn derive_rando_inner(ty: Ident) -> Result<proc_macro2::TokenStream, Error> {
         });
     Ok(quote! {
         #(#lazy_statics)*
+        static REGIONS: Lazy<Arc<Vec<Arc<Region<#ty>>>>> = Lazy::new(|| #regions);
+        static GLITCHED_REGIONS: Lazy<Arc<Vec<Arc<Region<#ty>>>>> = Lazy::new(|| #glitched_regions);
+
+        impl #ty {
+            /// The version of the randomizer this data was taken from.
+            pub const RANDO_VERSION: &'static str = #version;
+        }
 
         impl ootr::Rando for #ty {
             type Err = RandoErr;
             type RegionName = &'static str;
 
+            fn regions<'a>(&'a self, glitched: bool) -> Result<Arc<Vec<Arc<Region<#ty>>>>, RandoErr> {
+                Ok(Arc::clone(if glitched { &GLITCHED_REGIONS } else { &REGIONS }))
+            }
+
             fn root() -> &'static str { "Root" }
             #(#trait_fns)*
         }
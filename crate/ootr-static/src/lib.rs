@@ -13,15 +13,32 @@ use {
     once_cell::sync::Lazy,
     semver::Version,
     ootr::{
+        access::{
+            Age,
+            ArithOp,
+            CmpOp,
+            Expr,
+            LogicHelper,
+        },
         item::Item,
         model::{
             Dungeon,
             MainDungeon,
+            Medallion,
+            TimeRange,
         },
         region::{
+            EntranceInfo,
+            EntrancePair,
             Mq,
             Region,
         },
+        setting::{
+            SettingChoice,
+            SettingInfo,
+            SettingType,
+            SettingValue,
+        },
     },
 };
 
@@ -0,0 +1,680 @@
+//! Access rules for events, locations, and exits, parsed from the randomizer's rule syntax.
+//!
+//! The randomizer writes these rules as Python expressions which its `RuleParser` module compiles into Python code.
+//! Instead, this module parses them into an [`Expr`] which tools built on this crate can evaluate or inspect.
+
+use {
+    std::{
+        collections::{
+            HashMap,
+            HashSet,
+        },
+        fmt,
+        iter::Peekable,
+        str::CharIndices,
+        sync::Arc,
+    },
+    enum_iterator::Sequence,
+    quote_value::QuoteValue,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        Rando,
+        item::Item,
+        model::{
+            Medallion,
+            TimeRange,
+        },
+        setting::SettingInfo,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence, Deserialize, Serialize, QuoteValue)]
+pub enum Age {
+    Child,
+    Adult,
+}
+
+impl fmt::Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Age::Child => write!(f, "child"),
+            Age::Adult => write!(f, "adult"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+    NotIn,
+}
+
+impl fmt::Display for CmpOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CmpOp::Eq => write!(f, "=="),
+            CmpOp::Ne => write!(f, "!="),
+            CmpOp::Lt => write!(f, "<"),
+            CmpOp::Le => write!(f, "<="),
+            CmpOp::Gt => write!(f, ">"),
+            CmpOp::Ge => write!(f, ">="),
+            CmpOp::In => write!(f, "in"),
+            CmpOp::NotIn => write!(f, "not in"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    FloorDiv,
+    Mod,
+}
+
+impl fmt::Display for ArithOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithOp::Add => write!(f, "+"),
+            ArithOp::Sub => write!(f, "-"),
+            ArithOp::Mul => write!(f, "*"),
+            ArithOp::Div => write!(f, "/"),
+            ArithOp::FloorDiv => write!(f, "//"),
+            ArithOp::Mod => write!(f, "%"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, QuoteValue)]
+pub enum Expr {
+    /// Conjunction. Empty for a rule that's always true.
+    All(Vec<Expr>),
+    /// Disjunction. Empty for a rule that's always false.
+    Any(Vec<Expr>),
+    /// `is_child` or `is_adult`.
+    Age(Age),
+    /// A binary arithmetic operation. Unary minus is represented as subtraction from 0 unless applied to a literal.
+    Arith(Box<Expr>, ArithOp, Box<Expr>),
+    /// `at('Region', rule)`: the rule must be satisfied in the given region.
+    At(String, Box<Expr>),
+    Compare(Box<Expr>, CmpOp, Box<Expr>),
+    Event(String),
+    /// `here(rule)`: the rule must be satisfied in the region the rule belongs to.
+    Here(Box<Expr>),
+    /// An item and the number of copies of it required, which is usually a literal but can also refer to a setting.
+    Item(Item, Box<Expr>),
+    LitBool(bool),
+    LitFloat(f64),
+    LitInt(i64),
+    LitStr(String),
+    /// A call to a logic helper or to a method on the randomizer's `State` class. Helpers without parameters are also called without parentheses.
+    LogicHelper(String, Vec<Expr>),
+    Not(Box<Expr>),
+    /// A parameter of the logic helper whose body contains this expression.
+    Param(String),
+    Setting(String),
+    /// `is_starting_age`: whether the age is the one chosen by the `starting_age` setting.
+    StartingAge,
+    /// `at_day`, `at_night`, or `at_dampe_time`.
+    TimeOfDay(TimeRange),
+    Trick(String),
+    /// `skipped_trials[Element]` is parsed as the negation of this.
+    TrialActive(Medallion),
+}
+
+impl Expr {
+    fn into_bool(self, parser: &RuleParser) -> Expr {
+        match self {
+            Expr::LitStr(name) => if let Some(item) = parser.item_table.get(&name) {
+                Expr::Item(item.clone(), Box::new(Expr::LitInt(1)))
+            } else {
+                Expr::Event(name)
+            },
+            expr => expr,
+        }
+    }
+}
+
+/// A logic helper as defined in the randomizer's `LogicHelpers.json`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, QuoteValue)]
+pub struct LogicHelper {
+    pub params: Vec<String>,
+    pub body: Expr,
+}
+
+impl LogicHelper {
+    /// Returns the body of this helper with the parameters replaced by the given arguments, or `None` if the number of arguments doesn't match.
+    pub fn call(&self, args: &[Expr]) -> Option<Expr> {
+        fn substitute(expr: &Expr, bindings: &HashMap<&str, &Expr>) -> Expr {
+            let sub = |expr: &Expr| Box::new(substitute(expr, bindings));
+            match expr {
+                Expr::All(exprs) => Expr::All(exprs.iter().map(|expr| substitute(expr, bindings)).collect()),
+                Expr::Any(exprs) => Expr::Any(exprs.iter().map(|expr| substitute(expr, bindings)).collect()),
+                Expr::Arith(lhs, op, rhs) => Expr::Arith(sub(lhs), *op, sub(rhs)),
+                Expr::At(region, expr) => Expr::At(region.clone(), sub(expr)),
+                Expr::Compare(lhs, op, rhs) => Expr::Compare(sub(lhs), *op, sub(rhs)),
+                Expr::Here(expr) => Expr::Here(sub(expr)),
+                Expr::Item(item, count) => Expr::Item(item.clone(), sub(count)),
+                Expr::LogicHelper(name, args) => Expr::LogicHelper(name.clone(), args.iter().map(|arg| substitute(arg, bindings)).collect()),
+                Expr::Not(expr) => Expr::Not(sub(expr)),
+                Expr::Param(param) => bindings.get(&**param).map_or_else(|| expr.clone(), |&arg| arg.clone()),
+                Expr::Age(_) |
+                Expr::Event(_) |
+                Expr::LitBool(_) |
+                Expr::LitFloat(_) |
+                Expr::LitInt(_) |
+                Expr::LitStr(_) |
+                Expr::Setting(_) |
+                Expr::StartingAge |
+                Expr::TimeOfDay(_) |
+                Expr::Trick(_) |
+                Expr::TrialActive(_) => expr.clone(),
+            }
+        }
+
+        if args.len() != self.params.len() { return None }
+        let bindings = self.params.iter().map(|param| &**param).zip(args).collect();
+        Some(substitute(&self.body, &bindings))
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::All(exprs) => if exprs.is_empty() {
+                write!(f, "True")
+            } else {
+                write!(f, "(")?;
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i > 0 { write!(f, " and ")?; }
+                    write!(f, "{}", expr)?;
+                }
+                write!(f, ")")
+            },
+            Expr::Any(exprs) => if exprs.is_empty() {
+                write!(f, "False")
+            } else {
+                write!(f, "(")?;
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i > 0 { write!(f, " or ")?; }
+                    write!(f, "{}", expr)?;
+                }
+                write!(f, ")")
+            },
+            Expr::Age(age) => write!(f, "is_{}", age),
+            Expr::Arith(lhs, op, rhs) => write!(f, "({} {} {})", lhs, op, rhs),
+            Expr::At(region, expr) => write!(f, "at({:?}, {})", region, expr),
+            Expr::Compare(lhs, op, rhs) => write!(f, "{} {} {}", lhs, op, rhs),
+            Expr::Event(event) => write!(f, "{:?}", event),
+            Expr::Here(expr) => write!(f, "here({})", expr),
+            Expr::Item(item, count) => if let Expr::LitInt(1) = **count {
+                write!(f, "{}", item.name())
+            } else {
+                write!(f, "({}, {})", item.name(), count)
+            },
+            Expr::LitBool(true) => write!(f, "True"),
+            Expr::LitBool(false) => write!(f, "False"),
+            Expr::LitFloat(n) => write!(f, "{}", n),
+            Expr::LitInt(n) => write!(f, "{}", n),
+            Expr::LitStr(s) => write!(f, "{:?}", s),
+            Expr::LogicHelper(name, args) => if args.is_empty() {
+                write!(f, "{}", name)
+            } else {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 { write!(f, ", ")?; }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            },
+            Expr::Not(expr) => write!(f, "not {}", expr),
+            Expr::Param(param) => write!(f, "{}", param),
+            Expr::Setting(setting) => write!(f, "{}", setting),
+            Expr::StartingAge => write!(f, "is_starting_age"),
+            Expr::TimeOfDay(TimeRange::Day) => write!(f, "at_day"),
+            Expr::TimeOfDay(TimeRange::Night) => write!(f, "at_night"),
+            Expr::TimeOfDay(TimeRange::Dampe) => write!(f, "at_dampe_time"),
+            Expr::Trick(trick) => write!(f, "{}", trick),
+            Expr::TrialActive(med) => write!(f, "not skipped_trials[{}]", med.element()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseError {
+    InvalidNumber(String),
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    UnexpectedToken(String),
+    UnknownTrial(String),
+    UnsupportedSubscript(String),
+    UnterminatedString,
+    WrongNumberOfArgs {
+        name: &'static str,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber(s) => write!(f, "invalid number literal: {}", s),
+            ParseError::UnexpectedChar(c) => write!(f, "unexpected character: {:?}", c),
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of rule"),
+            ParseError::UnexpectedToken(token) => write!(f, "unexpected token: {}", token),
+            ParseError::UnknownTrial(trial) => write!(f, "unknown trial: {}", trial),
+            ParseError::UnsupportedSubscript(name) => write!(f, "subscripting {} is not supported", name),
+            ParseError::UnterminatedString => write!(f, "unterminated string literal"),
+            ParseError::WrongNumberOfArgs { name, expected, found } => write!(f, "{} takes {} arguments but {} were given", name, expected, found),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Name(String),
+    Str(String),
+    Num(String),
+    Op(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Name(name) => write!(f, "{}", name),
+            Token::Str(s) => write!(f, "{:?}", s),
+            Token::Num(n) => write!(f, "{}", n),
+            Token::Op(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+fn tokenize(rule: &str) -> Result<Vec<Token>, ParseError> {
+    fn take_while(rule: &str, chars: &mut Peekable<CharIndices<'_>>, start: usize, mut pred: impl FnMut(char) -> bool) -> String {
+        let mut end = rule.len();
+        while let Some(&(idx, c)) = chars.peek() {
+            if pred(c) {
+                chars.next();
+            } else {
+                end = idx;
+                break
+            }
+        }
+        rule[start..end].to_owned()
+    }
+
+    let mut tokens = Vec::default();
+    let mut chars = rule.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => { chars.next(); }
+            'A'..='Z' | 'a'..='z' | '_' => tokens.push(Token::Name(take_while(rule, &mut chars, start, |c| c.is_ascii_alphanumeric() || c == '_'))),
+            '0'..='9' => tokens.push(Token::Num(take_while(rule, &mut chars, start, |c| c.is_ascii_digit() || c == '.'))),
+            '\'' | '"' => {
+                chars.next();
+                let mut s = String::default();
+                loop {
+                    match chars.next() {
+                        Some((_, end)) if end == c => break,
+                        Some((_, c)) => s.push(c),
+                        None => return Err(ParseError::UnterminatedString),
+                    }
+                }
+                tokens.push(Token::Str(s));
+            }
+            '(' | ')' | '[' | ']' | ',' => {
+                chars.next();
+                tokens.push(Token::Op(match c {
+                    '(' => "(",
+                    ')' => ")",
+                    '[' => "[",
+                    ']' => "]",
+                    ',' => ",",
+                    _ => unreachable!(),
+                }));
+            }
+            '+' | '-' | '*' | '%' => {
+                chars.next();
+                tokens.push(Token::Op(match c {
+                    '+' => "+",
+                    '-' => "-",
+                    '*' => "*",
+                    '%' => "%",
+                    _ => unreachable!(),
+                }));
+            }
+            '/' => {
+                chars.next();
+                tokens.push(Token::Op(if chars.next_if(|&(_, c)| c == '/').is_some() { "//" } else { "/" }));
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let eq = chars.next_if(|&(_, c)| c == '=').is_some();
+                tokens.push(Token::Op(match (c, eq) {
+                    ('=', true) => "==",
+                    ('!', true) => "!=",
+                    ('<', false) => "<",
+                    ('<', true) => "<=",
+                    ('>', false) => ">",
+                    ('>', true) => ">=",
+                    (c, _) => return Err(ParseError::UnexpectedChar(c)),
+                }));
+            }
+            c => return Err(ParseError::UnexpectedChar(c)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses access rules, resolving names using the randomizer data they refer to.
+pub struct RuleParser {
+    escaped_items: Arc<HashMap<String, Item>>,
+    item_table: Arc<HashMap<String, Item>>,
+    logic_tricks: Arc<HashSet<String>>,
+    setting_infos: Arc<HashMap<String, SettingInfo>>,
+}
+
+impl RuleParser {
+    pub fn new<R: Rando>(rando: &R) -> Result<RuleParser, R::Err> {
+        Ok(RuleParser {
+            escaped_items: rando.escaped_items()?,
+            item_table: rando.item_table()?,
+            logic_tricks: rando.logic_tricks()?,
+            setting_infos: rando.setting_infos()?,
+        })
+    }
+
+    pub fn parse(&self, rule: &str) -> Result<Expr, ParseError> {
+        self.parse_helper_body(rule, &[])
+    }
+
+    /// Parses the body of a logic helper, where the given parameter names refer to the helper's arguments.
+    pub fn parse_helper_body(&self, rule: &str, params: &[String]) -> Result<Expr, ParseError> {
+        let tokens = tokenize(rule)?;
+        let mut tokens = tokens.into_iter().peekable();
+        let expr = self.parse_or(&mut tokens, params)?.into_bool(self);
+        if let Some(token) = tokens.next() {
+            return Err(ParseError::UnexpectedToken(token.to_string()))
+        }
+        Ok(expr)
+    }
+
+    fn parse_or(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        let first = self.parse_and(tokens, params)?;
+        if tokens.peek() != Some(&Token::Name(format!("or"))) { return Ok(first) }
+        let mut exprs = vec![first.into_bool(self)];
+        while tokens.next_if_eq(&Token::Name(format!("or"))).is_some() {
+            exprs.push(self.parse_and(tokens, params)?.into_bool(self));
+        }
+        Ok(Expr::Any(exprs))
+    }
+
+    fn parse_and(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        let first = self.parse_not(tokens, params)?;
+        if tokens.peek() != Some(&Token::Name(format!("and"))) { return Ok(first) }
+        let mut exprs = vec![first.into_bool(self)];
+        while tokens.next_if_eq(&Token::Name(format!("and"))).is_some() {
+            exprs.push(self.parse_not(tokens, params)?.into_bool(self));
+        }
+        Ok(Expr::All(exprs))
+    }
+
+    fn parse_not(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        if tokens.next_if_eq(&Token::Name(format!("not"))).is_some() {
+            Ok(Expr::Not(Box::new(self.parse_not(tokens, params)?.into_bool(self))))
+        } else {
+            self.parse_comparison(tokens, params)
+        }
+    }
+
+    fn parse_comparison(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        let lhs = self.parse_sum(tokens, params)?;
+        let op = match tokens.peek() {
+            Some(Token::Op("==")) => CmpOp::Eq,
+            Some(Token::Op("!=")) => CmpOp::Ne,
+            Some(Token::Op("<")) => CmpOp::Lt,
+            Some(Token::Op("<=")) => CmpOp::Le,
+            Some(Token::Op(">")) => CmpOp::Gt,
+            Some(Token::Op(">=")) => CmpOp::Ge,
+            Some(Token::Name(name)) if name == "in" => CmpOp::In,
+            Some(Token::Name(name)) if name == "not" => CmpOp::NotIn,
+            _ => return Ok(lhs),
+        };
+        tokens.next();
+        if op == CmpOp::NotIn {
+            self.expect(tokens, Token::Name(format!("in")))?;
+        }
+        let rhs = self.parse_sum(tokens, params)?;
+        Ok(Expr::Compare(Box::new(lhs), op, Box::new(rhs)))
+    }
+
+    fn parse_sum(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        let mut expr = self.parse_product(tokens, params)?;
+        loop {
+            let op = match tokens.peek() {
+                Some(Token::Op("+")) => ArithOp::Add,
+                Some(Token::Op("-")) => ArithOp::Sub,
+                _ => return Ok(expr),
+            };
+            tokens.next();
+            expr = Expr::Arith(Box::new(expr), op, Box::new(self.parse_product(tokens, params)?));
+        }
+    }
+
+    fn parse_product(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        let mut expr = self.parse_unary(tokens, params)?;
+        loop {
+            let op = match tokens.peek() {
+                Some(Token::Op("*")) => ArithOp::Mul,
+                Some(Token::Op("/")) => ArithOp::Div,
+                Some(Token::Op("//")) => ArithOp::FloorDiv,
+                Some(Token::Op("%")) => ArithOp::Mod,
+                _ => return Ok(expr),
+            };
+            tokens.next();
+            expr = Expr::Arith(Box::new(expr), op, Box::new(self.parse_unary(tokens, params)?));
+        }
+    }
+
+    fn parse_unary(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        if tokens.next_if_eq(&Token::Op("-")).is_some() {
+            Ok(match self.parse_unary(tokens, params)? {
+                Expr::LitInt(n) => Expr::LitInt(-n),
+                Expr::LitFloat(n) => Expr::LitFloat(-n),
+                expr => Expr::Arith(Box::new(Expr::LitInt(0)), ArithOp::Sub, Box::new(expr)),
+            })
+        } else {
+            self.parse_atom(tokens, params)
+        }
+    }
+
+    fn parse_atom(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, params: &[String]) -> Result<Expr, ParseError> {
+        match tokens.next().ok_or(ParseError::UnexpectedEnd)? {
+            Token::Op("(") => {
+                let expr = self.parse_or(tokens, params)?;
+                if tokens.next_if_eq(&Token::Op(",")).is_some() {
+                    // (item, count) tuple
+                    let count = self.parse_or(tokens, params)?;
+                    self.expect(tokens, Token::Op(")"))?;
+                    match expr.into_bool(self) {
+                        Expr::Item(item, _) => Ok(Expr::Item(item, Box::new(count))),
+                        expr => Err(ParseError::UnexpectedToken(expr.to_string())),
+                    }
+                } else {
+                    self.expect(tokens, Token::Op(")"))?;
+                    Ok(expr)
+                }
+            }
+            Token::Str(s) => Ok(Expr::LitStr(s)),
+            Token::Num(n) => if n.contains('.') {
+                n.parse().map(Expr::LitFloat).map_err(|_| ParseError::InvalidNumber(n))
+            } else {
+                n.parse().map(Expr::LitInt).map_err(|_| ParseError::InvalidNumber(n))
+            },
+            Token::Name(name) => if tokens.next_if_eq(&Token::Op("(")).is_some() {
+                let mut args = Vec::default();
+                if tokens.next_if_eq(&Token::Op(")")).is_none() {
+                    loop {
+                        args.push(self.parse_or(tokens, params)?);
+                        if tokens.next_if_eq(&Token::Op(")")).is_some() { break }
+                        self.expect(tokens, Token::Op(","))?;
+                    }
+                }
+                self.parse_call(name, args)
+            } else if tokens.next_if_eq(&Token::Op("[")).is_some() {
+                let index = match tokens.next().ok_or(ParseError::UnexpectedEnd)? {
+                    Token::Name(index) | Token::Str(index) => index,
+                    token => return Err(ParseError::UnexpectedToken(token.to_string())),
+                };
+                self.expect(tokens, Token::Op("]"))?;
+                if name == "skipped_trials" {
+                    let med = enum_iterator::all::<Medallion>().find(|med| med.element() == index).ok_or(ParseError::UnknownTrial(index))?;
+                    Ok(Expr::Not(Box::new(Expr::TrialActive(med))))
+                } else {
+                    Err(ParseError::UnsupportedSubscript(name))
+                }
+            } else {
+                Ok(self.parse_name(name, params))
+            },
+            token => Err(ParseError::UnexpectedToken(token.to_string())),
+        }
+    }
+
+    fn parse_call(&self, name: String, mut args: Vec<Expr>) -> Result<Expr, ParseError> {
+        match &*name {
+            "at" => if args.len() == 2 {
+                let rule = args.pop().expect("checked length").into_bool(self);
+                match args.pop().expect("checked length") {
+                    Expr::LitStr(region) => Ok(Expr::At(region, Box::new(rule))),
+                    region => Err(ParseError::UnexpectedToken(region.to_string())),
+                }
+            } else {
+                Err(ParseError::WrongNumberOfArgs { name: "at", expected: 2, found: args.len() })
+            },
+            "here" => if args.len() == 1 {
+                Ok(Expr::Here(Box::new(args.pop().expect("checked length").into_bool(self))))
+            } else {
+                Err(ParseError::WrongNumberOfArgs { name: "here", expected: 1, found: args.len() })
+            },
+            _ => Ok(Expr::LogicHelper(name, args)),
+        }
+    }
+
+    fn parse_name(&self, name: String, params: &[String]) -> Expr {
+        match &*name {
+            "True" => Expr::LitBool(true),
+            "False" => Expr::LitBool(false),
+            "is_child" => Expr::Age(Age::Child),
+            "is_adult" => Expr::Age(Age::Adult),
+            "is_starting_age" => Expr::StartingAge,
+            "at_day" => Expr::TimeOfDay(TimeRange::Day),
+            "at_night" => Expr::TimeOfDay(TimeRange::Night),
+            "at_dampe_time" => Expr::TimeOfDay(TimeRange::Dampe),
+            _ => if params.contains(&name) {
+                Expr::Param(name)
+            } else if let Some(item) = self.escaped_items.get(&name) {
+                Expr::Item(item.clone(), Box::new(Expr::LitInt(1)))
+            } else if self.logic_tricks.contains(&name) {
+                Expr::Trick(name)
+            } else if self.setting_infos.contains_key(&name) {
+                Expr::Setting(name)
+            } else {
+                Expr::LogicHelper(name, Vec::default())
+            },
+        }
+    }
+
+    fn expect(&self, tokens: &mut Peekable<impl Iterator<Item = Token>>, expected: Token) -> Result<(), ParseError> {
+        match tokens.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+            None => Err(ParseError::UnexpectedEnd),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser() -> RuleParser {
+        RuleParser {
+            escaped_items: Arc::new(HashMap::from([(format!("Bow"), Item(format!("Bow")))])),
+            item_table: Arc::new(HashMap::from([(format!("Bow"), Item(format!("Bow"))), (format!("Gold Skulltula Token"), Item(format!("Gold Skulltula Token")))])),
+            logic_tricks: Arc::new(HashSet::from([format!("logic_grottos_without_agony")])),
+            setting_infos: Arc::default(),
+        }
+    }
+
+    fn int(n: i64) -> Box<Expr> { Box::new(Expr::LitInt(n)) }
+
+    #[test]
+    fn names() {
+        let parser = parser();
+        assert_eq!(parser.parse("True").unwrap(), Expr::LitBool(true));
+        assert_eq!(parser.parse("is_adult").unwrap(), Expr::Age(Age::Adult));
+        assert_eq!(parser.parse("Bow").unwrap(), Expr::Item(Item(format!("Bow")), int(1)));
+        assert_eq!(parser.parse("logic_grottos_without_agony").unwrap(), Expr::Trick(format!("logic_grottos_without_agony")));
+        assert_eq!(parser.parse("'Drain Well'").unwrap(), Expr::Event(format!("Drain Well")));
+        assert_eq!(parser.parse("can_play(Song_of_Time)").unwrap(), Expr::LogicHelper(format!("can_play"), vec![Expr::LogicHelper(format!("Song_of_Time"), Vec::default())]));
+    }
+
+    #[test]
+    fn boolean_ops() {
+        let parser = parser();
+        assert_eq!(parser.parse("is_child and not Bow or at_night").unwrap(), Expr::Any(vec![
+            Expr::All(vec![
+                Expr::Age(Age::Child),
+                Expr::Not(Box::new(Expr::Item(Item(format!("Bow")), int(1)))),
+            ]),
+            Expr::TimeOfDay(TimeRange::Night),
+        ]));
+        assert_eq!(parser.parse("skipped_trials[Forest]").unwrap(), Expr::Not(Box::new(Expr::TrialActive(Medallion::Forest))));
+        assert_eq!(parser.parse("here(Bow)").unwrap(), Expr::Here(Box::new(Expr::Item(Item(format!("Bow")), int(1)))));
+    }
+
+    #[test]
+    fn item_counts() {
+        let parser = parser();
+        assert_eq!(parser.parse("('Gold Skulltula Token', 300)").unwrap(), Expr::Item(Item(format!("Gold Skulltula Token")), int(300)));
+        assert_eq!(parser.parse("(Bow, 2)").unwrap(), Expr::Item(Item(format!("Bow")), int(2)));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let parser = parser();
+        assert_eq!(parser.parse("x - 1 >= 2 * 3 // 4").unwrap(), Expr::Compare(
+            Box::new(Expr::Arith(Box::new(Expr::LogicHelper(format!("x"), Vec::default())), ArithOp::Sub, int(1))),
+            CmpOp::Ge,
+            Box::new(Expr::Arith(Box::new(Expr::Arith(int(2), ArithOp::Mul, int(3))), ArithOp::FloorDiv, int(4))),
+        ));
+        assert_eq!(parser.parse("-1 + 2 % 3").unwrap(), Expr::Arith(int(-1), ArithOp::Add, Box::new(Expr::Arith(int(2), ArithOp::Mod, int(3)))));
+        assert_eq!(parser.parse("-(1)").unwrap(), Expr::LitInt(-1));
+        assert_eq!(
+            parser.parse_helper_body("-n < 0", &[format!("n")]).unwrap(),
+            Expr::Compare(Box::new(Expr::Arith(int(0), ArithOp::Sub, Box::new(Expr::Param(format!("n"))))), CmpOp::Lt, int(0)),
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let parser = parser();
+        assert!(matches!(parser.parse("Bow and"), Err(ParseError::UnexpectedEnd)));
+        assert!(matches!(parser.parse("'Bow"), Err(ParseError::UnterminatedString)));
+        assert!(matches!(parser.parse("Bow $"), Err(ParseError::UnexpectedChar(_))));
+        assert!(matches!(parser.parse("skipped_trials[Earth]"), Err(ParseError::UnknownTrial(_))));
+        assert!(matches!(parser.parse("at('Root')"), Err(ParseError::WrongNumberOfArgs { name: "at", expected: 2, found: 1 })));
+    }
+}
@@ -0,0 +1,99 @@
+//! A serializable snapshot of the data a [`Rando`] implementation provides.
+//!
+//! Bundles can be exported once from a copy of the randomizer and then used by builds of `ootr-static` which don't have network access or a Python install.
+
+use {
+    std::collections::{
+        HashMap,
+        HashSet,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    crate::{
+        Rando,
+        access::{
+            Expr,
+            LogicHelper,
+        },
+        item::Item,
+        model::Dungeon,
+        region::{
+            EntrancePair,
+            Mq,
+            Region,
+        },
+        setting::SettingInfo,
+    },
+};
+
+/// Incremented whenever the format changes in a way that requires bundles to be exported again.
+pub const FORMAT_VERSION: u8 = 4;
+
+/// A region with its name and exits as plain strings, independent of the [`Rando`] implementation it was taken from.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BundleRegion {
+    pub name: String,
+    pub dungeon: Option<(Dungeon, Mq)>,
+    pub scene: Option<String>,
+    pub hint: Option<String>,
+    pub time_passes: bool,
+    pub events: HashMap<String, Expr>,
+    pub locations: HashMap<String, Expr>,
+    pub exits: HashMap<String, Expr>,
+}
+
+impl<R: Rando> From<&Region<R>> for BundleRegion {
+    fn from(region: &Region<R>) -> BundleRegion {
+        BundleRegion {
+            name: region.name.as_ref().to_owned(),
+            dungeon: region.dungeon,
+            scene: region.scene.clone(),
+            hint: region.hint.clone(),
+            time_passes: region.time_passes,
+            events: region.events.clone(),
+            locations: region.locations.clone(),
+            exits: region.exits.iter().map(|(to, rule)| (to.as_ref().to_owned(), rule.clone())).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Bundle {
+    pub format_version: u8,
+    /// The randomizer's own version string, e.g. `6.2.205 R-1`.
+    pub version: String,
+    pub entrances: Vec<EntrancePair>,
+    pub escaped_items: HashMap<String, Item>,
+    pub get_item_ids: HashMap<String, u16>,
+    pub item_table: HashMap<String, Item>,
+    pub logic_helpers: HashMap<String, LogicHelper>,
+    pub logic_tricks: HashSet<String>,
+    pub regions: Vec<BundleRegion>,
+    pub glitched_regions: Vec<BundleRegion>,
+    pub setting_infos: HashMap<String, SettingInfo>,
+    pub starting_items: HashMap<String, Item>,
+    pub vanilla_items: HashMap<String, Item>,
+}
+
+impl Bundle {
+    /// Collects all data from the given randomizer, which reports its version as `version`.
+    pub fn from_rando<R: Rando>(rando: &R, version: String) -> Result<Bundle, R::Err> {
+        Ok(Bundle {
+            format_version: FORMAT_VERSION,
+            version,
+            entrances: (*rando.entrances()?).clone(),
+            escaped_items: (*rando.escaped_items()?).clone(),
+            get_item_ids: (*rando.get_item_ids()?).clone(),
+            item_table: (*rando.item_table()?).clone(),
+            logic_helpers: (*rando.logic_helpers()?).clone(),
+            logic_tricks: (*rando.logic_tricks()?).clone(),
+            regions: rando.regions(false)?.iter().map(|region| BundleRegion::from(&**region)).collect(),
+            glitched_regions: rando.regions(true)?.iter().map(|region| BundleRegion::from(&**region)).collect(),
+            setting_infos: (*rando.setting_infos()?).clone(),
+            starting_items: (*rando.starting_items()?).clone(),
+            vanilla_items: (*rando.vanilla_items()?).clone(),
+        })
+    }
+}
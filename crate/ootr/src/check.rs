@@ -31,11 +31,26 @@ pub enum Check<R: Rando> {
     LogicHelper(String),
     /// Check whether the given dungeon is MQ or vanilla.
     Mq(Dungeon),
-    Setting(String), //TODO include the partitions that can be checked
+    Setting { //TODO include the partitions that can be checked
+        name: String,
+        /// Only used for display, so checks constructed with and without it compare equal.
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
+        display_name: Option<String>,
+    },
     TrialActive(Medallion),
     Trick(String),
 }
 
+impl<R: Rando> Check<R> {
+    /// A check for the given setting, with the display name taken from the rando data.
+    pub fn setting(rando: &R, name: String) -> Result<Check<R>, R::Err> {
+        Ok(Check::Setting {
+            display_name: rando.setting_infos()?.get(&name).and_then(|info| info.display_name.clone()),
+            name,
+        })
+    }
+}
+
 impl<R: Rando> fmt::Display for Check<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -45,7 +60,7 @@ impl<R: Rando> fmt::Display for Check<R> {
             Check::Location(loc) => loc.fmt(f),
             Check::LogicHelper(fn_name) => write!(f, "logic helper {:?}", fn_name),
             Check::Mq(dungeon) => write!(f, "is {} MQ or vanilla", dungeon),
-            Check::Setting(setting) => write!(f, "setting: {}", setting), //TODO show setting's display name
+            Check::Setting { name, display_name } => write!(f, "setting: {}", display_name.as_ref().unwrap_or(name)),
             Check::TrialActive(med) => write!(f, "{} trial active", med.element()),
             Check::Trick(trick) => write!(f, "trick: {}", trick), //TODO show trick's display name
         }
@@ -1,4 +1,5 @@
 use {
+    async_proto::Protocol,
     quote_value::QuoteValue,
     serde::{
         Deserialize,
@@ -10,7 +11,7 @@ use {
     },
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Protocol, Deserialize, Serialize, QuoteValue)]
 #[serde(transparent)]
 pub struct Item(pub String);
 
@@ -17,15 +17,23 @@ use {
     },
     semver::Version,
     crate::{
+        access::LogicHelper,
         item::Item,
-        region::Region,
+        region::{
+            EntrancePair,
+            Region,
+        },
+        setting::SettingInfo,
     },
 };
 
+pub mod access;
+pub mod bundle;
 pub mod check;
 pub mod item;
 pub mod model;
 pub mod region;
+pub mod setting;
 
 pub trait RandoErr: fmt::Debug + fmt::Display + Clone + Send {
     const ITEM_NOT_FOUND: Self;
@@ -35,12 +43,24 @@ pub trait Rando: Sized {
     type Err: RandoErr;
     type RegionName: Clone + Eq + Hash + From<&'static str> + AsRef<str> + for<'a> PartialEq<&'a str> + fmt::Debug + fmt::Display + Send;
 
+    /// The randomizer's entrance table, limited to entrances whose entrance index is known.
+    fn entrances(&self) -> Result<Arc<Vec<EntrancePair>>, Self::Err>;
     fn escaped_items(&self) -> Result<Arc<HashMap<String, Item>>, Self::Err>;
+    /// The IDs the game uses for giving each item to Link, by item name. Items without a get-item ID, such as events, are omitted.
+    fn get_item_ids(&self) -> Result<Arc<HashMap<String, u16>>, Self::Err>;
     fn item_table(&self) -> Result<Arc<HashMap<String, Item>>, Self::Err>;
+    /// Logic helpers by name.
+    fn logic_helpers(&self) -> Result<Arc<HashMap<String, LogicHelper>>, Self::Err>;
     fn logic_tricks(&self) -> Result<Arc<HashSet<String>>, Self::Err>;
-    fn regions(&self) -> Result<Arc<Vec<Arc<Region<Self>>>>, Self::Err>;
+    /// The world graph, either glitchless (`data/World`) or glitched (`data/Glitched World`).
+    fn regions(&self, glitched: bool) -> Result<Arc<Vec<Arc<Region<Self>>>>, Self::Err>;
     fn root() -> Self::RegionName;
-    fn setting_infos(&self) -> Result<Arc<HashSet<String>>, Self::Err>;
+    /// Setting metadata by setting name.
+    fn setting_infos(&self) -> Result<Arc<HashMap<String, SettingInfo>>, Self::Err>;
+    /// The items granted by each choice of the starting items settings, keyed by choice value.
+    fn starting_items(&self) -> Result<Arc<HashMap<String, Item>>, Self::Err>;
+    /// The item at each location in the vanilla game, by location name. Locations without a vanilla item, such as hints, are omitted.
+    fn vanilla_items(&self) -> Result<Arc<HashMap<String, Item>>, Self::Err>;
 }
 
 pub fn version() -> Version {
@@ -59,6 +59,9 @@ impl FromStr for Dungeon {
     }
 }
 
+derive_deserialize_from_fromstr!(Dungeon, "dungeon");
+derive_serialize_from_display!(Dungeon);
+
 impl fmt::Display for Dungeon {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -331,7 +334,7 @@ impl From<Stone> for Item {
     }
 }
 
-#[derive(Debug, Clone, Copy, QuoteValue)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sequence, Deserialize, Serialize, QuoteValue)]
 pub enum TimeRange {
     /// 06:00–18:00.
     ///
@@ -1,6 +1,6 @@
 use {
     std::{
-        collections::HashSet,
+        collections::HashMap,
         fmt,
         hash::{
             Hash,
@@ -15,6 +15,7 @@ use {
     },
     crate::{
         Rando,
+        access::Expr,
         model::Dungeon,
     },
 };
@@ -43,9 +44,27 @@ pub struct Region<R: Rando> {
     pub scene: Option<String>, //TODO use Scene type from oottracker?
     pub hint: Option<String>,
     pub time_passes: bool,
-    pub events: HashSet<String>,
-    pub locations: HashSet<String>,
-    pub exits: HashSet<R::RegionName>,
+    pub events: HashMap<String, Expr>,
+    pub locations: HashMap<String, Expr>,
+    pub exits: HashMap<R::RegionName, Expr>,
+}
+
+/// One direction of a shuffleable entrance from the randomizer's entrance table.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub struct EntranceInfo {
+    pub from: String,
+    pub to: String,
+    /// The entrance index the game uses when entering `to` this way.
+    pub index: u16,
+}
+
+/// A shuffleable entrance along with the one leading back, if the randomizer pairs them.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub struct EntrancePair {
+    /// The entrance type from the randomizer's entrance table, e.g. `Dungeon` or `Interior`.
+    pub kind: String,
+    pub forward: EntranceInfo,
+    pub reverse: Option<EntranceInfo>,
 }
 
 impl<R: Rando> PartialEq for Region<R> {
@@ -0,0 +1,80 @@
+//! Metadata about the randomizer's settings, as defined in its `SettingsList.py`.
+
+use {
+    std::fmt,
+    quote_value::QuoteValue,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub enum SettingType {
+    Bool,
+    Int {
+        min: Option<i64>,
+        max: Option<i64>,
+        step: i64,
+    },
+    Str,
+    List,
+    /// A type the tracker doesn't support, e.g. a dictionary.
+    Other,
+}
+
+impl fmt::Display for SettingType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingType::Bool => write!(f, "Boolean"),
+            SettingType::Int { .. } => write!(f, "integer"),
+            SettingType::Str => write!(f, "string"),
+            SettingType::List => write!(f, "list"),
+            SettingType::Other => write!(f, "unsupported type"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub enum SettingValue {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    List(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub struct SettingChoice {
+    pub value: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, QuoteValue)]
+pub struct SettingInfo {
+    pub name: String,
+    /// Position in the randomizer's list of settings, which is also the order in which settings strings encode them.
+    pub index: usize,
+    /// Whether this setting affects seed generation. Only shared settings are encoded in settings strings.
+    pub shared: bool,
+    /// The number of bits this setting takes up in a settings string.
+    pub bitwidth: u32,
+    /// The label shown in the randomizer's GUI, if any.
+    pub display_name: Option<String>,
+    pub ty: SettingType,
+    /// For `Str` and `List` settings, the allowed values in the order the randomizer's GUI shows them. Empty if any value is allowed.
+    pub choices: Vec<SettingChoice>,
+    /// `None` if the default value is of a type the tracker doesn't support.
+    pub default: Option<SettingValue>,
+}
+
+impl SettingInfo {
+    /// Returns the display name if there is one, or the internal name otherwise.
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Checks whether the given value is allowed for a `Str` or `List` setting.
+    pub fn allows_choice(&self, value: &str) -> bool {
+        self.choices.is_empty() || self.choices.iter().any(|choice| choice.value == value)
+    }
+}
@@ -63,10 +63,24 @@ namespace Net.Fenhl.OotAutoTracker {
         [DllImport("oottracker")] internal static extern SavesDiff saves_diff(Save old_save, Save new_save);
         [DllImport("oottracker")] internal static extern void saves_diff_free(IntPtr diff);
         [DllImport("oottracker")] internal static extern UnitResult saves_diff_send(TcpStream tcp_stream, IntPtr diff);
+        [DllImport("oottracker")] internal static extern RandoResult rando_for_rom_version(byte major, byte minor, byte patch);
+        [DllImport("oottracker")] internal static extern void rando_result_free(IntPtr rando_res);
+        [DllImport("oottracker")] internal static extern bool rando_result_is_ok(RandoResult rando_res);
+        [DllImport("oottracker")] internal static extern Rando rando_result_unwrap(IntPtr rando_res);
+        [DllImport("oottracker")] internal static extern StringHandle rando_result_debug_err(IntPtr rando_res);
+        [DllImport("oottracker")] internal static extern void rando_free(IntPtr rando);
         [DllImport("oottracker")] internal static extern Knowledge knowledge_none();
-        [DllImport("oottracker")] internal static extern Knowledge knowledge_vanilla();
+        [DllImport("oottracker")] internal static extern KnowledgeResult knowledge_vanilla(Rando rando);
+        [DllImport("oottracker")] internal static extern void knowledge_result_free(IntPtr knowledge_res);
+        [DllImport("oottracker")] internal static extern bool knowledge_result_is_ok(KnowledgeResult knowledge_res);
+        [DllImport("oottracker")] internal static extern Knowledge knowledge_result_unwrap(IntPtr knowledge_res);
+        [DllImport("oottracker")] internal static extern StringHandle knowledge_result_debug_err(IntPtr knowledge_res);
         [DllImport("oottracker")] internal static extern void knowledge_free(IntPtr knowledge);
         [DllImport("oottracker")] internal static extern UnitResult knowledge_send(TcpStream tcp_stream, Knowledge knowledge);
+        [DllImport("oottracker")] internal static extern bool knowledge_equal(Knowledge knowledge1, Knowledge knowledge2);
+        [DllImport("oottracker")] internal static extern KnowledgeDiff knowledge_diff(Knowledge old_knowledge, Knowledge new_knowledge);
+        [DllImport("oottracker")] internal static extern void knowledge_diff_free(IntPtr diff);
+        [DllImport("oottracker")] internal static extern UnitResult knowledge_diff_send(TcpStream tcp_stream, IntPtr diff);
         [DllImport("oottracker")] internal static extern ModelState model_new(IntPtr save, IntPtr knowledge);
         [DllImport("oottracker")] internal static extern void model_free(IntPtr model);
         [DllImport("oottracker")] internal static extern byte ram_num_ranges();
@@ -78,9 +92,10 @@ namespace Net.Fenhl.OotAutoTracker {
         [DllImport("oottracker")] internal static extern StringHandle ram_result_debug_err(IntPtr ram_res);
         [DllImport("oottracker")] internal static extern void ram_free(IntPtr ram);
         [DllImport("oottracker")] internal static extern bool ram_equal(Ram ram1, Ram ram2);
-        [DllImport("oottracker")] internal static extern void model_set_ram(ModelState model, Ram ram);
+        [DllImport("oottracker")] internal static extern UnitResult model_set_ram(ModelState model, Rando rando, Ram ram);
         [DllImport("oottracker")] internal static extern Save ram_clone_save(Ram ram);
-        [DllImport("oottracker")] internal static extern void model_set_tracker_ctx(ModelState model, int length, IntPtr data);
+        [DllImport("oottracker")] internal static extern Knowledge model_clone_knowledge(ModelState model);
+        [DllImport("oottracker")] internal static extern UnitResult model_set_tracker_ctx(ModelState model, Rando rando, int length, IntPtr data);
     }
 
     internal class StringHandle : SafeHandle {
@@ -376,6 +391,81 @@ namespace Net.Fenhl.OotAutoTracker {
         }
     }
 
+    internal class RandoResult : SafeHandle {
+        internal RandoResult() : base(IntPtr.Zero, true) {}
+
+        public override bool IsInvalid {
+            get { return this.handle == IntPtr.Zero; }
+        }
+
+        protected override bool ReleaseHandle() {
+            if (!this.IsInvalid) {
+                Native.rando_result_free(this.handle);
+            }
+            return true;
+        }
+
+        internal bool IsOk() => Native.rando_result_is_ok(this);
+
+        internal Rando Unwrap() {
+            var rando = Native.rando_result_unwrap(this.handle);
+            this.handle = IntPtr.Zero; // rando_result_unwrap takes ownership
+            return rando;
+        }
+
+        internal StringHandle DebugErr() {
+            var err = Native.rando_result_debug_err(this.handle);
+            this.handle = IntPtr.Zero; // rando_result_debug_err takes ownership
+            return err;
+        }
+    }
+
+    internal class Rando : SafeHandle {
+        internal Rando() : base(IntPtr.Zero, true) {}
+
+        public override bool IsInvalid {
+            get { return this.handle == IntPtr.Zero; }
+        }
+
+        protected override bool ReleaseHandle() {
+            if (!this.IsInvalid) {
+                Native.rando_free(this.handle);
+            }
+            return true;
+        }
+
+        internal KnowledgeResult VanillaKnowledge() => Native.knowledge_vanilla(this);
+    }
+
+    internal class KnowledgeResult : SafeHandle {
+        internal KnowledgeResult() : base(IntPtr.Zero, true) {}
+
+        public override bool IsInvalid {
+            get { return this.handle == IntPtr.Zero; }
+        }
+
+        protected override bool ReleaseHandle() {
+            if (!this.IsInvalid) {
+                Native.knowledge_result_free(this.handle);
+            }
+            return true;
+        }
+
+        internal bool IsOk() => Native.knowledge_result_is_ok(this);
+
+        internal Knowledge Unwrap() {
+            var knowledge = Native.knowledge_result_unwrap(this.handle);
+            this.handle = IntPtr.Zero; // knowledge_result_unwrap takes ownership
+            return knowledge;
+        }
+
+        internal StringHandle DebugErr() {
+            var err = Native.knowledge_result_debug_err(this.handle);
+            this.handle = IntPtr.Zero; // knowledge_result_debug_err takes ownership
+            return err;
+        }
+    }
+
     internal class Knowledge : SafeHandle {
         internal Knowledge() : base(IntPtr.Zero, true) {}
 
@@ -397,6 +487,29 @@ namespace Net.Fenhl.OotAutoTracker {
         }
 
         internal UnitResult Send(TcpStream tcp_stream) => Native.knowledge_send(tcp_stream, this);
+        internal bool Equals(Knowledge other) => Native.knowledge_equal(this, other);
+        internal KnowledgeDiff Diff(Knowledge other) => Native.knowledge_diff(this, other);
+    }
+
+    internal class KnowledgeDiff : SafeHandle {
+        internal KnowledgeDiff() : base(IntPtr.Zero, true) {}
+
+        public override bool IsInvalid {
+            get { return this.handle == IntPtr.Zero; }
+        }
+
+        protected override bool ReleaseHandle() {
+            if (!this.IsInvalid) {
+                Native.knowledge_diff_free(this.handle);
+            }
+            return true;
+        }
+
+        internal UnitResult Send(TcpStream tcp_stream) {
+            var unit_res = Native.knowledge_diff_send(tcp_stream, this.handle);
+            this.handle = IntPtr.Zero; // knowledge_diff_send takes ownership
+            return unit_res;
+        }
     }
 
     internal class ModelState : SafeHandle {
@@ -419,12 +532,13 @@ namespace Net.Fenhl.OotAutoTracker {
             return true;
         }
 
-        public void SetRam(Ram ram) => Native.model_set_ram(this, ram);
+        internal UnitResult SetRam(Rando rando, Ram ram) => Native.model_set_ram(this, rando, ram);
+        internal Knowledge CloneKnowledge() => Native.model_clone_knowledge(this);
 
-        internal void SetAutoTrackerContext(IMemoryApi memoryApi, long addr, int length) {
+        internal UnitResult SetAutoTrackerContext(Rando rando, IMemoryApi memoryApi, long addr, int length) {
             IntPtr data = Marshal.AllocHGlobal(length);
             Marshal.Copy(memoryApi.ReadByteRange(addr, length, "System Bus").ToArray(), 0, data, length);
-            Native.model_set_tracker_ctx(this, length, data);
+            return Native.model_set_tracker_ctx(this, rando, length, data);
         }
     }
 
@@ -550,6 +664,7 @@ namespace Net.Fenhl.OotAutoTracker {
         private bool initialized = false;
         private Config cfg = Native.config_default();
         private bool isVanilla;
+        private Rando? rando;
         //private TcpStream? stream;
         private uint? autoTrackerContextAddr;
         private uint autoTrackerContextVersion = 0;
@@ -557,6 +672,7 @@ namespace Net.Fenhl.OotAutoTracker {
         private Ram? prevRam;
         private List<byte> prevSaveData = new List<byte>();
         private Save? prevSave;
+        private Knowledge? prevKnowledge;
         private ModelState model = ModelState.FromSaveAndKnowledge(Native.save_default(), Native.knowledge_none());
         private string[] cellImages = new string[52];
 
@@ -760,6 +876,8 @@ namespace Net.Fenhl.OotAutoTracker {
 
             APIs.Memory.SetBigEndian(true);
             this.model.Dispose();
+            if (this.rando != null) { this.rando.Dispose(); }
+            this.rando = null;
             /*
             if (this.stream != null) { this.stream.Disconnect().Dispose(); }
             this.stream = null;
@@ -767,6 +885,8 @@ namespace Net.Fenhl.OotAutoTracker {
             */
             if (this.prevSave != null) { this.prevSave.Dispose(); }
             this.prevSave = null;
+            if (this.prevKnowledge != null) { this.prevKnowledge.Dispose(); }
+            this.prevKnowledge = null;
             UpdateSave(false, "Save: waiting for game");
             if ((APIs.GameInfo.GetGameInfo()?.Name ?? "Null") == "Null") {
                 this.model = ModelState.FromSaveAndKnowledge(Native.save_default(), Native.knowledge_none());
@@ -779,10 +899,30 @@ namespace Net.Fenhl.OotAutoTracker {
                 } else {
                     var version = rom_ident.GetRange(0x15, 3);
                     this.isVanilla = Enumerable.SequenceEqual(version, new List<byte>(new byte[] { 0, 0, 0 }));
-                    this.model = ModelState.FromSaveAndKnowledge(Native.save_default(), this.isVanilla ? Native.knowledge_vanilla() : Native.knowledge_none());
+                    using (var rando_res = Native.rando_for_rom_version(version[0], version[1], version[2])) {
+                        if (rando_res.IsOk()) {
+                            this.rando = rando_res.Unwrap();
+                        } else {
+                            this.model = ModelState.FromSaveAndKnowledge(Native.save_default(), Native.knowledge_none());
+                            UpdateGame(false, $"Failed to load randomizer data: {rando_res.DebugErr().AsString()}");
+                            UpdateCells();
+                            return;
+                        }
+                    }
                     if (this.isVanilla) {
+                        using (var knowledge_res = this.rando.VanillaKnowledge()) {
+                            if (knowledge_res.IsOk()) {
+                                this.model = ModelState.FromSaveAndKnowledge(Native.save_default(), knowledge_res.Unwrap());
+                            } else {
+                                this.model = ModelState.FromSaveAndKnowledge(Native.save_default(), Native.knowledge_none());
+                                UpdateGame(false, $"Failed to load randomizer data: {knowledge_res.DebugErr().AsString()}");
+                                UpdateCells();
+                                return;
+                            }
+                        }
                         UpdateGame(true, "Playing OoT (vanilla)");
                     } else {
+                        this.model = ModelState.FromSaveAndKnowledge(Native.save_default(), Native.knowledge_none());
                         UpdateGame(true, $"Playing OoTR version {version[0]}.{version[1]}.{version[2]}");
                     }
                     /*
@@ -791,10 +931,8 @@ namespace Net.Fenhl.OotAutoTracker {
                             if (this.stream != null) { this.stream.Disconnect().Dispose(); }
                             this.stream = stream_res.Unwrap();
                             UpdateConnection(true, "Connected");
-                            if (this.isVanilla) {
-                                using (var knowledge = Native.knowledge_vanilla()) { //TODO pull knowledge back out of this.model
-                                    knowledge.Send(this.stream);
-                                }
+                            using (var knowledge = this.model.CloneKnowledge()) {
+                                knowledge.Send(this.stream);
                             }
                         } else {
                             using (StringHandle err = stream_res.DebugErr()) {
@@ -811,6 +949,7 @@ namespace Net.Fenhl.OotAutoTracker {
         public override void UpdateValues(ToolFormUpdateType type) {
             if (type != ToolFormUpdateType.PreFrame) { return; } //TODO setting to also enable auto-tracking during turbo (ToolFormUpdateType.FastPreFrame)?
             if ((APIs.GameInfo.GetGameInfo()?.Name ?? "Null") == "Null") { return; }
+            if (this.rando == null) { return; } // not playing OoT or OoTR, or failed to load rando data
             if (this.autoTrackerContextAddr == null && Enumerable.SequenceEqual(APIs.Memory.ReadByteRange(0x11a5d0 + 0x1c, 6, "RDRAM"), new List<byte>(Encoding.UTF8.GetBytes("ZELDAZ")))) { // don't check auto-tracker context version while rom is loaded but not properly initialized
                 var randoContextAddr = 0x8040_0000;
                 var newAutoTrackerContextAddr = APIs.Memory.ReadU32(randoContextAddr + 0xc, "System Bus");
@@ -832,7 +971,13 @@ namespace Net.Fenhl.OotAutoTracker {
                         }
                     }
                     if (length > 0) {
-                        this.model.SetAutoTrackerContext(APIs.Memory, newAutoTrackerContextAddr, length);
+                        using (UnitResult unit_res = this.model.SetAutoTrackerContext(this.rando, APIs.Memory, newAutoTrackerContextAddr, length)) {
+                            if (!unit_res.IsOk()) {
+                                using (StringHandle err = unit_res.DebugErr()) {
+                                    UpdateGame(false, $"Knowledge not updated from auto-tracker context: {err.AsString()}");
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -854,9 +999,40 @@ namespace Net.Fenhl.OotAutoTracker {
                     return;
                 }
             }
-            UpdateSave(true, $"Save data ok, last checked {DateTime.Now}");
-            this.model.SetRam(prevRam);
+            using (UnitResult unit_res = this.model.SetRam(this.rando, prevRam)) {
+                if (unit_res.IsOk()) {
+                    UpdateSave(true, $"Save data ok, last checked {DateTime.Now}");
+                } else {
+                    using (StringHandle err = unit_res.DebugErr()) {
+                        UpdateSave(false, $"Save data read but knowledge not updated: {err.AsString()}");
+                    }
+                }
+            }
             UpdateCells();
+            var knowledge = this.model.CloneKnowledge();
+            if (prevKnowledge == null) {
+                prevKnowledge = knowledge; // the initial knowledge is sent when connecting
+            } else if (!knowledge.Equals(prevKnowledge)) {
+                /*
+                if (this.stream != null) {
+                    using (KnowledgeDiff diff = prevKnowledge.Diff(knowledge)) {
+                        using (UnitResult unit_res = diff.Send(this.stream)) {
+                            if (!unit_res.IsOk()) {
+                                if (this.stream != null) { this.stream.Dispose(); }
+                                this.stream = null;
+                                using (StringHandle err = unit_res.DebugErr()) {
+                                    UpdateConnection(false, $"Failed to send knowledge: {err.AsString()}");
+                                }
+                            }
+                        }
+                    }
+                }
+                */
+                prevKnowledge.Dispose();
+                prevKnowledge = knowledge;
+            } else {
+                knowledge.Dispose();
+            }
             var save = prevRam.CloneSave();
             if (prevSave != null && save.Equals(prevSave)) { return; }
             if (prevSave == null) {
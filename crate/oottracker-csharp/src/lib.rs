@@ -16,12 +16,14 @@ use {
             Command,
         },
         slice,
+        sync::Arc,
         time::Duration,
     },
     async_proto::Protocol as _,
     itertools::Itertools as _,
     libc::c_char,
     semver::Version,
+    ootr_dynamic::BundleRando,
     oottracker::{
         ModelState,
         TrackerCtx,
@@ -37,7 +39,6 @@ use {
         },
         save::{
             self,
-            GameMode,
             Save,
         },
         ui::{
@@ -51,6 +52,7 @@ use {
             TrackerLayout,
             dirs,
         },
+        versions::Registry,
     },
 };
 
@@ -338,7 +340,8 @@ pub fn version() -> Version {
         (CellStyle::Dimmed, CellOverlay::Image(overlay)) => img.with_overlay(&overlay).to_string('.', false),
         (_, CellOverlay::Location { loc, style }) => loc.to_string('.', match style {
             LocationStyle::Normal => ImageDirContext::Normal,
-            LocationStyle::Dimmed => ImageDirContext::Dimmed,
+            // conflicting knowledge is shown like unknown knowledge since this frontend has no dedicated style for it
+            LocationStyle::Dimmed | LocationStyle::Contradiction => ImageDirContext::Dimmed,
             LocationStyle::Mq => unimplemented!(),
         }),
         (CellStyle::Dimmed, CellOverlay::Count { .. }) | (CellStyle::LeftDimmed | CellStyle::RightDimmed, _) => unimplemented!(),
@@ -542,12 +545,83 @@ pub fn version() -> Version {
     HandleOwned::new(Packet::SaveDelta(*diff.into_box()).write_sync(&mut *tcp_stream).map_err(DebugError::from))
 }
 
+/// Loads the randomizer data for the given version number from a ROM header. Vanilla ROMs and unknown versions use the built-in data.
+#[no_mangle] pub extern "C" fn rando_for_rom_version(major: u8, minor: u8, patch: u8) -> HandleOwned<DebugResult<Arc<BundleRando>>> {
+    HandleOwned::new(Registry::load_user().map(|registry| Arc::clone(registry.for_rom_version(major, minor, patch))).map_err(DebugError::from))
+}
+
+/// # Safety
+///
+/// `rando_res` must point at a valid `DebugResult<Arc<BundleRando>>`. This function takes ownership of the `DebugResult`.
+#[no_mangle] pub unsafe extern "C" fn rando_result_free(rando_res: HandleOwned<DebugResult<Arc<BundleRando>>>) {
+    let _ = rando_res.into_box();
+}
+
+/// # Safety
+///
+/// `rando_res` must point at a valid `DebugResult<Arc<BundleRando>>`.
+#[no_mangle] pub unsafe extern "C" fn rando_result_is_ok(rando_res: *const DebugResult<Arc<BundleRando>>) -> FfiBool {
+    (&*rando_res).is_ok().into()
+}
+
+/// # Safety
+///
+/// `rando_res` must point at a valid `DebugResult<Arc<BundleRando>>`. This function takes ownership of the `DebugResult`.
+#[no_mangle] pub unsafe extern "C" fn rando_result_unwrap(rando_res: HandleOwned<DebugResult<Arc<BundleRando>>>) -> HandleOwned<Arc<BundleRando>> {
+    HandleOwned::new(rando_res.into_box().debug_unwrap())
+}
+
+/// # Safety
+///
+/// `rando_res` must point at a valid `DebugResult<Arc<BundleRando>>`. This function takes ownership of the `DebugResult`.
+#[no_mangle] pub unsafe extern "C" fn rando_result_debug_err(rando_res: HandleOwned<DebugResult<Arc<BundleRando>>>) -> StringHandle {
+    StringHandle::from_string(rando_res.into_box().unwrap_err())
+}
+
+/// # Safety
+///
+/// `rando` must point at a valid `Arc<BundleRando>`. This function takes ownership of the `Arc`.
+#[no_mangle] pub unsafe extern "C" fn rando_free(rando: HandleOwned<Arc<BundleRando>>) {
+    let _ = rando.into_box();
+}
+
 #[no_mangle] pub extern "C" fn knowledge_none() -> HandleOwned<Knowledge> {
     HandleOwned::default()
 }
 
-#[no_mangle] pub extern "C" fn knowledge_vanilla() -> HandleOwned<Knowledge> {
-    HandleOwned::new(Knowledge::vanilla())
+/// # Safety
+///
+/// `rando` must point at a valid `Arc<BundleRando>`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_vanilla(rando: *const Arc<BundleRando>) -> HandleOwned<DebugResult<Knowledge>> {
+    HandleOwned::new(Knowledge::vanilla(&**rando).map_err(DebugError::from))
+}
+
+/// # Safety
+///
+/// `knowledge_res` must point at a valid `DebugResult<Knowledge>`. This function takes ownership of the `DebugResult`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_result_free(knowledge_res: HandleOwned<DebugResult<Knowledge>>) {
+    let _ = knowledge_res.into_box();
+}
+
+/// # Safety
+///
+/// `knowledge_res` must point at a valid `DebugResult<Knowledge>`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_result_is_ok(knowledge_res: *const DebugResult<Knowledge>) -> FfiBool {
+    (&*knowledge_res).is_ok().into()
+}
+
+/// # Safety
+///
+/// `knowledge_res` must point at a valid `DebugResult<Knowledge>`. This function takes ownership of the `DebugResult`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_result_unwrap(knowledge_res: HandleOwned<DebugResult<Knowledge>>) -> HandleOwned<Knowledge> {
+    HandleOwned::new(knowledge_res.into_box().debug_unwrap())
+}
+
+/// # Safety
+///
+/// `knowledge_res` must point at a valid `DebugResult<Knowledge>`. This function takes ownership of the `DebugResult`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_result_debug_err(knowledge_res: HandleOwned<DebugResult<Knowledge>>) -> StringHandle {
+    StringHandle::from_string(knowledge_res.into_box().unwrap_err())
 }
 
 /// # Safety
@@ -566,6 +640,36 @@ pub fn version() -> Version {
     HandleOwned::new(Packet::KnowledgeInit((&*knowledge).clone()).write_sync(&mut *tcp_stream).map_err(DebugError::from))
 }
 
+/// # Safety
+///
+/// `knowledge1` and `knowledge2` must point at valid `Knowledge` values.
+#[no_mangle] pub unsafe extern "C" fn knowledge_equal(knowledge1: *const Knowledge, knowledge2: *const Knowledge) -> FfiBool {
+    (&*knowledge1 == &*knowledge2).into()
+}
+
+/// # Safety
+///
+/// `old_knowledge` and `new_knowledge` must point at valid `Knowledge` values.
+#[no_mangle] pub unsafe extern "C" fn knowledge_diff(old_knowledge: *const Knowledge, new_knowledge: *const Knowledge) -> HandleOwned<Delta> {
+    HandleOwned::new(&*new_knowledge - &*old_knowledge)
+}
+
+/// # Safety
+///
+/// `diff` must point at a valid `Delta`. This function takes ownership of the `Delta`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_diff_free(diff: HandleOwned<Delta>) {
+    let _ = diff.into_box();
+}
+
+/// # Safety
+///
+/// `tcp_stream` must be a unique pointer at a valid `TcpStream`.
+///
+/// `diff` must point at a valid `Delta`. This function takes ownership of the `Delta`.
+#[no_mangle] pub unsafe extern "C" fn knowledge_diff_send(tcp_stream: *mut TcpStream, diff: HandleOwned<Delta>) -> HandleOwned<DebugResult<()>> {
+    HandleOwned::new(Packet::KnowledgeDelta(*diff.into_box()).write_sync(&mut *tcp_stream).map_err(DebugError::from))
+}
+
 /// # Safety
 ///
 /// `save` must point at a valid `Save`, and `knowledge` must point at a valid `Knowledge`. This function takes ownership of both arguments.
@@ -643,12 +747,20 @@ pub fn version() -> Version {
 ///
 /// `model` must point at a valid `ModelState` and must not be read or mutated during the function call.
 ///
+/// `rando` must point at a valid `Arc<BundleRando>`.
+///
 /// `ram` must point at a valid `Ram` and must not be mutated during the function call.
-#[no_mangle] pub unsafe extern "C" fn model_set_ram(model: *mut ModelState, ram: *const Ram) {
+#[no_mangle] pub unsafe extern "C" fn model_set_ram(model: *mut ModelState, rando: *const Arc<BundleRando>, ram: *const Ram) -> HandleOwned<DebugResult<()>> {
     let model = &mut *model;
     let ram = &*ram;
-    if ram.save.game_mode == GameMode::Gameplay { model.ram = *ram }
-    model.update_knowledge();
+    HandleOwned::new(model.update_ram(&**rando, *ram).map_err(DebugError::from))
+}
+
+/// # Safety
+///
+/// `model` must point at a valid `ModelState` and must not be mutated during the function call.
+#[no_mangle] pub unsafe extern "C" fn model_clone_knowledge(model: *const ModelState) -> HandleOwned<Knowledge> {
+    HandleOwned::new((&*model).knowledge.clone())
 }
 
 /// # Safety
@@ -662,11 +774,14 @@ pub fn version() -> Version {
 ///
 /// `model` must point at a valid `ModelState` and mut not be read or mutated during the function call.
 ///
+/// `rando` must point at a valid `Arc<BundleRando>`.
+///
 /// `data` must point at the start of a valid slice with the given `length` and must not be mutated for the duration of the function call.
-#[no_mangle] pub unsafe extern "C" fn model_set_tracker_ctx(model: *mut ModelState, length: i32, data: *const u8) {
+#[no_mangle] pub unsafe extern "C" fn model_set_tracker_ctx(model: *mut ModelState, rando: *const Arc<BundleRando>, length: i32, data: *const u8) -> HandleOwned<DebugResult<()>> {
     assert!(!data.is_null());
     let model = &mut *model;
     let data = slice::from_raw_parts(data, length.try_into().expect("negative or excessive length"));
+    let prev = model.clone();
     model.tracker_ctx = TrackerCtx::new(data);
-    model.update_knowledge();
+    HandleOwned::new(model.update_knowledge(&**rando, &prev).map_err(DebugError::from))
 }
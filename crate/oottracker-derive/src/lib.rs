@@ -127,6 +127,19 @@ impl FlagName {
     }
 }
 
+impl FlagName {
+    /// The rando names referenced by this flag, as expressions of type `crate::checks::ReferencedName`.
+    fn referenced_names(&self) -> Vec<proc_macro2::TokenStream> {
+        match self {
+            FlagName::Event(lit) => vec![quote!(crate::checks::ReferencedName::Event(#lit))],
+            FlagName::Ident(_) => Vec::default(),
+            FlagName::Lit(lit) => vec![quote!(crate::checks::ReferencedName::Location(#lit))],
+            FlagName::Entrance(from, to) => vec![quote!(crate::checks::ReferencedName::Exit { from: #from, to: #to })],
+            FlagName::Prereq(_, at_check) => at_check.referenced_names(),
+        }
+    }
+}
+
 impl Parse for FlagName {
     fn parse(input: ParseStream<'_>) -> Result<FlagName> {
         let lookahead = input.lookahead1();
@@ -248,10 +261,12 @@ pub fn flags_list(input: TokenStream) -> TokenStream {
     let mut entrance_prereqs = Vec::default();
     let mut event_checks = Vec::default();
     let mut location_checks = Vec::default();
+    let mut referenced_names = Vec::default();
     for ((fields, idx), fields_ty) in all_fields.iter().zip(&tup_idxs).zip(&fields_tys) {
         if let Some(fields) = fields {
             for Flag { name, .. } in fields {
                 let name_ident = name.to_ident();
+                referenced_names.extend(name.referenced_names());
                 match name {
                     FlagName::Event(event_name_lit) => {
                         event_checks.push(quote!(#event_name_lit => Some(self.#idx.contains(#fields_ty::#name_ident))));
@@ -333,6 +348,9 @@ pub fn flags_list(input: TokenStream) -> TokenStream {
         #vis #struct_token #name(#(#contents,)*);
 
         impl #name {
+            /// The names of events, locations, and exits which this type can check.
+            pub(crate) const REFERENCED_NAMES: &'static [crate::checks::ReferencedName] = &[#(#referenced_names,)*];
+
             pub(crate) fn checked<R: ootr::Rando>(&self, check: &ootr::check::Check<R>) -> Option<bool> {
                 match check {
                     ootr::check::Check::AnonymousEvent(at_check, id) => match &**at_check {
@@ -610,12 +628,19 @@ pub fn scene_flags(input: TokenStream) -> TokenStream {
     let mut location_checks = Vec::default();
     let mut skull_location_checks = Vec::default();
     let mut location_prereqs = Vec::default();
+    let mut referenced_names = Vec::default();
     for scene in &scenes {
         let scene_field = scene.name.to_field();
+        for data in &scene.data {
+            if let SceneData::RegionName(RegionName::One(region_lit)) = data {
+                referenced_names.push(quote!(crate::checks::ReferencedName::Region(#region_lit)));
+            }
+        }
         for (kind, fields) in scene.fields() {
             let fields_ty = kind.ty(&scene.name);
             for Flag { name, .. } in fields {
                 let name_ident = name.to_ident();
+                referenced_names.extend(name.referenced_names());
                 if let SceneFieldsKind::GoldSkulltulas = kind {
                     match name {
                         FlagName::Lit(name_lit) => {
@@ -832,6 +857,9 @@ pub fn scene_flags(input: TokenStream) -> TokenStream {
         }
 
         impl #name {
+            /// The names of regions, events, locations, and exits which this type or [`GoldSkulltulas`] can check.
+            pub(crate) const REFERENCED_NAMES: &'static [crate::checks::ReferencedName] = &[#(#referenced_names,)*];
+
             pub(crate) fn checked<R: ootr::Rando>(&self, check: &ootr::check::Check<R>) -> Option<bool> {
                 match check {
                     ootr::check::Check::AnonymousEvent(at_check, id) => match &**at_check {
@@ -1,5 +1,6 @@
 use {
     std::{
+        collections::HashMap,
         fmt,
         path::PathBuf,
     },
@@ -33,7 +34,26 @@ use {
         },
     },
     itertools::Itertools as _,
-    ootr::Rando,
+    ootr::{
+        Rando,
+        check::Check,
+    },
+    oottracker::{
+        ModelState,
+        checks::{
+            CheckStatus,
+            CheckStatusError,
+        },
+        knowledge::{
+            Fact,
+            Knowledge,
+            Source,
+        },
+        versions::{
+            Channel,
+            Registry,
+        },
+    },
 };
 
 #[derive(Derivative, Debug)]
@@ -83,6 +103,33 @@ impl fmt::Display for SettingsInfoKind {
     }
 }
 
+/// An entry in the randomizer version pick list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RandoVersionChoice {
+    channel: Option<Channel>,
+    pub(crate) version: String,
+}
+
+impl RandoVersionChoice {
+    /// The channels in the given registry, followed by each of its versions.
+    pub(crate) fn all(versions: &Registry) -> Vec<RandoVersionChoice> {
+        versions.channels().iter()
+            .map(|(channel, version)| RandoVersionChoice { channel: Some(channel.clone()), version: version.clone() })
+            .chain(versions.versions().map(|version| RandoVersionChoice { channel: None, version: version.to_owned() }))
+            .collect()
+    }
+}
+
+impl fmt::Display for RandoVersionChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref channel) = self.channel {
+            write!(f, "{} ({})", channel, self.version)
+        } else {
+            self.version.fmt(f)
+        }
+    }
+}
+
 struct TextInputStyle;
 
 impl text_input::StyleSheet for TextInputStyle {
@@ -124,6 +171,7 @@ pub(crate) enum Message<R: Rando> {
     EditPlandoPath(String),
     EditSettingsString(String),
     EditWeightsPath(String),
+    PickRandoVersion(RandoVersionChoice),
     PickRegion(R::RegionName),
     PickSettingsInfo(SettingsInfoKind),
 }
@@ -134,11 +182,17 @@ pub(crate) struct State<R: Rando + 'static> {
     //TODO store in model state
     #[derivative(Default(value = "R::root()"))]
     current_region: R::RegionName,
+    /// The result of the last logic evaluation along with the model state it was computed for, so it's only recomputed when the model changes.
+    check_statuses: Option<(ModelState, Result<HashMap<Check<R>, CheckStatus>, CheckStatusError<R>>)>,
     region_pick: pick_list::State<R::RegionName>,
+    /// `None` means the built-in data is used.
+    rando_version: Option<RandoVersionChoice>,
+    rando_version_pick: pick_list::State<RandoVersionChoice>,
     save_btn: button::State,
     reset_btn: button::State,
     //TODO store in knowledge
     settings_info: SettingsInfo,
+    settings_error: Option<String>,
     settings_pick: pick_list::State<SettingsInfoKind>,
     settings_text: text_input::State,
     starting_inv_btns_row0: [button::State; 11],
@@ -151,32 +205,84 @@ pub(crate) struct State<R: Rando + 'static> {
 }
 
 impl<R: Rando + 'static> State<R> {
-    pub(crate) fn update(&mut self, msg: Message<R>) -> Command<crate::Message<R>> {
+    pub(crate) fn update(&mut self, rando: &R, model: &mut ModelState, msg: Message<R>) -> Command<crate::Message<R>> {
         match msg {
-            Message::EditPlandoPath(new_path) => if let Ok(new_path) = new_path.parse() {
+            Message::EditPlandoPath(new_path) => if let Ok(new_path) = new_path.parse::<PathBuf>() {
                 if let SettingsInfo::Plando(ref mut path) = self.settings_info {
+                    self.settings_error = if new_path.is_file() {
+                        match oottracker::plando::load(rando, &new_path) {
+                            Ok(knowledge) => {
+                                model.knowledge = knowledge;
+                                None
+                            }
+                            Err(e) => Some(e.to_string()),
+                        }
+                    } else {
+                        None
+                    };
                     *path = new_path;
                 }
             },
             Message::EditSettingsString(new_string) => if let SettingsInfo::String(ref mut string) = self.settings_info {
+                self.settings_error = if new_string.is_empty() {
+                    None
+                } else {
+                    model.knowledge.apply_settings_string(rando, &new_string).err().map(|e| e.to_string())
+                };
                 *string = new_string;
             },
-            Message::EditWeightsPath(new_path) => if let Ok(new_path) = new_path.parse() {
+            Message::EditWeightsPath(new_path) => if let Ok(new_path) = new_path.parse::<PathBuf>() {
                 if let SettingsInfo::Weights(ref mut path) = self.settings_info {
+                    self.settings_error = if new_path.is_file() {
+                        match oottracker::weights::load(&new_path) {
+                            Ok(knowledge) => {
+                                // forget what was known from the previously loaded weights file
+                                let mut prev = model.knowledge.clone();
+                                let from_weights = |sources: &HashMap<Fact, Source>, name: &String| sources.get(&Fact::Setting { name: name.clone() }) == Some(&Source::Weights);
+                                prev.bool_settings.retain(|name, _| !from_weights(&prev.sources, name));
+                                prev.string_settings.retain(|name, _| !from_weights(&prev.sources, name));
+                                prev.sources.retain(|fact, source| !matches!(fact, Fact::Setting { .. }) || *source != Source::Weights);
+                                match prev & knowledge {
+                                    Ok(knowledge) => {
+                                        model.knowledge = knowledge;
+                                        None
+                                    }
+                                    Err(e) => Some(e.to_string()),
+                                }
+                            }
+                            Err(e) => Some(e.to_string()),
+                        }
+                    } else {
+                        None
+                    };
                     *path = new_path;
                 }
             },
+            Message::PickRandoVersion(new_version) => {
+                self.rando_version = Some(new_version);
+                self.check_statuses = None; // computed using the old version's logic
+                self.current_region = R::root(); // the current region might not exist in the new version
+                if let Err(e) = model.knowledge.validate(rando) {
+                    // the known settings or tricks don't exist in the new version
+                    model.knowledge = Knowledge::default();
+                    self.settings_error = Some(format!("known settings were reset: {}", e));
+                }
+            }
             Message::PickRegion(new_region) => self.current_region = new_region,
-            Message::PickSettingsInfo(new_info) => self.settings_info.set_kind(new_info),
+            Message::PickSettingsInfo(new_info) => {
+                self.settings_info.set_kind(new_info);
+                self.settings_error = None;
+            }
         }
         Command::none()
     }
 
-    pub(crate) fn view(&mut self, rando: &R) -> Element<'_, Message<R>> {
+    pub(crate) fn view(&mut self, rando_versions: Vec<RandoVersionChoice>, rando: &R, model: &ModelState) -> Element<'_, Message<R>> {
+        let glitched = model.knowledge.glitched().unwrap_or(false);
         let mut col = Column::new().push(Row::new()
             .push(PickList::new(
                 &mut self.region_pick,
-                rando.regions().expect("failed to load regions" /*TODO better error handling */).iter().map(|region| region.name.clone()).collect_vec(),
+                rando.regions(glitched).expect("failed to load regions" /*TODO better error handling */).iter().map(|region| region.name.clone()).collect_vec(),
                 Some(self.current_region.clone()),
                 Message::PickRegion,
             ))
@@ -187,7 +293,11 @@ impl<R: Rando + 'static> State<R> {
         match self.current_region.as_ref() {
             "Root" => col = col
                 .push(Text::new("External knowledge:"))
-                //TODO randomizer version (support latest release, latest Dev, latest Dev-R, and any version currently used in a major tournament or the RSL)
+                .push(Row::new()
+                    .push(Text::new("Randomizer version:").height(Length::Units(30)).vertical_alignment(alignment::Vertical::Center))
+                    .push(PickList::new(&mut self.rando_version_pick, rando_versions, self.rando_version.clone(), Message::PickRandoVersion))
+                    .spacing(16)
+                )
                 .push(Row::new()
                     .push(Text::new("Settings:").height(Length::Units(30)).vertical_alignment(alignment::Vertical::Center))
                     .push(PickList::new(&mut self.settings_pick, all().collect_vec(), Some(self.settings_info.kind()), Message::PickSettingsInfo))
@@ -198,6 +308,7 @@ impl<R: Rando + 'static> State<R> {
                     })
                     .spacing(16)
                 )
+                .push(Text::new(self.settings_error.as_deref().unwrap_or_default()))
                 .push(Text::new("Starting inventory:")) //TODO on_press for each row
                 .push(Row::with_children(self.starting_inv_btns_row0.iter_mut().zip(vec!["Hearts", "Shadow Medallion", "Light Medallion", "Forest Medallion", "Deku Sticks", "Deku Nuts", "Bombs", "Bow", "Fire Arrows", "Din's Fire", "Bottle"]).map(|(state, label)| Button::new(state, Text::new(label)).into()).collect()))
                 .push(Row::with_children(self.starting_inv_btns_row1.iter_mut().zip(vec!["Wallet/Rupees", "Spirit Medallion", "Water Medallion", "Fire Medallion", "Slingshot", "Ocarina", "Bombchus", "Hookshot", "Ice Arrows", "Farore's Wind", "Child Trade"]).map(|(state, label)| Button::new(state, Text::new(label)).into()).collect()))
@@ -212,8 +323,29 @@ impl<R: Rando + 'static> State<R> {
                 .push(Text::new("TODO replace Master Sword Pedestal location with big “age change” button (age is considered for which checks are in logic and where savewarp goes)")),
             _ => {}
         }
+        if self.check_statuses.as_ref().map_or(true, |(cached_model, _)| cached_model != model) {
+            self.check_statuses = Some((model.clone(), oottracker::logic::check_statuses(rando, model)));
+        }
+        match &self.check_statuses.as_ref().expect("just computed").1 {
+            Ok(statuses) => {
+                let locations = rando.regions(glitched).expect("failed to load regions" /*TODO better error handling */).iter()
+                    .filter(|region| region.name == self.current_region)
+                    .flat_map(|region| region.locations.keys().cloned())
+                    .sorted()
+                    .dedup()
+                    .collect_vec();
+                for location in locations {
+                    let status = match statuses.get(&Check::Location(location.clone())) {
+                        Some(CheckStatus::Checked) => "checked",
+                        Some(CheckStatus::Reachable) => "in logic",
+                        Some(CheckStatus::NotYetReachable) | None => "not yet in logic",
+                    };
+                    col = col.push(Text::new(format!("{}: {}", location, status)));
+                }
+            }
+            Err(e) => col = col.push(Text::new(format!("error determining logic: {}", e))),
+        }
         col
-            .push(Text::new("TODO checks"))
             .spacing(16)
             .padding(16)
             .into()
@@ -9,6 +9,7 @@ use {
         env,
         fmt,
         io,
+        mem,
         sync::Arc,
     },
     derivative::Derivative,
@@ -67,20 +68,27 @@ use {
     url::Url,
     wheel::FromArc,
     ootr::Rando,
+    ootr_dynamic::BundleRando,
     oottracker::{
         ModelState,
+        UpdateError,
+        checks::CheckStatusError,
         firebase,
         github::Repo,
+        knowledge::Contradiction,
         net::{
             self,
             Connection,
         },
         proto::Packet,
-        save::*,
         ui::{
             self,
             *,
         },
+        versions::{
+            self,
+            Registry,
+        },
     },
 };
 #[cfg(target_os = "macos")] use {
@@ -125,7 +133,8 @@ fn cell_image(cell: &TrackerCellId, state: &ModelState) -> Image {
         (CellStyle::Dimmed, CellOverlay::Image(overlay)) => img.with_overlay(&overlay).embedded(false),
         (_, CellOverlay::Location { loc, style }) => loc.embedded(match style {
             LocationStyle::Normal => ImageDirContext::Normal,
-            LocationStyle::Dimmed => ImageDirContext::Dimmed,
+            // conflicting knowledge is shown like unknown knowledge since this frontend has no dedicated style for it
+            LocationStyle::Dimmed | LocationStyle::Contradiction => ImageDirContext::Dimmed,
             LocationStyle::Mq => unimplemented!(),
         }),
         (CellStyle::Dimmed, CellOverlay::Count { .. }) | (CellStyle::LeftDimmed | CellStyle::RightDimmed, _) => unimplemented!(),
@@ -136,11 +145,11 @@ fn cell_image(cell: &TrackerCellId, state: &ModelState) -> Image {
 }
 
 trait TrackerCellIdExt {
-    fn view<'a>(&self, state: &ModelState, cell_button: &'a mut button::State) -> Element<'a, Message<ootr_static::Rando>>; //TODO allow ootr_dynamic::Rando
+    fn view<'a>(&self, state: &ModelState, cell_button: &'a mut button::State) -> Element<'a, Message<BundleRando>>;
 }
 
 impl TrackerCellIdExt for TrackerCellId {
-    fn view<'a>(&self, state: &ModelState, cell_button: &'a mut button::State) -> Element<'a, Message<ootr_static::Rando>> { //TODO allow ootr_dynamic::Rando
+    fn view<'a>(&self, state: &ModelState, cell_button: &'a mut button::State) -> Element<'a, Message<BundleRando>> {
         Button::new(cell_button, cell_image(self, state))
             .on_press(Message::LeftClick(*self))
             .padding(0)
@@ -176,16 +185,21 @@ enum Message<R: Rando> {
     ConfigError(ui::Error),
     ConnectionError(ConnectionError),
     Connect,
+    Contradiction(Contradiction),
     DismissNotification,
     DismissWelcomeScreen,
     InstallUpdate,
     KeyboardModifiers(KeyboardModifiers),
     LeftClick(TrackerCellId),
     LoadConfig(Config),
+    LoadRandoVersions(Arc<Registry>),
     Logic(logic::Message<R>),
+    LogicError(CheckStatusError<R>),
     MouseMoved([f32; 2]),
     Nop,
     Packet(Packet),
+    RandoDataError(R::Err),
+    RandoVersionsError(versions::Error),
     ResetUpdateState,
     RightClick,
     SetAutoUpdateCheck(bool),
@@ -201,12 +215,26 @@ enum Message<R: Rando> {
     UpdateCheckError(UpdateCheckError),
 }
 
+impl<R: Rando> From<UpdateError<R>> for Message<R> {
+    fn from(e: UpdateError<R>) -> Message<R> {
+        match e {
+            UpdateError::Contradiction(e) => Message::Contradiction(e),
+            UpdateError::Rando(e) => Message::RandoDataError(e),
+            UpdateError::Logic(e) => Message::LogicError(e),
+        }
+    }
+}
+
 impl<R: Rando> fmt::Display for Message<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Message::ClientDisconnected => write!(f, "connection lost"),
             Message::ConfigError(e) => write!(f, "error loading/saving preferences: {}", e),
             Message::ConnectionError(e) => write!(f, "connection error: {}", e),
+            Message::Contradiction(e) => e.fmt(f),
+            Message::LogicError(e) => write!(f, "error determining logic: {}", e),
+            Message::RandoDataError(e) => write!(f, "error loading randomizer data: {}", e),
+            Message::RandoVersionsError(e) => write!(f, "error loading randomizer versions: {}", e),
             _ => write!(f, "{:?}", self), // these messages are not notifications so just fall back to Debug
         }
     }
@@ -313,6 +341,7 @@ struct State<R: Rando + 'static> {
     enable_update_checks_button: button::State,
     disable_update_checks_button: button::State,
     cell_buttons: [button::State; 52],
+    versions: Arc<Registry>,
     rando: Arc<R>,
     model: ModelState,
     logic: logic::State<R>,
@@ -358,8 +387,9 @@ impl<R: Rando + 'static> State<R> {
     }
 }
 
-impl Default for State<ootr_static::Rando> {
-    fn default() -> State<ootr_static::Rando> {
+impl Default for State<BundleRando> {
+    fn default() -> State<BundleRando> {
+        let versions = Registry::builtin().expect("failed to load built-in rando data");
         State {
             flags: Args::default(),
             config: None,
@@ -387,7 +417,8 @@ impl Default for State<ootr_static::Rando> {
                 button::State::default(), button::State::default(), button::State::default(), button::State::default(), button::State::default(), button::State::default(),
                 button::State::default(), button::State::default(), button::State::default(), button::State::default(), button::State::default(), button::State::default(),
             ],
-            rando: Arc::new(ootr_static::Rando),
+            rando: Arc::clone(versions.builtin_rando()),
+            versions: Arc::new(versions),
             model: ModelState::default(),
             logic: logic::State::default(),
             notification: None,
@@ -397,8 +428,8 @@ impl Default for State<ootr_static::Rando> {
     }
 }
 
-impl From<Args> for State<ootr_static::Rando> { //TODO include Rando in flags and make this impl generic
-    fn from(flags: Args) -> State<ootr_static::Rando> {
+impl From<Args> for State<BundleRando> { //TODO include Rando in flags and make this impl generic
+    fn from(flags: Args) -> State<BundleRando> {
         State {
             flags,
             ..State::default()
@@ -406,19 +437,27 @@ impl From<Args> for State<ootr_static::Rando> { //TODO include Rando in flags an
     }
 }
 
-impl Application for State<ootr_static::Rando> { //TODO include Rando in flags and make this impl generic
+impl Application for State<BundleRando> { //TODO include Rando in flags and make this impl generic
     type Executor = iced::executor::Default;
-    type Message = Message<ootr_static::Rando>;
+    type Message = Message<BundleRando>;
     type Flags = Args;
 
-    fn new(flags: Args) -> (State<ootr_static::Rando>, Command<Message<ootr_static::Rando>>) {
-        (State::from(flags), Command::single(Action::Future(async {
-            match Config::new().await {
-                Ok(Some(config)) => Message::LoadConfig(config),
-                Ok(None) => Message::Nop,
-                Err(e) => Message::ConfigError(e),
-            }
-        }.boxed())))
+    fn new(flags: Args) -> (State<BundleRando>, Command<Message<BundleRando>>) {
+        (State::from(flags), Command::batch(vec![
+            Command::single(Action::Future(async {
+                match Config::new().await {
+                    Ok(Some(config)) => Message::LoadConfig(config),
+                    Ok(None) => Message::Nop,
+                    Err(e) => Message::ConfigError(e),
+                }
+            }.boxed())),
+            Command::single(Action::Future(async {
+                match Registry::load_user() {
+                    Ok(versions) => Message::LoadRandoVersions(Arc::new(versions)),
+                    Err(e) => Message::RandoVersionsError(e),
+                }
+            }.boxed())),
+        ]))
     }
 
     fn title(&self) -> String {
@@ -429,7 +468,7 @@ impl Application for State<ootr_static::Rando> { //TODO include Rando in flags a
         }
     }
 
-    fn update(&mut self, message: Message<ootr_static::Rando>) -> Command<Message<ootr_static::Rando>> {
+    fn update(&mut self, message: Message<BundleRando>) -> Command<Message<BundleRando>> {
         match message {
             Message::ClientDisconnected => if self.notification.as_ref().map_or(true, |&(is_temp, _)| is_temp) { // don't override an existing, probably more descriptive error message
                 return self.notify(message)
@@ -490,40 +529,63 @@ impl Application for State<ootr_static::Rando> { //TODO include Rando in flags a
                 }
                 v => unimplemented!("config version from the future: {}", v),
             },
-            Message::Logic(msg) => return self.logic.update(msg),
+            Message::LoadRandoVersions(versions) => self.versions = versions,
+            Message::Logic(msg) => {
+                if let logic::Message::PickRandoVersion(ref choice) = msg {
+                    if let Some(rando) = self.versions.get(&choice.version) {
+                        self.rando = Arc::clone(rando);
+                    }
+                }
+                return self.logic.update(&self.rando, &mut self.model, msg)
+            }
             Message::MouseMoved(pos) => self.last_cursor_pos = pos,
             Message::Nop => {}
             Message::Packet(packet) => {
-                match packet {
+                let knowledge_res = match packet {
                     Packet::Goodbye => unreachable!(), // Goodbye is not yielded from proto::read
                     Packet::SaveDelta(delta) => {
+                        let prev = self.model.clone();
                         self.model.ram.save = &self.model.ram.save + &delta;
-                        self.model.update_knowledge();
+                        self.model.update_knowledge(&*self.rando, &prev)
                     }
                     Packet::SaveInit(save) => {
+                        let prev = self.model.clone();
                         self.model.ram.save = save;
-                        self.model.update_knowledge();
+                        self.model.update_knowledge(&*self.rando, &prev)
                     }
-                    Packet::KnowledgeInit(knowledge) => self.model.knowledge = knowledge,
-                    Packet::RamInit(ram) => {
-                        if ram.save.game_mode == GameMode::Gameplay { self.model.ram = ram }
-                        self.model.update_knowledge();
+                    Packet::KnowledgeInit(knowledge) => {
+                        self.model.knowledge = knowledge;
+                        Ok(())
                     }
-                    Packet::UpdateCell(cell_id, value) => if let Some(ref connection) = self.connection {
-                        if let Some(app) = connection.firebase_app() {
-                            app.set_cell(&mut self.model, cell_id, value).expect("failed to apply state change from Firebase"); //TODO show error message instead of panicking?
+                    Packet::KnowledgeDelta(delta) => {
+                        self.model.knowledge += delta;
+                        Ok(())
+                    }
+                    Packet::RamInit(ram) => self.model.update_ram(&*self.rando, ram),
+                    Packet::UpdateCell(cell_id, value) => {
+                        if let Some(ref connection) = self.connection {
+                            if let Some(app) = connection.firebase_app() {
+                                app.set_cell(&mut self.model, cell_id, value).expect("failed to apply state change from Firebase"); //TODO show error message instead of panicking?
+                            }
                         }
-                    },
+                        Ok(())
+                    }
                     Packet::ModelInit(model) => {
-                        self.model = model;
-                        self.model.update_knowledge();
+                        let prev = mem::replace(&mut self.model, model);
+                        self.model.update_knowledge(&*self.rando, &prev)
                     }
                     Packet::ModelDelta(delta) => {
+                        let prev = self.model.clone();
                         self.model += delta;
-                        self.model.update_knowledge();
+                        let item_res = self.model.learn_item_placement(&*self.rando, &prev.ram);
+                        let learn_exit_res = self.model.learn_exit(&*self.rando, &prev.ram);
+                        let knowledge_res = self.model.update_knowledge(&*self.rando, &prev);
+                        item_res.and(learn_exit_res).and(knowledge_res)
                     }
-                }
+                };
+                if let Err(e) = knowledge_res { return self.notify(e.into()) }
             }
+            Message::Contradiction(_) | Message::LogicError(_) | Message::RandoDataError(_) | Message::RandoVersionsError(_) => return self.notify(message),
             Message::ResetUpdateState => self.update_check = UpdateCheckState::Unknown(button::State::default()),
             Message::RightClick => {
                 if self.menu_state.is_none() {
@@ -595,7 +657,7 @@ impl Application for State<ootr_static::Rando> { //TODO include Rando in flags a
         Command::none()
     }
 
-    fn view(&mut self) -> Element<'_, Message<ootr_static::Rando>> {
+    fn view(&mut self) -> Element<'_, Message<BundleRando>> {
         let layout = self.layout();
         let mut cell_buttons = self.cell_buttons.iter_mut();
 
@@ -743,7 +805,7 @@ impl Application for State<ootr_static::Rando> { //TODO include Rando in flags a
         if self.flags.show_logic_tracker {
             Row::new()
                 .push(items_container)
-                .push(self.logic.view(&self.rando).map(Message::Logic))
+                .push(self.logic.view(logic::RandoVersionChoice::all(&self.versions), &self.rando, &self.model).map(Message::Logic))
                 .width(Length::Fill)
                 .into()
         } else {
@@ -751,7 +813,7 @@ impl Application for State<ootr_static::Rando> { //TODO include Rando in flags a
         }
     }
 
-    fn subscription(&self) -> iced::Subscription<Message<ootr_static::Rando>> {
+    fn subscription(&self) -> iced::Subscription<Message<BundleRando>> {
         Subscription::batch(vec![
             iced_native::subscription::events_with(|event, status| match (event, status) {
                 (iced_native::Event::Keyboard(iced_native::keyboard::Event::ModifiersChanged(modifiers)), _) => Some(Message::KeyboardModifiers(modifiers)),
@@ -863,7 +925,7 @@ enum UpdateCheckState {
 }
 
 impl UpdateCheckState {
-    fn view(&mut self) -> Element<'_, Message<ootr_static::Rando>> {
+    fn view(&mut self) -> Element<'_, Message<BundleRando>> {
         match self {
             UpdateCheckState::Unknown(check_btn) => Row::new()
                 .push(Text::new(concat!("version ", env!("CARGO_PKG_VERSION"))))
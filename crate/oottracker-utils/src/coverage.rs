@@ -0,0 +1,90 @@
+#![deny(rust_2018_idioms, unused, unused_import_braces, unused_lifetimes, unused_qualifications, warnings)]
+#![forbid(unsafe_code)]
+
+use {
+    std::{
+        collections::BTreeMap,
+        path::PathBuf,
+        sync::Arc,
+    },
+    thiserror::Error,
+    ootr::check::Check,
+    ootr_dynamic::{
+        BundleRando,
+        BundleRandoErr,
+    },
+    oottracker::{
+        checks::{
+            self,
+            Coverage,
+        },
+        versions::{
+            self,
+            Registry,
+        },
+    },
+    oottracker_utils::rando,
+};
+
+/// Reports which events, locations, and exits of a randomizer version the tracker can auto-track, and how.
+#[derive(clap::Parser)]
+#[clap(version)]
+struct Args {
+    /// A copy of the randomizer or a bundle exported using `oottracker-export-rando-data`. Defaults to the data compiled into the tracker.
+    rando: Option<PathBuf>,
+    /// Report on glitched logic instead of glitchless
+    #[clap(long)]
+    glitched: bool,
+    /// Also list the checks which are tracked, not only the ones which aren't
+    #[clap(short, long)]
+    verbose: bool,
+}
+
+#[derive(Debug, Error)]
+enum Error {
+    #[error("error loading rando data: {0}")] Bundle(#[from] BundleRandoErr),
+    #[error(transparent)] Load(#[from] rando::LoadError),
+    #[error(transparent)] Versions(#[from] versions::Error),
+}
+
+fn kind(check: &Check<BundleRando>) -> &'static str {
+    match check {
+        Check::Event(_) => "events",
+        Check::Location(_) => "locations",
+        _ => "other",
+    }
+}
+
+#[wheel::main]
+fn main(args: Args) -> Result<(), Error> {
+    let rando = if let Some(path) = args.rando {
+        Arc::new(rando::load(path)?)
+    } else {
+        Arc::clone(Registry::builtin()?.builtin_rando())
+    };
+    let report = checks::coverage_report(&*rando, args.glitched)?;
+    println!("auto-tracking coverage for {} ({} logic):", rando.version(), if args.glitched { "glitched" } else { "glitchless" });
+    let mut totals = BTreeMap::<_, (usize, usize)>::default();
+    let (mut shuffleable_exits, mut vanilla_exits) = (0, 0);
+    for (check, coverage) in &report {
+        match coverage {
+            Coverage::SceneTransition => shuffleable_exits += 1,
+            Coverage::VanillaExit => vanilla_exits += 1,
+            _ => {
+                let (tracked, total) = totals.entry(kind(check)).or_default();
+                if coverage.is_tracked() { *tracked += 1 }
+                *total += 1;
+            }
+        }
+    }
+    for (kind, (tracked, total)) in totals {
+        println!("  {}: {}/{} tracked", kind, tracked, total);
+    }
+    println!("  exits: {} learned from scene transitions, {} never shuffled", shuffleable_exits, vanilla_exits);
+    for (check, coverage) in &report {
+        if args.verbose || !coverage.is_tracked() && !coverage.is_exit() {
+            println!("{}: {}", check, coverage);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+#![deny(rust_2018_idioms, unused, unused_import_braces, unused_lifetimes, unused_qualifications, warnings)]
+#![forbid(unsafe_code)]
+
+use {
+    std::{
+        fs::File,
+        io::{
+            self,
+            BufWriter,
+        },
+        path::PathBuf,
+    },
+    pyo3::prelude::*,
+    thiserror::Error,
+    ootr::bundle::Bundle,
+};
+
+/// Exports the data of a copy of the randomizer as a bundle which can be used to build `ootr-static` without Python or network access.
+#[derive(clap::Parser)]
+#[clap(version)]
+struct Args {
+    /// The path to a copy of the randomizer
+    rando_path: PathBuf,
+    /// The path to write the bundle to
+    output: PathBuf,
+}
+
+#[derive(Debug, Error)]
+enum Error {
+    #[error(transparent)] Io(#[from] io::Error),
+    #[error(transparent)] Json(#[from] serde_json::Error),
+    #[error("error loading rando data: {0}")] Rando(ootr_dynamic::RandoErr),
+}
+
+#[wheel::main]
+fn main(args: Args) -> Result<(), Error> {
+    let bundle = Python::with_gil(|py| {
+        let rando = ootr_dynamic::Rando::new(py, args.rando_path);
+        Bundle::from_rando(&rando, rando.version()?)
+    }).map_err(Error::Rando)?;
+    serde_json::to_writer(BufWriter::new(File::create(args.output)?), &bundle)?;
+    Ok(())
+}
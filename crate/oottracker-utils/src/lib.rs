@@ -1,4 +1,5 @@
 #![deny(rust_2018_idioms, unused, unused_import_braces, unused_lifetimes, unused_qualifications, warnings)]
 #![forbid(unsafe_code)]
 
+pub mod rando;
 pub mod version;
@@ -0,0 +1,116 @@
+#![deny(rust_2018_idioms, unused, unused_import_braces, unused_lifetimes, unused_qualifications, warnings)]
+#![forbid(unsafe_code)]
+
+use {
+    std::{
+        collections::HashMap,
+        fmt,
+        hash::Hash,
+        path::PathBuf,
+        sync::Arc,
+    },
+    itertools::Itertools as _,
+    thiserror::Error,
+    ootr::{
+        Rando as _,
+        region::Region,
+    },
+    ootr_dynamic::{
+        BundleRando,
+        BundleRandoErr,
+    },
+    oottracker::checks,
+    oottracker_utils::rando,
+};
+
+/// Compares the logic and data of two versions of the randomizer, e.g. to find out what needs to be updated in the tracker after a new Dev release.
+#[derive(clap::Parser)]
+#[clap(version)]
+struct Args {
+    /// The older version: a copy of the randomizer or a bundle exported using `oottracker-export-rando-data`
+    old: PathBuf,
+    /// The newer version, in the same format
+    new: PathBuf,
+    /// Also list names used in the tracker's RAM flag tables which don't exist in the newer version
+    #[clap(long)]
+    stale_names: bool,
+}
+
+#[derive(Debug, Error)]
+enum Error {
+    #[error("error loading rando data: {0}")] Bundle(#[from] BundleRandoErr),
+    #[error(transparent)] Load(#[from] rando::LoadError),
+}
+
+/// Lists the keys which were added, removed, or whose values changed between two maps.
+///
+/// Keys which were removed and added with identical values are reported as renamed.
+fn diff_map<K: Ord + Hash + fmt::Display, V: PartialEq>(kind: &str, old: &HashMap<K, V>, new: &HashMap<K, V>) -> Vec<String> {
+    let mut removed = old.keys().filter(|key| !new.contains_key(*key)).sorted().collect_vec();
+    let mut added = new.keys().filter(|key| !old.contains_key(*key)).sorted().collect_vec();
+    let mut renamed = Vec::default();
+    removed.retain(|old_key| if let Some(idx) = added.iter().position(|new_key| old[*old_key] == new[*new_key]) {
+        renamed.push((*old_key, added.remove(idx)));
+        false
+    } else {
+        true
+    });
+    removed.into_iter().map(|key| format!("- {} {}", kind, key))
+        .chain(added.into_iter().map(|key| format!("+ {} {}", kind, key)))
+        .chain(renamed.into_iter().map(|(old_key, new_key)| format!("~ {} {} renamed to {}", kind, old_key, new_key)))
+        .chain(old.keys().filter(|key| new.get(*key).map_or(false, |new_value| old[*key] != *new_value)).sorted().map(|key| format!("* {} {} changed", kind, key)))
+        .collect()
+}
+
+fn print_section(header: &str, lines: Vec<String>) {
+    println!("{}:", header);
+    if lines.is_empty() { println!("  (no changes)") }
+    for line in lines { println!("  {}", line) }
+}
+
+fn diff_regions(old: &[Arc<Region<BundleRando>>], new: &[Arc<Region<BundleRando>>]) -> Vec<String> {
+    let old = old.iter().map(|region| (&region.name, region)).collect::<HashMap<_, _>>();
+    let new = new.iter().map(|region| (&region.name, region)).collect::<HashMap<_, _>>();
+    let mut lines = old.keys().filter(|name| !new.contains_key(*name)).sorted().map(|name| format!("- region {}", name))
+        .chain(new.keys().filter(|name| !old.contains_key(*name)).sorted().map(|name| format!("+ region {}", name)))
+        .collect_vec();
+    for name in old.keys().filter(|name| new.contains_key(*name)).sorted() {
+        let (old_region, new_region) = (old[*name], new[*name]);
+        let mut region_lines = Vec::default();
+        if old_region.dungeon != new_region.dungeon { region_lines.push(format!("* dungeon changed from {:?} to {:?}", old_region.dungeon, new_region.dungeon)) }
+        if old_region.scene != new_region.scene { region_lines.push(format!("* scene changed from {:?} to {:?}", old_region.scene, new_region.scene)) }
+        if old_region.hint != new_region.hint { region_lines.push(format!("* hint area changed from {:?} to {:?}", old_region.hint, new_region.hint)) }
+        if old_region.time_passes != new_region.time_passes { region_lines.push(format!("* time_passes changed to {}", new_region.time_passes)) }
+        region_lines.extend(diff_map("event", &old_region.events, &new_region.events));
+        region_lines.extend(diff_map("location", &old_region.locations, &new_region.locations));
+        region_lines.extend(diff_map("exit to", &old_region.exits, &new_region.exits));
+        if !region_lines.is_empty() {
+            lines.push(format!("* region {}:", name));
+            lines.extend(region_lines.into_iter().map(|line| format!("  {}", line)));
+        }
+    }
+    lines
+}
+
+#[wheel::main]
+fn main(args: Args) -> Result<(), Error> {
+    let old = rando::load(args.old)?;
+    let new = rando::load(args.new)?;
+    println!("comparing {} to {}", old.version(), new.version());
+    print_section("glitchless regions", diff_regions(&old.regions(false)?, &new.regions(false)?));
+    print_section("glitched regions", diff_regions(&old.regions(true)?, &new.regions(true)?));
+    print_section("items", diff_map("item", &old.item_table()?, &new.item_table()?));
+    print_section("logic helpers", diff_map("helper", &old.logic_helpers()?, &new.logic_helpers()?));
+    let (old_tricks, new_tricks) = (old.logic_tricks()?, new.logic_tricks()?);
+    print_section("tricks", old_tricks.difference(&new_tricks).sorted().map(|trick| format!("- trick {}", trick))
+        .chain(new_tricks.difference(&old_tricks).sorted().map(|trick| format!("+ trick {}", trick)))
+        .collect());
+    print_section("settings", diff_map("setting", &old.setting_infos()?, &new.setting_infos()?));
+    if args.stale_names {
+        let stale_names = checks::stale_names(&new)?;
+        println!("names in the tracker's RAM flag tables which don't exist in {}:", new.version());
+        if stale_names.is_empty() { println!("  (none)") }
+        for name in stale_names { println!("  {}", name) }
+    }
+    Ok(())
+}
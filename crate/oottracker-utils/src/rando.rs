@@ -0,0 +1,28 @@
+use {
+    std::path::PathBuf,
+    pyo3::prelude::*,
+    ootr::bundle::Bundle,
+    ootr_dynamic::{
+        BundleRando,
+        BundleRandoErr,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    #[error("error loading rando data: {0}")] Bundle(#[from] BundleRandoErr),
+    #[error("error loading rando data: {0}")] Rando(ootr_dynamic::RandoErr),
+}
+
+/// Loads a copy of the randomizer or a bundle exported using `oottracker-export-rando-data`.
+pub fn load(path: PathBuf) -> Result<BundleRando, LoadError> {
+    Ok(if path.is_dir() {
+        let bundle = Python::with_gil(|py| {
+            let rando = ootr_dynamic::Rando::new(py, path);
+            Bundle::from_rando(&rando, rando.version()?)
+        }).map_err(LoadError::Rando)?;
+        BundleRando::from_bundle(bundle)?
+    } else {
+        BundleRando::load(path)?
+    })
+}
@@ -4,10 +4,7 @@ use {
         time::Duration,
     },
     itertools::Itertools as _,
-    ootr_utils::{
-        PyModules,
-        Version,
-    },
+    ootr_utils::PyModules,
     pyo3::{
         prelude::*,
         types::PyDict,
@@ -51,8 +48,10 @@ use {
         MwRooms,
         Restreams,
         Rooms,
+        Versions,
         edit_room,
         get_room,
+        mw::parse_rando_version,
         restream::render_double_cell,
     },
 };
@@ -195,14 +194,20 @@ fn format_item_kind(modules: PyModules<'_>, kind: u16) -> PyResult<String> {
 #[derive(Debug, thiserror::Error, rocket_util::Error)]
 enum NotesError {
     #[error(transparent)] Python(#[from] PyErr),
+    #[error("unsupported randomizer version: {0}")] RandoVersion(String),
 }
 
 #[rocket::get("/mw-notes/<room>")]
-async fn mw_notes(mw_rooms: &State<MwRooms>, room: &str) -> Result<Option<RawHtml<String>>, NotesError> {
+async fn mw_notes(mw_rooms: &State<MwRooms>, versions: &State<Versions>, room: &str) -> Result<Option<RawHtml<String>>, NotesError> {
     let mw_rooms = mw_rooms.read().await;
     let Some(mw_room) = mw_rooms.get(room) else { return Ok(None) };
     let mw_room = mw_room.read().await;
-    let rando_version = Version::from_dev(6, 2, 205); //TODO don't hardcode
+    let rando_version = if let Some(ref rando_version) = mw_room.rando_version {
+        rando_version.clone()
+    } else {
+        let builtin_version = versions.builtin_rando().version();
+        parse_rando_version(builtin_version).ok_or_else(|| NotesError::RandoVersion(builtin_version.to_owned()))?
+    };
     Python::with_gil(|py| {
         let modules = rando_version.py_modules(py)?;
         Ok(Some(html! {
@@ -326,7 +331,7 @@ async fn click(pool: &State<PgPool>, rooms: &State<Rooms>, name: &str, cell_id:
     Ok(Redirect::to(rocket::uri!(room(name, _))))
 }
 
-pub(crate) fn rocket(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms) -> Rocket<rocket::Build> {
+pub(crate) fn rocket(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, versions: Versions) -> Rocket<rocket::Build> {
     rocket::custom(rocket::Config {
         port: 24807,
         ..rocket::Config::default()
@@ -335,6 +340,7 @@ pub(crate) fn rocket(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms:
     .manage(rooms)
     .manage(restreams)
     .manage(mw_rooms)
+    .manage(versions)
     .mount("/static", FileServer::new(relative!("../../assets/web/static"), rocket::fs::Options::None))
     .mount("/", rocket::routes![
         index,
@@ -40,11 +40,17 @@ use {
         watch::*,
     },
     warp::Filter as _,
+    ootr_dynamic::BundleRando,
     oottracker::{
         Knowledge,
         ModelState,
         Ram,
         TrackerCtx,
+        settings_string,
+        versions::{
+            self,
+            Registry,
+        },
     },
     crate::{
         mw::MwState,
@@ -60,6 +66,7 @@ mod websocket;
 type MwRooms = Arc<RwLock<HashMap<String, Arc<RwLock<MwState>>>>>;
 type Restreams = Arc<RwLock<HashMap<String, RestreamState>>>;
 type Rooms = Arc<Mutex<HashMap<String, RoomState>>>;
+type Versions = Arc<Registry>;
 
 struct RoomState {
     name: String,
@@ -67,6 +74,8 @@ struct RoomState {
     rx: Receiver<()>,
     last_saved: Instant,
     model: ModelState,
+    /// The randomizer version the room's seed was generated with. `None` means the built-in data is used.
+    rando_version: Option<String>,
 }
 
 impl RoomState {
@@ -84,9 +93,14 @@ impl RoomState {
             tx, rx, model,
             name: name.to_owned(),
             last_saved: Instant::now(),
+            rando_version: None,
         }
     }
 
+    pub(crate) fn rando<'a>(&self, versions: &'a Registry) -> &'a Arc<BundleRando> {
+        self.rando_version.as_deref().map_or_else(|| versions.builtin_rando(), |version| versions.for_seed(version))
+    }
+
     pub(crate) async fn save(&mut self, pool: &PgPool) -> Result<(), Error> {
         if self.last_saved.elapsed() >= Duration::from_secs(60) {
             self.force_save(pool).await?;
@@ -97,7 +111,7 @@ impl RoomState {
     pub(crate) async fn force_save(&mut self, pool: &PgPool) -> Result<(), Error> {
         let ModelState { ref knowledge, ref ram, .. } = self.model; //TODO include tracker context
         //TODO versioning (e.g. to recover RAM from previous versions)
-        sqlx::query!("INSERT INTO rooms (name, knowledge, ram) VALUES ($1, $2, $3) ON CONFLICT (name) DO UPDATE SET knowledge = EXCLUDED.knowledge, ram = EXCLUDED.ram", self.name, serde_json::to_value(knowledge)?, &ram.to_ranges()[..]).execute(pool).await?;
+        sqlx::query!("INSERT INTO rooms (name, knowledge, ram, rando_version) VALUES ($1, $2, $3, $4) ON CONFLICT (name) DO UPDATE SET knowledge = EXCLUDED.knowledge, ram = EXCLUDED.ram, rando_version = EXCLUDED.rando_version", self.name, serde_json::to_value(knowledge)?, &ram.to_ranges()[..], self.rando_version).execute(pool).await?;
         self.last_saved = Instant::now();
         Ok(())
     }
@@ -131,8 +145,12 @@ enum Error {
     Read(ReadError),
     Rocket(rocket::error::Error),
     RoomName,
+    SettingsString(settings_string::DecodeError<BundleRando>),
     Sql(sqlx::Error),
     Task(tokio::task::JoinError),
+    #[from(ignore)]
+    UnknownRandoVersion(String),
+    Versions(versions::Error),
     Write(WriteError),
 }
 
@@ -145,8 +163,11 @@ impl fmt::Display for Error {
             Self::Read(e) => write!(f, "read error: {e}"),
             Self::Rocket(e) => write!(f, "rocket error: {e}"),
             Self::RoomName => write!(f, "invalid room name"),
+            Self::SettingsString(e) => write!(f, "error decoding settings string: {e}"),
             Self::Sql(e) => write!(f, "database error: {e}"),
             Self::Task(e) => write!(f, "task error: {e}"),
+            Self::UnknownRandoVersion(version) => write!(f, "no rando data for version {version}"),
+            Self::Versions(e) => write!(f, "error loading randomizer versions: {e}"),
             Self::Write(e) => write!(f, "write error: {e}"),
         }
     }
@@ -161,8 +182,11 @@ impl<'r> rocket::response::Responder<'r, 'static> for Error {
             Self::Read(_) => Err(Status::InternalServerError),
             Self::Rocket(_) => Err(Status::InternalServerError),
             Self::RoomName => Err(Status::NotFound),
+            Self::SettingsString(_) => Err(Status::BadRequest),
             Self::Sql(_) => Err(Status::InternalServerError),
             Self::Task(_) => Err(Status::InternalServerError),
+            Self::UnknownRandoVersion(_) => Err(Status::NotFound),
+            Self::Versions(_) => Err(Status::InternalServerError),
             Self::Write(_) => Err(Status::InternalServerError),
         }
     }
@@ -170,12 +194,14 @@ impl<'r> rocket::response::Responder<'r, 'static> for Error {
 
 #[wheel::main(rocket)]
 async fn main() -> Result<(), Error> {
+    let versions = Versions::new(Registry::load_user()?);
     let pool = PgPool::connect_with(PgConnectOptions::default().database("oottracker").application_name("oottracker-web")).await?;
     let rooms = {
         let mut rooms = HashMap::default();
-        let mut query = sqlx::query!(r#"SELECT name, knowledge AS "knowledge: Json<Knowledge>", ram FROM rooms"#).fetch(&pool);
+        let mut query = sqlx::query!(r#"SELECT name, knowledge AS "knowledge: Json<Knowledge>", ram, rando_version FROM rooms"#).fetch(&pool);
         while let Some(room) = query.try_next().await? {
-            let state = RoomState::from_model(&room.name, ModelState { knowledge: room.knowledge.0, ram: Ram::from_range_bufs(room.ram)?, tracker_ctx: TrackerCtx::default() });
+            let mut state = RoomState::from_model(&room.name, ModelState { knowledge: room.knowledge.0, ram: Ram::from_range_bufs(room.ram)?, tracker_ctx: TrackerCtx::default() });
+            state.rando_version = room.rando_version;
             rooms.insert(room.name, state);
         }
         Rooms::new(Mutex::new(rooms))
@@ -198,10 +224,11 @@ async fn main() -> Result<(), Error> {
         let rooms = Rooms::clone(&rooms);
         let restreams = Restreams::clone(&restreams);
         let mw_rooms = MwRooms::clone(&mw_rooms);
-        let handler = warp::ws().and_then(move |ws| websocket::ws_handler(pool.clone(), Rooms::clone(&rooms), Restreams::clone(&restreams), MwRooms::clone(&mw_rooms), ws));
+        let versions = Versions::clone(&versions);
+        let handler = warp::ws().and_then(move |ws| websocket::ws_handler(pool.clone(), Rooms::clone(&rooms), Restreams::clone(&restreams), MwRooms::clone(&mw_rooms), Versions::clone(&versions), ws));
         tokio::spawn(warp::serve(handler).run(([127, 0, 0, 1], 24808))).err_into()
     };
-    let rocket_task = tokio::spawn(http::rocket(pool, rooms, restreams, mw_rooms).launch()).map(|res| match res {
+    let rocket_task = tokio::spawn(http::rocket(pool, rooms, restreams, mw_rooms, versions).launch()).map(|res| match res {
         Ok(Ok(_)) => Ok(()),
         Ok(Err(e)) => Err(Error::from(e)),
         Err(e) => Err(Error::from(e)),
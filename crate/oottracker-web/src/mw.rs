@@ -12,6 +12,10 @@ use {
         Either,
         pending,
     },
+    ootr_utils::{
+        Branch,
+        Version,
+    },
     tokio::{
         select,
         sync::{
@@ -48,6 +52,26 @@ pub(crate) struct MwState {
     pub(crate) worlds: Vec<(watch::Sender<()>, watch::Receiver<()>, ModelState, Vec<MwItem>, HashSet<MwItem>)>,
     pub(crate) autotracker_delay: Duration,
     pub(crate) incoming_queue: mpsc::UnboundedSender<AutoUpdate>,
+    /// Used to name items and locations. `None` means the version of the built-in rando data is used.
+    pub(crate) rando_version: Option<Version>,
+}
+
+/// Parses a randomizer version string as reported by the randomizer, e.g. `6.2.205` for the main Dev branch or `6.2.205 R-1` for Dev-R.
+pub(crate) fn parse_rando_version(version: &str) -> Option<Version> {
+    let (base, supplementary) = match version.split_once(' ') {
+        Some((base, suffix)) => (base, Some(suffix.strip_prefix("R-")?.parse().ok()?)),
+        None => (version, None),
+    };
+    let mut parts = base.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+    if parts.next().is_some() { return None }
+    Some(if let Some(supplementary) = supplementary {
+        Version::from_branch(Branch::DevR, major, minor, patch, supplementary)
+    } else {
+        Version::from_dev(major, minor, patch)
+    })
 }
 
 impl MwState {
@@ -59,6 +83,7 @@ impl MwState {
                 (tx, rx, ModelState { ram: save.unwrap_or_default().into(), knowledge: Default::default(), tracker_ctx: Default::default() }, queue, HashSet::default())
             }).collect(),
             autotracker_delay: Duration::default(),
+            rando_version: None,
             incoming_queue,
         }));
         let this_clone = Arc::clone(&this);
@@ -2,6 +2,7 @@ use {
     std::{
         borrow::Cow,
         collections::HashMap,
+        sync::Arc,
     },
     tokio::sync::watch::*,
     ootr::model::{
@@ -10,8 +11,14 @@ use {
         MainDungeon,
         Stone,
     },
+    ootr_dynamic::BundleRando,
     oottracker::{
         ModelState,
+        knowledge::{
+            Contradiction,
+            Fact,
+            Knowledge,
+        },
         ui::{
             CellOverlay,
             CellRender,
@@ -19,11 +26,14 @@ use {
             ImageInfo,
             LocationStyle,
         },
+        versions::Registry,
     },
 };
 
 pub(crate) struct RestreamState {
     worlds: Vec<(Sender<()>, Receiver<()>, HashMap<String, ModelState>)>,
+    /// The randomizer version the restream's seeds were generated with. `None` means the built-in data is used.
+    rando_version: Option<String>,
 }
 
 impl RestreamState {
@@ -33,9 +43,18 @@ impl RestreamState {
                 let (tx, rx) = channel(());
                 (tx, rx, players.into_iter().map(|player| (player.to_owned(), ModelState::default())).collect())
             }).collect(),
+            rando_version: None,
         }
     }
 
+    pub(crate) fn set_rando_version(&mut self, version: String) {
+        self.rando_version = Some(version);
+    }
+
+    pub(crate) fn rando<'a>(&self, versions: &'a Registry) -> &'a Arc<BundleRando> {
+        self.rando_version.as_deref().map_or_else(|| versions.builtin_rando(), |version| versions.for_seed(version))
+    }
+
     pub(crate) fn runner(&self, runner: &str) -> Option<(&Sender<()>, &Receiver<()>, &ModelState)> {
         self.worlds.iter().filter_map(|(tx, rx, players)| players.get(runner).map(move |state| (&*tx, &*rx, state))).next()
     }
@@ -45,6 +64,18 @@ impl RestreamState {
     }
 }
 
+/// Merges what the two runners know about the location of the given dungeon reward.
+pub(crate) fn reward_location(runner1: &ModelState, runner2: &ModelState, reward: DungeonReward) -> Result<Option<DungeonRewardLocation>, Contradiction> {
+    let fact = Fact::DungeonRewardLocation { reward };
+    let [knowledge1, knowledge2] = [runner1, runner2].map(|runner| {
+        let mut knowledge = Knowledge::default();
+        if let Some(&location) = runner.knowledge.dungeon_reward_locations.get(&reward) { knowledge.dungeon_reward_locations.insert(reward, location); }
+        if let Some(&source) = runner.knowledge.sources.get(&fact) { knowledge.sources.insert(fact.clone(), source); }
+        knowledge
+    });
+    Ok((knowledge1 & knowledge2)?.dungeon_reward_locations.get(&reward).copied())
+}
+
 pub(crate) fn render_double_cell(runner1: &ModelState, runner2: &ModelState, reward: DungeonReward) -> CellRender {
     let img_filename = match reward {
         DungeonReward::Medallion(med) => Cow::Owned(format!("{}_medallion", med.element().to_ascii_lowercase())),
@@ -58,7 +89,11 @@ pub(crate) fn render_double_cell(runner1: &ModelState, runner2: &ModelState, rew
         (true, false) => CellStyle::RightDimmed,
         (true, true) => CellStyle::Normal,
     };
-    let location = (runner1.knowledge.clone() & runner2.knowledge.clone()).map(|knowledge| knowledge.dungeon_reward_locations.get(&reward).copied()).unwrap_or_default(); //TODO display contradiction errors differently?
+    let (location, loc_style) = match reward_location(runner1, runner2, reward) {
+        Ok(Some(loc)) => (Some(loc), LocationStyle::Normal),
+        Ok(None) => (None, LocationStyle::Dimmed),
+        Err(_) => (None, LocationStyle::Contradiction),
+    };
     let loc_img_filename = match location {
         None => "unknown_text",
         Some(DungeonRewardLocation::Dungeon(MainDungeon::DekuTree)) => "deku_text",
@@ -76,7 +111,7 @@ pub(crate) fn render_double_cell(runner1: &ModelState, runner2: &ModelState, rew
         style,
         overlay: CellOverlay::Location {
             loc: ImageInfo::new(loc_img_filename),
-            style: if location.is_some() { LocationStyle::Normal } else { LocationStyle::Dimmed },
+            style: loc_style,
         },
     }
 }
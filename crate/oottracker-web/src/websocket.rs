@@ -23,29 +23,37 @@ use {
             WebSocket,
         },
     },
-    oottracker::websocket::{
-        ClientMessage,
-        MwItem,
-        ServerMessage,
+    oottracker::{
+        ui::TrackerCellId,
+        websocket::{
+            ClientMessage,
+            MwItem,
+            ServerMessage,
+        },
     },
     crate::{
         Error,
         MwRooms,
         Restreams,
         Rooms,
+        Versions,
         edit_room,
         get_room,
         mw::{
+            self,
             AutoUpdate,
             MwState,
         },
-        restream::render_double_cell,
+        restream::{
+            render_double_cell,
+            reward_location,
+        },
     },
 };
 
 type WsSink = Arc<Mutex<SplitSink<WebSocket, Message>>>;
 
-async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, mut stream: impl Stream<Item = Result<Message, warp::Error>> + Unpin + Send, sink: WsSink) -> Result<(), Error> {
+async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, versions: Versions, mut stream: impl Stream<Item = Result<Message, warp::Error>> + Unpin + Send, sink: WsSink) -> Result<(), Error> {
     let ping_sink = WsSink::clone(&sink);
     tokio::spawn(async move {
         loop {
@@ -114,7 +122,7 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                 let restreams = Restreams::clone(&restreams);
                 let sink = WsSink::clone(&sink);
                 tokio::spawn(async move {
-                    let (mut old_cells, mut rx) = {
+                    let (mut old_cells, mut old_contradictions, mut rx) = {
                         let restreams = restreams.read().await;
                         let restream = match restreams.get(&restream) {
                             Some(restream) => restream,
@@ -140,11 +148,18 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                         let cells = layout.cells().into_iter()
                             .map(|reward| render_double_cell(runner1, runner2, reward))
                             .collect::<Vec<_>>();
+                        let contradictions = layout.cells().into_iter()
+                            .filter_map(|reward| reward_location(runner1, runner2, reward).err())
+                            .map(|e| e.to_string())
+                            .collect::<Vec<_>>();
                         if ServerMessage::Init(cells.clone()).write_warp(&mut *sink.lock().await).await.is_err() { return } //TODO better error handling
-                        (cells, rx.clone())
+                        for contradiction in &contradictions {
+                            if ServerMessage::from_error(contradiction).write_warp(&mut *sink.lock().await).await.is_err() { return } //TODO better error handling
+                        }
+                        (cells, contradictions, rx.clone())
                     };
                     while let Ok(()) = rx.changed().await { //TODO better error handling
-                        let new_cells = {
+                        let (new_cells, new_contradictions) = {
                             let restreams = restreams.read().await;
                             let restream = match restreams.get(&restream) {
                                 Some(restream) => restream,
@@ -167,14 +182,23 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                                     return
                                 }
                             };
-                            layout.cells().into_iter().map(|reward| render_double_cell(runner1, runner2, reward)).collect::<Vec<_>>()
+                            (
+                                layout.cells().into_iter().map(|reward| render_double_cell(runner1, runner2, reward)).collect::<Vec<_>>(),
+                                layout.cells().into_iter().filter_map(|reward| reward_location(runner1, runner2, reward).err()).map(|e| e.to_string()).collect::<Vec<_>>(),
+                            )
                         };
                         for (i, (old_cell, new_cell)) in old_cells.iter().zip(&new_cells).enumerate() {
                             if old_cell != new_cell {
                                 if (ServerMessage::Update { cell_id: i.try_into().expect("too many cells"), new_cell: new_cell.clone() }).write_warp(&mut *sink.lock().await).await.is_err() { return } //TODO better error handling
                             }
                         }
+                        for contradiction in &new_contradictions {
+                            if !old_contradictions.contains(contradiction) {
+                                if ServerMessage::from_error(contradiction).write_warp(&mut *sink.lock().await).await.is_err() { return } //TODO better error handling
+                            }
+                        }
                         old_cells = new_cells;
+                        old_contradictions = new_contradictions;
                     }
                 });
             }
@@ -187,6 +211,7 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                         return Ok(())
                     }
                 };
+                let rando = Arc::clone(restream.rando(&versions));
                 let (tx, runner) = match restream.runner_mut(&runner) {
                     Some((tx, _, runner)) => (tx, runner),
                     None => {
@@ -206,7 +231,15 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                 } else {
                     let _ /* no med right-click menu in web app */ = cell.kind().left_click(true /*TODO verify that the client has access?*/, KeyboardModifiers::default(), runner);
                 }
+                let progression_res = if !matches!(cell, TrackerCellId::GoMode | TrackerCellId::GoBk) { // don't override a manually set progression mode
+                    runner.update_progression_mode(&*rando)
+                } else {
+                    Ok(())
+                };
                 tx.send(()).expect("failed to notify websockets about state change");
+                if let Err(e) = progression_res {
+                    let _ = ServerMessage::from_error(e).write_warp(&mut *sink.lock().await).await; //TODO better error handling
+                }
             }
             ClientMessage::SubscribeRaw { room } => {
                 let rooms = Rooms::clone(&rooms);
@@ -248,6 +281,16 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                 }); //TODO send errors from task to client
             }
             ClientMessage::SetRaw { room, state } => edit_room(pool, &rooms, room, |room| { room.model = state; Ok(()) }).await?,
+            ClientMessage::SetSettingsString { room, settings_string } => edit_room(pool, &rooms, room, |room| {
+                let rando = room.rando(&versions);
+                room.model.knowledge.apply_settings_string(&**rando, &settings_string)?;
+                Ok(())
+            }).await?,
+            ClientMessage::SetRandoVersion { room, version } => edit_room(pool, &rooms, room, |room| {
+                if versions.get(&version).is_none() { return Err(Error::UnknownRandoVersion(version)) }
+                room.rando_version = Some(version);
+                Ok(())
+            }).await?,
             ClientMessage::ClickRoom { room, layout, cell_id, right } => {
                 let cell = match layout.cells().get(usize::from(cell_id)) {
                     Some(cell) => cell.id,
@@ -256,14 +299,22 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
                         return Ok(())
                     }
                 };
+                let mut progression_res = Ok(());
                 edit_room(pool, &rooms, room, |room| {
                     if right {
                         let _ /* no med right-click menu in web app */ = cell.kind().right_click(true /*TODO verify that the client has access?*/, KeyboardModifiers::default(), &mut room.model);
                     } else {
                         let _ /* no med right-click menu in web app */ = cell.kind().left_click(true /*TODO verify that the client has access?*/, KeyboardModifiers::default(), &mut room.model);
                     }
+                    if !matches!(cell, TrackerCellId::GoMode | TrackerCellId::GoBk) { // don't override a manually set progression mode
+                        let rando = room.rando(&versions);
+                        progression_res = room.model.update_progression_mode(&**rando);
+                    }
                     Ok(())
                 }).await?;
+                if let Err(e) = progression_res {
+                    let _ = ServerMessage::from_error(e).write_warp(&mut *sink.lock().await).await; //TODO better error handling
+                }
             }
             ClientMessage::MwCreateRoom { room, worlds } => {
                 mw_rooms.write().await.insert(room, MwState::new(worlds));
@@ -376,18 +427,42 @@ async fn client_session(pool: &PgPool, rooms: Rooms, restreams: Restreams, mw_ro
             } else {
                 let _ = ServerMessage::from_error("no such multiworld room").write_warp(&mut *sink.lock().await).await; //TODO better error handling
             },
+            ClientMessage::MwSetRandoVersion { room, version } => if let Some(room) = mw_rooms.read().await.get(&room) {
+                if let Some(version) = mw::parse_rando_version(&version) {
+                    room.write().await.rando_version = Some(version);
+                } else {
+                    let _ = ServerMessage::from_error(format!("unsupported randomizer version: {version}")).write_warp(&mut *sink.lock().await).await; //TODO better error handling
+                }
+            } else {
+                let _ = ServerMessage::from_error("no such multiworld room").write_warp(&mut *sink.lock().await).await; //TODO better error handling
+            },
+            ClientMessage::SetRestreamRandoVersion { restream, version } => {
+                let mut restreams = restreams.write().await;
+                let restream = match restreams.get_mut(&restream) {
+                    Some(restream) => restream,
+                    None => {
+                        let _ = ServerMessage::from_error("no such restream").write_warp(&mut *sink.lock().await).await; //TODO better error handling
+                        return Ok(())
+                    }
+                };
+                if versions.get(&version).is_none() {
+                    let _ = ServerMessage::from_error(Error::UnknownRandoVersion(version)).write_warp(&mut *sink.lock().await).await; //TODO better error handling
+                    return Ok(())
+                }
+                restream.set_rando_version(version);
+            }
         }
     }
 }
 
-async fn client_connection(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, ws: WebSocket) {
+async fn client_connection(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, versions: Versions, ws: WebSocket) {
     let (ws_sink, ws_stream) = ws.split();
     let ws_sink = WsSink::new(Mutex::new(ws_sink));
-    if let Err(e) = client_session(&pool, rooms, restreams, mw_rooms, ws_stream, WsSink::clone(&ws_sink)).await {
+    if let Err(e) = client_session(&pool, rooms, restreams, mw_rooms, versions, ws_stream, WsSink::clone(&ws_sink)).await {
         let _ = ServerMessage::from_error(e).write_warp(&mut *ws_sink.lock().await).await;
     }
 }
 
-pub(crate) async fn ws_handler(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, ws: warp::ws::Ws) -> Result<impl Reply, Rejection> {
-    Ok(ws.on_upgrade(move |ws| client_connection(pool, rooms, restreams, mw_rooms, ws)))
+pub(crate) async fn ws_handler(pool: PgPool, rooms: Rooms, restreams: Restreams, mw_rooms: MwRooms, versions: Versions, ws: warp::ws::Ws) -> Result<impl Reply, Rejection> {
+    Ok(ws.on_upgrade(move |ws| client_connection(pool, rooms, restreams, mw_rooms, versions, ws)))
 }
@@ -1,733 +1,968 @@
 use {
     std::{
+        borrow::Cow,
+        collections::{
+            BTreeSet,
+            HashSet,
+        },
         fmt,
         io,
+        iter,
         sync::Arc,
     },
     derivative::Derivative,
     derive_more::From,
     ootr::{
         Rando,
-        region::Mq,
+        region::{
+            EntranceInfo,
+            EntrancePair,
+            Mq,
+        },
     },
     crate::{
         Check,
         ModelState,
+        info_tables::{
+            EventChkInf,
+            InfTable,
+            ItemGetInf,
+        },
+        knowledge::NpcHint,
+        ram::Ram,
         region::RegionLookupError,
+        scene::SceneFlags,
     },
 };
 
+/// Whether a check has been done, as far as the tracker can tell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckedState {
+    Checked,
+    NotChecked,
+    /// The tracker can't know this by design, e.g. because the check is repeatable.
+    Unknown(Cow<'static, str>),
+    /// The tracker doesn't support this check (yet), e.g. because it was added in a newer version of the randomizer.
+    Unsupported(Cow<'static, str>),
+}
+
+impl CheckedState {
+    /// `None` if it's unknown or unsupported whether the check has been done.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            CheckedState::Checked => Some(true),
+            CheckedState::NotChecked => Some(false),
+            CheckedState::Unknown(_) | CheckedState::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<bool> for CheckedState {
+    fn from(checked: bool) -> CheckedState {
+        if checked { CheckedState::Checked } else { CheckedState::NotChecked }
+    }
+}
+
+impl fmt::Display for CheckedState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckedState::Checked => write!(f, "checked"),
+            CheckedState::NotChecked => write!(f, "not checked"),
+            CheckedState::Unknown(reason) => write!(f, "unknown ({})", reason),
+            CheckedState::Unsupported(reason) => write!(f, "unsupported ({})", reason),
+        }
+    }
+}
+
+const REPEATABLE: CheckedState = CheckedState::Unknown(Cow::Borrowed("repeatable check"));
+const UNIMPLEMENTED: CheckedState = CheckedState::Unsupported(Cow::Borrowed("not yet implemented"));
+
 pub trait CheckExt {
-    fn checked(&self, model: &ModelState) -> Option<bool>; //TODO change return type to bool once all used checks are implemented
+    fn checked_state(&self, model: &ModelState) -> CheckedState;
+
+    fn checked(&self, model: &ModelState) -> Option<bool> { //TODO change return type to bool once all used checks are implemented
+        self.checked_state(model).as_bool()
+    }
 }
 
 impl<R: Rando> CheckExt for Check<R> {
-    fn checked(&self, model: &ModelState) -> Option<bool> {
+    fn checked_state(&self, model: &ModelState) -> CheckedState {
         // event and location lists from Dev-R as of commit b670183e9aff520c20ac2ee65aa55e3740c5f4b4
-        if let Some(checked) = model.ram.save.gold_skulltulas.checked(self) { return Some(checked) }
-        if let Some(checked) = model.ram.scene_flags().checked(self) { return Some(checked) }
-        if let Some(checked) = model.ram.save.event_chk_inf.checked(self) { return Some(checked) }
-        if let Some(checked) = model.ram.save.item_get_inf.checked(self) { return Some(checked) }
-        if let Some(checked) = model.ram.save.inf_table.checked(self) { return Some(checked) }
+        if let Some(checked) = model.ram.save.gold_skulltulas.checked(self) { return checked.into() }
+        if let Some(checked) = model.ram.scene_flags().checked(self) { return checked.into() }
+        if let Some(checked) = model.ram.save.event_chk_inf.checked(self) { return checked.into() }
+        if let Some(checked) = model.ram.save.item_get_inf.checked(self) { return checked.into() }
+        if let Some(checked) = model.ram.save.inf_table.checked(self) { return checked.into() }
         match self {
             Check::AnonymousEvent(at_check, id) => match (&**at_check, id) {
-                (Check::Event(event), 0) if *event == "Deku Tree Clear" /*vanilla*/ => Some(
+                (Check::Event(event), 0) if *event == "Deku Tree Clear" /*vanilla*/ => CheckedState::from(
                     model.ram.scene_flags().deku_tree.room_clear.contains(
                         crate::scene::DekuTreeRoomClear::SCRUBS_231_PUZZLE
                     )
                 ),
-                (Check::Exit { from_mq: None, from, to }, 0) if *from == "Death Mountain" && *to == "Death Mountain Summit" => Some(
+                (Check::Exit { from_mq: None, from, to }, 0) if *from == "Death Mountain" && *to == "Death Mountain Summit" => CheckedState::from(
                     model.ram.scene_flags().death_mountain.switches.contains(
                         crate::scene::DeathMountainSwitches::DMT_TO_SUMMIT_FIRST_BOULDER
                         | crate::scene::DeathMountainSwitches::DMT_TO_SUMMIT_SECOND_BOULDER
                     )
                 ),
-                (Check::Exit { from_mq: None, from, to }, 1) if *from == "Death Mountain" && *to == "Death Mountain Summit" => Some(
+                (Check::Exit { from_mq: None, from, to }, 1) if *from == "Death Mountain" && *to == "Death Mountain Summit" => CheckedState::from(
                     model.ram.scene_flags().death_mountain.switches.contains(
                         crate::scene::DeathMountainSwitches::BLOW_UP_DC_ENTRANCE
                         | crate::scene::DeathMountainSwitches::PLANT_BEAN
                     )
                 ),
-                (Check::Exit { from_mq: Some(Mq::Vanilla), from, to }, 0) if *from == "Deku Tree Lobby" && *to == "Deku Tree Basement Backroom" => Some(
+                (Check::Exit { from_mq: Some(Mq::Vanilla), from, to }, 0) if *from == "Deku Tree Lobby" && *to == "Deku Tree Basement Backroom" => CheckedState::from(
                     model.ram.scene_flags().deku_tree.switches.contains(
                         crate::scene::DekuTreeSwitches::BASEMENT_BURN_FIRST_WEB_TO_BACK_ROOM
                         | crate::scene::DekuTreeSwitches::LIGHT_TORCHES_AFTER_WATER_ROOM
                     )
                 ),
-                (Check::Exit { from_mq: Some(Mq::Vanilla), from, to }, 2) if *from == "Deku Tree Lobby" && *to == "Deku Tree Basement Backroom" => Some(
+                (Check::Exit { from_mq: Some(Mq::Vanilla), from, to }, 2) if *from == "Deku Tree Lobby" && *to == "Deku Tree Basement Backroom" => CheckedState::from(
                     model.ram.scene_flags().deku_tree.switches.contains(
                         crate::scene::DekuTreeSwitches::BASEMENT_PUSHED_BLOCK
                     )
                 ),
-                (Check::Exit { from_mq: Some(Mq::Vanilla), from, to }, 1) if *from == "Deku Tree Lobby" && *to == "Deku Tree Boss Room" => Some(
+                (Check::Exit { from_mq: Some(Mq::Vanilla), from, to }, 1) if *from == "Deku Tree Lobby" && *to == "Deku Tree Boss Room" => CheckedState::from(
                     model.ram.scene_flags().deku_tree.switches.contains(
                         crate::scene::DekuTreeSwitches::BASEMENT_PUSHED_BLOCK
                     )
                 ),
-                (Check::Location(loc), 0) if *loc == "Deku Tree Queen Gohma Heart" => Some(
+                (Check::Location(loc), 0) if *loc == "Deku Tree Queen Gohma Heart" => CheckedState::from(
                     model.ram.scene_flags().deku_tree.room_clear.contains(
                         crate::scene::DekuTreeRoomClear::SCRUBS_231_PUZZLE
                     )
                 ),
-                (Check::Location(loc), 0) if *loc == "Queen Gohma" => Some(
+                (Check::Location(loc), 0) if *loc == "Queen Gohma" => CheckedState::from(
                     model.ram.scene_flags().deku_tree.room_clear.contains(
                         crate::scene::DekuTreeRoomClear::SCRUBS_231_PUZZLE
                     )
                 ),
                 // the anonymous event for this skulltula is really just collecting it from a different region with different item requirements
-                (Check::Location(loc), 0) if *loc == "Forest Temple GS Level Island Courtyard" => Some(
+                (Check::Location(loc), 0) if *loc == "Forest Temple GS Level Island Courtyard" => CheckedState::from(
                     model.ram.save.gold_skulltulas.forest_temple.contains(
                         crate::scene::ForestTempleGoldSkulltulas::FOREST_TEMPLE_GS_LEVEL_ISLAND_COURTYARD
                     )
                 ),
                 // the anonymous events for this chest are really just opening it from different regions with different item requirements
-                (Check::Location(loc), 0) | (Check::Location(loc), 1) if *loc == "Forest Temple Raised Island Courtyard Chest" => Some(
+                (Check::Location(loc), 0) | (Check::Location(loc), 1) if *loc == "Forest Temple Raised Island Courtyard Chest" => CheckedState::from(
                     model.ram.scene_flags().forest_temple.chests.contains(
                         crate::scene::ForestTempleChests::FOREST_TEMPLE_RAISED_ISLAND_COURTYARD_CHEST
                     )
                 ),
-                (_, _) => None, //TODO make a list of all anonymous events
+                (_, _) => UNIMPLEMENTED, //TODO make a list of all anonymous events
             },
             Check::Event(event) => match &event[..] {
                 // Overworld
-                "Showed Mido Sword & Shield" => None, //TODO
-                "Bonooru" => None, //TODO
-                "Carpenter Rescue" => None, //TODO
-                "GF Gate Open" => None, //TODO
-                "Sell Big Poe" => None, //TODO
-                "Skull Mask" => None, //TODO
-                "Mask of Truth" => None, //TODO
-                "Drain Well" => None, //TODO
-                "GC Woods Warp Open" => Some(
+                "Showed Mido Sword & Shield" => UNIMPLEMENTED, //TODO
+                "Bonooru" => UNIMPLEMENTED, //TODO
+                "Carpenter Rescue" => UNIMPLEMENTED, //TODO
+                "GF Gate Open" => UNIMPLEMENTED, //TODO
+                "Sell Big Poe" => UNIMPLEMENTED, //TODO
+                "Skull Mask" => UNIMPLEMENTED, //TODO
+                "Mask of Truth" => UNIMPLEMENTED, //TODO
+                "Drain Well" => UNIMPLEMENTED, //TODO
+                "GC Woods Warp Open" => CheckedState::from(
                     model.ram.scene_flags().goron_city.switches.intersects(
                         crate::scene::GoronCitySwitches::LW_LEFT_BOULDER
                         | crate::scene::GoronCitySwitches::LW_MIDDLE_BOULDER
                         | crate::scene::GoronCitySwitches::LW_RIGHT_BOULDER
                     )
                 ),
-                "Epona" => None, //TODO
-                "Links Cow" => None, //TODO
-                "Odd Mushroom Access" => None, //TODO
-                "Poachers Saw Access" => None, //TODO
-                "Eyedrops Access" => None, //TODO
-                "Broken Sword Access" => None, //TODO
-                "Cojiro Access" => None, //TODO
-                "Wake Up Adult Talon" => None, //TODO
-                "Odd Potion Access" => None, //TODO
-                "Dampes Windmill Access" => None, //TODO
-                "Prescription Access" => None, //TODO
-                "Stop GC Rolling Goron as Adult" => None, //TODO
-                "King Zora Thawed" => None, //TODO
-                "Eyeball Frog Access" => None, //TODO
+                "Epona" => UNIMPLEMENTED, //TODO
+                "Links Cow" => UNIMPLEMENTED, //TODO
+                "Odd Mushroom Access" => UNIMPLEMENTED, //TODO
+                "Poachers Saw Access" => UNIMPLEMENTED, //TODO
+                "Eyedrops Access" => UNIMPLEMENTED, //TODO
+                "Broken Sword Access" => UNIMPLEMENTED, //TODO
+                "Cojiro Access" => UNIMPLEMENTED, //TODO
+                "Wake Up Adult Talon" => UNIMPLEMENTED, //TODO
+                "Odd Potion Access" => UNIMPLEMENTED, //TODO
+                "Dampes Windmill Access" => UNIMPLEMENTED, //TODO
+                "Prescription Access" => UNIMPLEMENTED, //TODO
+                "Stop GC Rolling Goron as Adult" => UNIMPLEMENTED, //TODO
+                "King Zora Thawed" => UNIMPLEMENTED, //TODO
+                "Eyeball Frog Access" => UNIMPLEMENTED, //TODO
 
                 // Forest Temple
-                "Forest Temple Jo and Beth" => Some(
+                "Forest Temple Jo and Beth" => CheckedState::from(
                     model.ram.scene_flags().forest_temple.switches.contains(
                         crate::scene::ForestTempleSwitches::JOELLE_DEFEATED
                         | crate::scene::ForestTempleSwitches::BETH_DEFEATED
                     )
                 ),
-                "Forest Temple Amy and Meg" => None, //TODO
+                "Forest Temple Amy and Meg" => UNIMPLEMENTED, //TODO
 
                 // Water Temple
-                "Child Water Temple" => None, //TODO
-                "Water Temple Clear" => None, //TODO
+                "Child Water Temple" => UNIMPLEMENTED, //TODO
+                "Water Temple Clear" => UNIMPLEMENTED, //TODO
 
-                _ => panic!("unknown event name: {}", event),
+                _ => CheckedState::Unsupported(Cow::Owned(format!("unknown event name: {}", event))),
             },
-            Check::Exit { from, to, .. } => Some(model.knowledge.get_exit(from.as_ref(), to.as_ref()).is_some()),
+            Check::Exit { from, to, .. } => CheckedState::from(model.knowledge.get_exit(from.as_ref(), to.as_ref()).is_some()),
             Check::Location(loc) => match &loc[..] {
-                "LH Child Fishing" => Some(model.ram.save.fishing_context.contains(crate::save::FishingContext::CHILD_PRIZE_OBTAINED)),
-                "LH Adult Fishing" => Some(model.ram.save.fishing_context.contains(crate::save::FishingContext::ADULT_PRIZE_OBTAINED)),
-                "Market Bombchu Bowling Bombchus" => None, // repeatable check
-                "ZR Magic Bean Salesman" => None, //TODO make sure this is handled correctly both with and without bean shuffle
-                "DMT Biggoron" => Some(model.ram.save.dmt_biggoron_checked),
-                "Market 10 Big Poes" => None, //TODO figure out how to read point target count from ROM, or read it from the text box
-                "Wasteland Bombchu Salesman" => None, //TODO make sure this is handled correctly both with and without medi/carp shuffle (and according to knowledge)
-                "GC Medigoron" => None, //TODO make sure this is handled correctly both with and without medi/carp shuffle (and according to knowledge)
-
-                "Pierre" => None, //TODO
-                "Deliver Rutos Letter" => None, //TODO
-                "Master Sword Pedestal" => None, // repeatable check
-
-                "Deku Baba Sticks" => None, // repeatable check
-                "Deku Baba Nuts" => None, // repeatable check
-                "Stick Pot" => None, // repeatable check
-                "Nut Pot" => None, // repeatable check
-                "Nut Crate" => None, // repeatable check
-                "Blue Fire" => None, // repeatable check
-                "Lone Fish" => None, // repeatable check
-                "Fish Group" => None, // repeatable check
-                "Bug Rock" => None, // repeatable check
-                "Bug Shrub" => None, // repeatable check
-                "Wandering Bugs" => None, // repeatable check
-                "Fairy Pot" => None, // repeatable check
-                "Free Fairies" => None, // repeatable check
-                "Wall Fairy" => None, // repeatable check
-                "Butterfly Fairy" => None, // repeatable check
-                "Gossip Stone Fairy" => None, // repeatable check
-                "Bean Plant Fairy" => None, // repeatable check
-                "Fairy Pond" => None, // repeatable check
-                "Big Poe Kill" => None, //TODO mark as checked when enough big Poes are collected (sold + in current bottles)
+                "LH Child Fishing" => CheckedState::from(model.ram.save.fishing_context.contains(crate::save::FishingContext::CHILD_PRIZE_OBTAINED)),
+                "LH Adult Fishing" => CheckedState::from(model.ram.save.fishing_context.contains(crate::save::FishingContext::ADULT_PRIZE_OBTAINED)),
+                "Market Bombchu Bowling Bombchus" => REPEATABLE,
+                "ZR Magic Bean Salesman" => UNIMPLEMENTED, //TODO make sure this is handled correctly both with and without bean shuffle
+                "DMT Biggoron" => CheckedState::from(model.ram.save.dmt_biggoron_checked),
+                "Market 10 Big Poes" => UNIMPLEMENTED, //TODO figure out how to read point target count from ROM, or read it from the text box
+                "Wasteland Bombchu Salesman" => UNIMPLEMENTED, //TODO make sure this is handled correctly both with and without medi/carp shuffle (and according to knowledge)
+                "GC Medigoron" => UNIMPLEMENTED, //TODO make sure this is handled correctly both with and without medi/carp shuffle (and according to knowledge)
+
+                "Pierre" => UNIMPLEMENTED, //TODO
+                "Deliver Rutos Letter" => UNIMPLEMENTED, //TODO
+                "Master Sword Pedestal" => REPEATABLE,
+
+                "Deku Baba Sticks" => REPEATABLE,
+                "Deku Baba Nuts" => REPEATABLE,
+                "Stick Pot" => REPEATABLE,
+                "Nut Pot" => REPEATABLE,
+                "Nut Crate" => REPEATABLE,
+                "Blue Fire" => REPEATABLE,
+                "Lone Fish" => REPEATABLE,
+                "Fish Group" => REPEATABLE,
+                "Bug Rock" => REPEATABLE,
+                "Bug Shrub" => REPEATABLE,
+                "Wandering Bugs" => REPEATABLE,
+                "Fairy Pot" => REPEATABLE,
+                "Free Fairies" => REPEATABLE,
+                "Wall Fairy" => REPEATABLE,
+                "Butterfly Fairy" => REPEATABLE,
+                "Gossip Stone Fairy" => REPEATABLE,
+                "Bean Plant Fairy" => REPEATABLE,
+                "Fairy Pond" => REPEATABLE,
+                "Big Poe Kill" => UNIMPLEMENTED, //TODO mark as checked when enough big Poes are collected (sold + in current bottles)
 
                 // Deku Tree MQ
-                "Deku Tree MQ Map Chest" => None, //TODO
-                "Deku Tree MQ Compass Chest" => None, //TODO
-                "Deku Tree MQ Slingshot Chest" => None, //TODO
-                "Deku Tree MQ Slingshot Room Back Chest" => None, //TODO
-                "Deku Tree MQ Basement Chest" => None, //TODO
-                "Deku Tree MQ Before Spinning Log Chest" => None, //TODO
-                "Deku Tree MQ After Spinning Log Chest" => None, //TODO
+                "Deku Tree MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ Slingshot Chest" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ Slingshot Room Back Chest" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ Basement Chest" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ Before Spinning Log Chest" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ After Spinning Log Chest" => UNIMPLEMENTED, //TODO
 
                 // Dodongo's Cavern MQ
-                "Dodongos Cavern MQ Map Chest" => None, //TODO
-                "Dodongos Cavern MQ Bomb Bag Chest" => None, //TODO
-                "Dodongos Cavern MQ Compass Chest" => None, //TODO
-                "Dodongos Cavern MQ Larvae Room Chest" => None, //TODO
-                "Dodongos Cavern MQ Torch Puzzle Room Chest" => None, //TODO
-                "Dodongos Cavern MQ Under Grave Chest" => None, //TODO
+                "Dodongos Cavern MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Bomb Bag Chest" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Larvae Room Chest" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Torch Puzzle Room Chest" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Under Grave Chest" => UNIMPLEMENTED, //TODO
 
                 // Jabu Jabu's Belly MQ
-                "Jabu Jabus Belly MQ First Room Side Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Map Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Second Room Lower Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Compass Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Second Room Upper Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Basement Near Switches Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Basement Near Vines Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Near Boss Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Falling Like Like Room Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Boomerang Room Small Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Boomerang Chest" => None, //TODO
-                "Jabu Jabus Belly MQ Cow" => None, //TODO
+                "Jabu Jabus Belly MQ First Room Side Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Second Room Lower Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Second Room Upper Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Basement Near Switches Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Basement Near Vines Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Near Boss Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Falling Like Like Room Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Boomerang Room Small Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Boomerang Chest" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ Cow" => UNIMPLEMENTED, //TODO
 
                 // Forest Temple MQ
-                "Forest Temple MQ First Room Chest" => None, //TODO
-                "Forest Temple MQ Wolfos Chest" => None, //TODO
-                "Forest Temple MQ Bow Chest" => None, //TODO
-                "Forest Temple MQ Raised Island Courtyard Lower Chest" => None, //TODO
-                "Forest Temple MQ Raised Island Courtyard Upper Chest" => None, //TODO
-                "Forest Temple MQ Well Chest" => None, //TODO
-                "Forest Temple MQ Map Chest" => None, //TODO
-                "Forest Temple MQ Compass Chest" => None, //TODO
-                "Forest Temple MQ Falling Ceiling Room Chest" => None, //TODO
-                "Forest Temple MQ Basement Chest" => None, //TODO
-                "Forest Temple MQ Redead Chest" => None, //TODO
-                "Forest Temple MQ Boss Key Chest" => None, //TODO
+                "Forest Temple MQ First Room Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Wolfos Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Bow Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Raised Island Courtyard Lower Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Raised Island Courtyard Upper Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Well Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Falling Ceiling Room Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Basement Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Redead Chest" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ Boss Key Chest" => UNIMPLEMENTED, //TODO
 
                 // Fire Temple MQ
-                "Fire Temple MQ Near Boss Chest" => None, //TODO
-                "Fire Temple MQ Megaton Hammer Chest" => None, //TODO
-                "Fire Temple MQ Compass Chest" => None, //TODO
-                "Fire Temple MQ Lizalfos Maze Lower Chest" => None, //TODO
-                "Fire Temple MQ Lizalfos Maze Upper Chest" => None, //TODO
-                "Fire Temple MQ Chest On Fire" => None, //TODO
-                "Fire Temple MQ Map Room Side Chest" => None, //TODO
-                "Fire Temple MQ Map Chest" => None, //TODO
-                "Fire Temple MQ Boss Key Chest" => None, //TODO
-                "Fire Temple MQ Big Lava Room Blocked Door Chest" => None, //TODO
-                "Fire Temple MQ Lizalfos Maze Side Room Chest" => None, //TODO
-                "Fire Temple MQ Freestanding Key" => None, //TODO
+                "Fire Temple MQ Near Boss Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Megaton Hammer Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Lizalfos Maze Lower Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Lizalfos Maze Upper Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Chest On Fire" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Map Room Side Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Boss Key Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Big Lava Room Blocked Door Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Lizalfos Maze Side Room Chest" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ Freestanding Key" => UNIMPLEMENTED, //TODO
 
                 // Water Temple MQ
-                "Water Temple MQ Central Pillar Chest" => None, //TODO
-                "Water Temple MQ Boss Key Chest" => None, //TODO
-                "Water Temple MQ Longshot Chest" => None, //TODO
-                "Water Temple MQ Compass Chest" => None, //TODO
-                "Water Temple MQ Map Chest" => None, //TODO
-                "Water Temple MQ Freestanding Key" => None, //TODO
+                "Water Temple MQ Central Pillar Chest" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ Boss Key Chest" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ Longshot Chest" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ Freestanding Key" => UNIMPLEMENTED, //TODO
 
                 // Spirit Temple MQ
-                "Spirit Temple MQ Entrance Front Left Chest" => None, //TODO
-                "Spirit Temple MQ Entrance Back Right Chest" => None, //TODO
-                "Spirit Temple MQ Entrance Front Right Chest" => None, //TODO
-                "Spirit Temple MQ Entrance Back Left Chest" => None, //TODO
-                "Spirit Temple MQ Child Hammer Switch Chest" => None, //TODO
-                "Spirit Temple MQ Map Chest" => None, //TODO
-                "Spirit Temple MQ Map Room Enemy Chest" => None, //TODO
-                "Spirit Temple MQ Child Climb North Chest" => None, //TODO
-                "Spirit Temple MQ Child Climb South Chest" => None, //TODO
-                "Spirit Temple MQ Compass Chest" => None, //TODO
-                "Spirit Temple MQ Statue Room Lullaby Chest" => None, //TODO
-                "Spirit Temple MQ Statue Room Invisible Chest" => None, //TODO
-                "Spirit Temple MQ Silver Block Hallway Chest" => None, //TODO
-                "Spirit Temple MQ Sun Block Room Chest" => None, //TODO
-                "Spirit Temple MQ Symphony Room Chest" => None, //TODO
-                "Spirit Temple MQ Leever Room Chest" => None, //TODO
-                "Spirit Temple MQ Beamos Room Chest" => None, //TODO
-                "Spirit Temple MQ Chest Switch Chest" => None, //TODO
-                "Spirit Temple MQ Boss Key Chest" => None, //TODO
-                "Spirit Temple MQ Mirror Puzzle Invisible Chest" => None, //TODO
+                "Spirit Temple MQ Entrance Front Left Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Entrance Back Right Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Entrance Front Right Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Entrance Back Left Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Child Hammer Switch Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Map Room Enemy Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Child Climb North Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Child Climb South Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Statue Room Lullaby Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Statue Room Invisible Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Silver Block Hallway Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Sun Block Room Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Symphony Room Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Leever Room Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Beamos Room Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Chest Switch Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Boss Key Chest" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ Mirror Puzzle Invisible Chest" => UNIMPLEMENTED, //TODO
 
                 // Shadow Temple MQ
-                "Shadow Temple MQ Compass Chest" => None, //TODO
-                "Shadow Temple MQ Hover Boots Chest" => None, //TODO
-                "Shadow Temple MQ Early Gibdos Chest" => None, //TODO
-                "Shadow Temple MQ Map Chest" => None, //TODO
-                "Shadow Temple MQ Beamos Silver Rupees Chest" => None, //TODO
-                "Shadow Temple MQ Falling Spikes Switch Chest" => None, //TODO
-                "Shadow Temple MQ Falling Spikes Lower Chest" => None, //TODO
-                "Shadow Temple MQ Falling Spikes Upper Chest" => None, //TODO
-                "Shadow Temple MQ Invisible Spikes Chest" => None, //TODO
-                "Shadow Temple MQ Boss Key Chest" => None, //TODO
-                "Shadow Temple MQ Spike Walls Left Chest" => None, //TODO
-                "Shadow Temple MQ Stalfos Room Chest" => None, //TODO
-                "Shadow Temple MQ Invisible Blades Invisible Chest" => None, //TODO
-                "Shadow Temple MQ Invisible Blades Visible Chest" => None, //TODO
-                "Shadow Temple MQ Bomb Flower Chest" => None, //TODO
-                "Shadow Temple MQ Wind Hint Chest" => None, //TODO
-                "Shadow Temple MQ After Wind Hidden Chest" => None, //TODO
-                "Shadow Temple MQ After Wind Enemy Chest" => None, //TODO
-                "Shadow Temple MQ Near Ship Invisible Chest" => None, //TODO
-                "Shadow Temple MQ Freestanding Key" => None, //TODO
+                "Shadow Temple MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Hover Boots Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Early Gibdos Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Beamos Silver Rupees Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Falling Spikes Switch Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Falling Spikes Lower Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Falling Spikes Upper Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Invisible Spikes Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Boss Key Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Spike Walls Left Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Stalfos Room Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Invisible Blades Invisible Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Invisible Blades Visible Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Bomb Flower Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Wind Hint Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ After Wind Hidden Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ After Wind Enemy Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Near Ship Invisible Chest" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ Freestanding Key" => UNIMPLEMENTED, //TODO
 
                 // Bottom of the Well MQ
-                "Bottom of the Well MQ Map Chest" => None, //TODO
-                "Bottom of the Well MQ Lens of Truth Chest" => None, //TODO
-                "Bottom of the Well MQ Compass Chest" => None, //TODO
-                "Bottom of the Well MQ Dead Hand Freestanding Key" => None, //TODO
-                "Bottom of the Well MQ East Inner Room Freestanding Key" => None, //TODO
+                "Bottom of the Well MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well MQ Lens of Truth Chest" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well MQ Dead Hand Freestanding Key" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well MQ East Inner Room Freestanding Key" => UNIMPLEMENTED, //TODO
 
                 // Ice Cavern MQ
-                "Ice Cavern MQ Iron Boots Chest" => None, //TODO
-                "Ice Cavern MQ Compass Chest" => None, //TODO
-                "Ice Cavern MQ Map Chest" => None, //TODO
-                "Ice Cavern MQ Freestanding PoH" => None, //TODO
+                "Ice Cavern MQ Iron Boots Chest" => UNIMPLEMENTED, //TODO
+                "Ice Cavern MQ Compass Chest" => UNIMPLEMENTED, //TODO
+                "Ice Cavern MQ Map Chest" => UNIMPLEMENTED, //TODO
+                "Ice Cavern MQ Freestanding PoH" => UNIMPLEMENTED, //TODO
 
                 // Gerudo Training Ground MQ
-                "Gerudo Training Ground MQ Lobby Right Chest" => None, //TODO
-                "Gerudo Training Ground MQ Lobby Left Chest" => None, //TODO
-                "Gerudo Training Ground MQ First Iron Knuckle Chest" => None, //TODO
-                "Gerudo Training Ground MQ Before Heavy Block Chest" => None, //TODO
-                "Gerudo Training Ground MQ Eye Statue Chest" => None, //TODO
-                "Gerudo Training Ground MQ Flame Circle Chest" => None, //TODO
-                "Gerudo Training Ground MQ Second Iron Knuckle Chest" => None, //TODO
-                "Gerudo Training Ground MQ Dinolfos Chest" => None, //TODO
-                "Gerudo Training Ground MQ Ice Arrows Chest" => None, //TODO
-                "Gerudo Training Ground MQ Maze Right Central Chest" => None, //TODO
-                "Gerudo Training Ground MQ Maze Path First Chest" => None, //TODO
-                "Gerudo Training Ground MQ Maze Right Side Chest" => None, //TODO
-                "Gerudo Training Ground MQ Maze Path Third Chest" => None, //TODO
-                "Gerudo Training Ground MQ Maze Path Second Chest" => None, //TODO
-                "Gerudo Training Ground MQ Hidden Ceiling Chest" => None, //TODO
-                "Gerudo Training Ground MQ Underwater Silver Rupee Chest" => None, //TODO
-                "Gerudo Training Ground MQ Heavy Block Chest" => None, //TODO
+                "Gerudo Training Ground MQ Lobby Right Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Lobby Left Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ First Iron Knuckle Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Before Heavy Block Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Eye Statue Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Flame Circle Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Second Iron Knuckle Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Dinolfos Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Ice Arrows Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Maze Right Central Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Maze Path First Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Maze Right Side Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Maze Path Third Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Maze Path Second Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Hidden Ceiling Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Underwater Silver Rupee Chest" => UNIMPLEMENTED, //TODO
+                "Gerudo Training Ground MQ Heavy Block Chest" => UNIMPLEMENTED, //TODO
 
                 // Ganon's Castle MQ
-                "Ganons Castle MQ Water Trial Chest" => None, //TODO
-                "Ganons Castle MQ Forest Trial Eye Switch Chest" => None, //TODO
-                "Ganons Castle MQ Forest Trial Frozen Eye Switch Chest" => None, //TODO
-                "Ganons Castle MQ Light Trial Lullaby Chest" => None, //TODO
-                "Ganons Castle MQ Shadow Trial Bomb Flower Chest" => None, //TODO
-                "Ganons Castle MQ Shadow Trial Eye Switch Chest" => None, //TODO
-                "Ganons Castle MQ Spirit Trial Golden Gauntlets Chest" => None, //TODO
-                "Ganons Castle MQ Spirit Trial Sun Back Right Chest" => None, //TODO
-                "Ganons Castle MQ Spirit Trial Sun Back Left Chest" => None, //TODO
-                "Ganons Castle MQ Spirit Trial Sun Front Left Chest" => None, //TODO
-                "Ganons Castle MQ Spirit Trial First Chest" => None, //TODO
-                "Ganons Castle MQ Spirit Trial Invisible Chest" => None, //TODO
-                "Ganons Castle MQ Forest Trial Freestanding Key" => None, //TODO
-
-                "Links Pocket" => Some(true), //TODO check if vanilla or rando, if vanilla check for appropriate flag
-                "Queen Gohma" => None, //TODO
-                "Twinrova" => None, //TODO
-                "Bongo Bongo" => None, //TODO
-                "Ganon" => Some(false), //TODO remember if game has been beaten (relevant for multiworld and go mode)
-
-                "Deku Tree Queen Gohma Heart" => None, //TODO
-                "Dodongos Cavern King Dodongo Heart" => None, //TODO
-                "Jabu Jabus Belly Barinade Heart" => None, //TODO
-                "Forest Temple Phantom Ganon Heart" => None, //TODO
-                "Fire Temple Volvagia Heart" => None, //TODO
-                "Water Temple Morpha Heart" => None, //TODO
-                "Spirit Temple Twinrova Heart" => None, //TODO
-                "Shadow Temple Bongo Bongo Heart" => None, //TODO
+                "Ganons Castle MQ Water Trial Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Forest Trial Eye Switch Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Forest Trial Frozen Eye Switch Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Light Trial Lullaby Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Shadow Trial Bomb Flower Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Shadow Trial Eye Switch Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Spirit Trial Golden Gauntlets Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Spirit Trial Sun Back Right Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Spirit Trial Sun Back Left Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Spirit Trial Sun Front Left Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Spirit Trial First Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Spirit Trial Invisible Chest" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Forest Trial Freestanding Key" => UNIMPLEMENTED, //TODO
+
+                "Links Pocket" => CheckedState::from(true), //TODO check if vanilla or rando, if vanilla check for appropriate flag
+                "Queen Gohma" => UNIMPLEMENTED, //TODO
+                "Twinrova" => UNIMPLEMENTED, //TODO
+                "Bongo Bongo" => UNIMPLEMENTED, //TODO
+                "Ganon" => CheckedState::from(model.knowledge.progression_mode == crate::knowledge::ProgressionMode::Done),
+
+                "Deku Tree Queen Gohma Heart" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern King Dodongo Heart" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly Barinade Heart" => UNIMPLEMENTED, //TODO
+                "Forest Temple Phantom Ganon Heart" => UNIMPLEMENTED, //TODO
+                "Fire Temple Volvagia Heart" => UNIMPLEMENTED, //TODO
+                "Water Temple Morpha Heart" => UNIMPLEMENTED, //TODO
+                "Spirit Temple Twinrova Heart" => UNIMPLEMENTED, //TODO
+                "Shadow Temple Bongo Bongo Heart" => UNIMPLEMENTED, //TODO
 
                 // Dungeon Skulls
-                "Deku Tree GS Basement Back Room" => None, //TODO
-                "Deku Tree GS Basement Gate" => None, //TODO
-                "Deku Tree GS Basement Vines" => None, //TODO
-                "Deku Tree GS Compass Room" => None, //TODO
-
-                "Deku Tree MQ GS Lobby" => None, //TODO
-                "Deku Tree MQ GS Compass Room" => None, //TODO
-                "Deku Tree MQ GS Basement Graves Room" => None, //TODO
-                "Deku Tree MQ GS Basement Back Room" => None, //TODO
-
-                "Dodongos Cavern GS Vines Above Stairs" => None, //TODO
-                "Dodongos Cavern GS Scarecrow" => None, //TODO
-                "Dodongos Cavern GS Alcove Above Stairs" => None, //TODO
-                "Dodongos Cavern GS Back Room" => None, //TODO
-                "Dodongos Cavern GS Side Room Near Lower Lizalfos" => None, //TODO
-
-                "Dodongos Cavern MQ GS Scrub Room" => None, //TODO
-                "Dodongos Cavern MQ GS Song of Time Block Room" => None, //TODO
-                "Dodongos Cavern MQ GS Lizalfos Room" => None, //TODO
-                "Dodongos Cavern MQ GS Larvae Room" => None, //TODO
-                "Dodongos Cavern MQ GS Back Area" => None, //TODO
-
-                "Jabu Jabus Belly GS Lobby Basement Lower" => None, //TODO
-                "Jabu Jabus Belly GS Lobby Basement Upper" => None, //TODO
-                "Jabu Jabus Belly GS Near Boss" => None, //TODO
-                "Jabu Jabus Belly GS Water Switch Room" => None, //TODO
-
-                "Jabu Jabus Belly MQ GS Tailpasaran Room" => None, //TODO
-                "Jabu Jabus Belly MQ GS Invisible Enemies Room" => None, //TODO
-                "Jabu Jabus Belly MQ GS Boomerang Chest Room" => None, //TODO
-                "Jabu Jabus Belly MQ GS Near Boss" => None, //TODO
-
-                "Forest Temple GS Raised Island Courtyard" => None, //TODO
-                "Forest Temple GS First Room" => None, //TODO
-                "Forest Temple GS Lobby" => None, //TODO
-                "Forest Temple GS Basement" => None, //TODO
-
-                "Forest Temple MQ GS First Hallway" => None, //TODO
-                "Forest Temple MQ GS Block Push Room" => None, //TODO
-                "Forest Temple MQ GS Raised Island Courtyard" => None, //TODO
-                "Forest Temple MQ GS Level Island Courtyard" => None, //TODO
-                "Forest Temple MQ GS Well" => None, //TODO
-
-                "Fire Temple GS Song of Time Room" => None, //TODO
-                "Fire Temple GS Boss Key Loop" => None, //TODO
-                "Fire Temple GS Boulder Maze" => None, //TODO
-                "Fire Temple GS Scarecrow Top" => None, //TODO
-                "Fire Temple GS Scarecrow Climb" => None, //TODO
-
-                "Fire Temple MQ GS Above Fire Wall Maze" => None, //TODO
-                "Fire Temple MQ GS Fire Wall Maze Center" => None, //TODO
-                "Fire Temple MQ GS Big Lava Room Open Door" => None, //TODO
-                "Fire Temple MQ GS Fire Wall Maze Side Room" => None, //TODO
-                "Fire Temple MQ GS Skull On Fire" => None, //TODO
-
-                "Water Temple GS Behind Gate" => None, //TODO
-                "Water Temple GS Falling Platform Room" => None, //TODO
-                "Water Temple GS Central Pillar" => None, //TODO
-                "Water Temple GS Near Boss Key Chest" => None, //TODO
-                "Water Temple GS River" => None, //TODO
-
-                "Water Temple MQ GS Before Upper Water Switch" => None, //TODO
-                "Water Temple MQ GS Freestanding Key Area" => None, //TODO
-                "Water Temple MQ GS Lizalfos Hallway" => None, //TODO
-                "Water Temple MQ GS River" => None, //TODO
-                "Water Temple MQ GS Triple Wall Torch" => None, //TODO
-
-                "Spirit Temple GS Hall After Sun Block Room" => None, //TODO
-                "Spirit Temple GS Boulder Room" => None, //TODO
-                "Spirit Temple GS Lobby" => None, //TODO
-                "Spirit Temple GS Sun on Floor Room" => None, //TODO
-                "Spirit Temple GS Metal Fence" => None, //TODO
-
-                "Spirit Temple MQ GS Symphony Room" => None, //TODO
-                "Spirit Temple MQ GS Leever Room" => None, //TODO
-                "Spirit Temple MQ GS Nine Thrones Room West" => None, //TODO
-                "Spirit Temple MQ GS Nine Thrones Room North" => None, //TODO
-                "Spirit Temple MQ GS Sun Block Room" => None, //TODO
-
-                "Shadow Temple GS Single Giant Pot" => None, //TODO
-                "Shadow Temple GS Falling Spikes Room" => None, //TODO
-                "Shadow Temple GS Triple Giant Pot" => None, //TODO
-                "Shadow Temple GS Like Like Room" => None, //TODO
-                "Shadow Temple GS Near Ship" => None, //TODO
-
-                "Shadow Temple MQ GS Falling Spikes Room" => None, //TODO
-                "Shadow Temple MQ GS Wind Hint Room" => None, //TODO
-                "Shadow Temple MQ GS After Wind" => None, //TODO
-                "Shadow Temple MQ GS After Ship" => None, //TODO
-                "Shadow Temple MQ GS Near Boss" => None, //TODO
+                "Deku Tree GS Basement Back Room" => UNIMPLEMENTED, //TODO
+                "Deku Tree GS Basement Gate" => UNIMPLEMENTED, //TODO
+                "Deku Tree GS Basement Vines" => UNIMPLEMENTED, //TODO
+                "Deku Tree GS Compass Room" => UNIMPLEMENTED, //TODO
+
+                "Deku Tree MQ GS Lobby" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ GS Compass Room" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ GS Basement Graves Room" => UNIMPLEMENTED, //TODO
+                "Deku Tree MQ GS Basement Back Room" => UNIMPLEMENTED, //TODO
+
+                "Dodongos Cavern GS Vines Above Stairs" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern GS Scarecrow" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern GS Alcove Above Stairs" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern GS Back Room" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern GS Side Room Near Lower Lizalfos" => UNIMPLEMENTED, //TODO
+
+                "Dodongos Cavern MQ GS Scrub Room" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ GS Song of Time Block Room" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ GS Lizalfos Room" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ GS Larvae Room" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ GS Back Area" => UNIMPLEMENTED, //TODO
+
+                "Jabu Jabus Belly GS Lobby Basement Lower" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly GS Lobby Basement Upper" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly GS Near Boss" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly GS Water Switch Room" => UNIMPLEMENTED, //TODO
+
+                "Jabu Jabus Belly MQ GS Tailpasaran Room" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ GS Invisible Enemies Room" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ GS Boomerang Chest Room" => UNIMPLEMENTED, //TODO
+                "Jabu Jabus Belly MQ GS Near Boss" => UNIMPLEMENTED, //TODO
+
+                "Forest Temple GS Raised Island Courtyard" => UNIMPLEMENTED, //TODO
+                "Forest Temple GS First Room" => UNIMPLEMENTED, //TODO
+                "Forest Temple GS Lobby" => UNIMPLEMENTED, //TODO
+                "Forest Temple GS Basement" => UNIMPLEMENTED, //TODO
+
+                "Forest Temple MQ GS First Hallway" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ GS Block Push Room" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ GS Raised Island Courtyard" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ GS Level Island Courtyard" => UNIMPLEMENTED, //TODO
+                "Forest Temple MQ GS Well" => UNIMPLEMENTED, //TODO
+
+                "Fire Temple GS Song of Time Room" => UNIMPLEMENTED, //TODO
+                "Fire Temple GS Boss Key Loop" => UNIMPLEMENTED, //TODO
+                "Fire Temple GS Boulder Maze" => UNIMPLEMENTED, //TODO
+                "Fire Temple GS Scarecrow Top" => UNIMPLEMENTED, //TODO
+                "Fire Temple GS Scarecrow Climb" => UNIMPLEMENTED, //TODO
+
+                "Fire Temple MQ GS Above Fire Wall Maze" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ GS Fire Wall Maze Center" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ GS Big Lava Room Open Door" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ GS Fire Wall Maze Side Room" => UNIMPLEMENTED, //TODO
+                "Fire Temple MQ GS Skull On Fire" => UNIMPLEMENTED, //TODO
+
+                "Water Temple GS Behind Gate" => UNIMPLEMENTED, //TODO
+                "Water Temple GS Falling Platform Room" => UNIMPLEMENTED, //TODO
+                "Water Temple GS Central Pillar" => UNIMPLEMENTED, //TODO
+                "Water Temple GS Near Boss Key Chest" => UNIMPLEMENTED, //TODO
+                "Water Temple GS River" => UNIMPLEMENTED, //TODO
+
+                "Water Temple MQ GS Before Upper Water Switch" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ GS Freestanding Key Area" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ GS Lizalfos Hallway" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ GS River" => UNIMPLEMENTED, //TODO
+                "Water Temple MQ GS Triple Wall Torch" => UNIMPLEMENTED, //TODO
+
+                "Spirit Temple GS Hall After Sun Block Room" => UNIMPLEMENTED, //TODO
+                "Spirit Temple GS Boulder Room" => UNIMPLEMENTED, //TODO
+                "Spirit Temple GS Lobby" => UNIMPLEMENTED, //TODO
+                "Spirit Temple GS Sun on Floor Room" => UNIMPLEMENTED, //TODO
+                "Spirit Temple GS Metal Fence" => UNIMPLEMENTED, //TODO
+
+                "Spirit Temple MQ GS Symphony Room" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ GS Leever Room" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ GS Nine Thrones Room West" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ GS Nine Thrones Room North" => UNIMPLEMENTED, //TODO
+                "Spirit Temple MQ GS Sun Block Room" => UNIMPLEMENTED, //TODO
+
+                "Shadow Temple GS Single Giant Pot" => UNIMPLEMENTED, //TODO
+                "Shadow Temple GS Falling Spikes Room" => UNIMPLEMENTED, //TODO
+                "Shadow Temple GS Triple Giant Pot" => UNIMPLEMENTED, //TODO
+                "Shadow Temple GS Like Like Room" => UNIMPLEMENTED, //TODO
+                "Shadow Temple GS Near Ship" => UNIMPLEMENTED, //TODO
+
+                "Shadow Temple MQ GS Falling Spikes Room" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ GS Wind Hint Room" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ GS After Wind" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ GS After Ship" => UNIMPLEMENTED, //TODO
+                "Shadow Temple MQ GS Near Boss" => UNIMPLEMENTED, //TODO
 
                 // Mini Dungeon Skulls
-                "Bottom of the Well GS Like Like Cage" => None, //TODO
-                "Bottom of the Well GS East Inner Room" => None, //TODO
-                "Bottom of the Well GS West Inner Room" => None, //TODO
+                "Bottom of the Well GS Like Like Cage" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well GS East Inner Room" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well GS West Inner Room" => UNIMPLEMENTED, //TODO
 
-                "Bottom of the Well MQ GS Basement" => None, //TODO
-                "Bottom of the Well MQ GS Coffin Room" => None, //TODO
-                "Bottom of the Well MQ GS West Inner Room" => None, //TODO
+                "Bottom of the Well MQ GS Basement" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well MQ GS Coffin Room" => UNIMPLEMENTED, //TODO
+                "Bottom of the Well MQ GS West Inner Room" => UNIMPLEMENTED, //TODO
 
-                "Ice Cavern GS Push Block Room" => None, //TODO
-                "Ice Cavern GS Spinning Scythe Room" => None, //TODO
-                "Ice Cavern GS Heart Piece Room" => None, //TODO
+                "Ice Cavern GS Push Block Room" => UNIMPLEMENTED, //TODO
+                "Ice Cavern GS Spinning Scythe Room" => UNIMPLEMENTED, //TODO
+                "Ice Cavern GS Heart Piece Room" => UNIMPLEMENTED, //TODO
 
-                "Ice Cavern MQ GS Scarecrow" => None, //TODO
-                "Ice Cavern MQ GS Ice Block" => None, //TODO
-                "Ice Cavern MQ GS Red Ice" => None, //TODO
+                "Ice Cavern MQ GS Scarecrow" => UNIMPLEMENTED, //TODO
+                "Ice Cavern MQ GS Ice Block" => UNIMPLEMENTED, //TODO
+                "Ice Cavern MQ GS Red Ice" => UNIMPLEMENTED, //TODO
 
                 // Overworld Skulls
-                "HF GS Cow Grotto" => None, //TODO
-                "HF GS Near Kak Grotto" => None, //TODO
-
-                "LLR GS Back Wall" => None, //TODO
-                "LLR GS Rain Shed" => None, //TODO
-                "LLR GS House Window" => None, //TODO
-                "LLR GS Tree" => None, //TODO
-
-                "KF GS Bean Patch" => None, //TODO
-                "KF GS Know It All House" => None, //TODO
-                "KF GS House of Twins" => None, //TODO
-
-                "LW GS Bean Patch Near Bridge" => None, //TODO
-                "LW GS Bean Patch Near Theater" => None, //TODO
-                "LW GS Above Theater" => None, //TODO
-                "SFM GS" => None, //TODO
-
-                "OGC GS" => None, //TODO
-                "HC GS Storms Grotto" => None, //TODO
-                "HC GS Tree" => None, //TODO
-                "Market GS Guard House" => None, //TODO
-
-                "DMC GS Bean Patch" => None, //TODO
-                "DMC GS Crate" => None, //TODO
-
-                "DMT GS Bean Patch" => None, //TODO
-                "DMT GS Near Kak" => None, //TODO
-                "DMT GS Above Dodongos Cavern" => None, //TODO
-                "DMT GS Falling Rocks Path" => None, //TODO
-
-                "GC GS Center Platform" => None, //TODO
-                "GC GS Boulder Maze" => None, //TODO
-
-                "Kak GS House Under Construction" => None, //TODO
-                "Kak GS Skulltula House" => None, //TODO
-                "Kak GS Guards House" => None, //TODO
-                "Kak GS Tree" => None, //TODO
-                "Kak GS Watchtower" => None, //TODO
-                "Kak GS Above Impas House" => None, //TODO
-
-                "Graveyard GS Wall" => None, //TODO
-                "Graveyard GS Bean Patch" => None, //TODO
-
-                "ZR GS Ladder" => None, //TODO
-                "ZR GS Tree" => None, //TODO
-                "ZR GS Above Bridge" => None, //TODO
-                "ZR GS Near Raised Grottos" => None, //TODO
-
-                "ZD GS Frozen Waterfall" => None, //TODO
-                "ZF GS Above the Log" => None, //TODO
-                "ZF GS Hidden Cave" => None, //TODO
-                "ZF GS Tree" => None, //TODO
-
-                "LH GS Bean Patch" => None, //TODO
-                "LH GS Small Island" => None, //TODO
-                "LH GS Lab Wall" => None, //TODO
-                "LH GS Lab Crate" => None, //TODO
-                "LH GS Tree" => None, //TODO
-
-                "GV GS Bean Patch" => None, //TODO
-                "GV GS Small Bridge" => None, //TODO
-                "GV GS Pillar" => None, //TODO
-                "GV GS Behind Tent" => None, //TODO
-
-                "GF GS Archery Range" => None, //TODO
-                "GF GS Top Floor" => None, //TODO
-
-                "Wasteland GS" => None, //TODO
-                "Colossus GS Bean Patch" => None, //TODO
-                "Colossus GS Hill" => None, //TODO
-                "Colossus GS Tree" => None, //TODO
+                "HF GS Cow Grotto" => UNIMPLEMENTED, //TODO
+                "HF GS Near Kak Grotto" => UNIMPLEMENTED, //TODO
+
+                "LLR GS Back Wall" => UNIMPLEMENTED, //TODO
+                "LLR GS Rain Shed" => UNIMPLEMENTED, //TODO
+                "LLR GS House Window" => UNIMPLEMENTED, //TODO
+                "LLR GS Tree" => UNIMPLEMENTED, //TODO
+
+                "KF GS Bean Patch" => UNIMPLEMENTED, //TODO
+                "KF GS Know It All House" => UNIMPLEMENTED, //TODO
+                "KF GS House of Twins" => UNIMPLEMENTED, //TODO
+
+                "LW GS Bean Patch Near Bridge" => UNIMPLEMENTED, //TODO
+                "LW GS Bean Patch Near Theater" => UNIMPLEMENTED, //TODO
+                "LW GS Above Theater" => UNIMPLEMENTED, //TODO
+                "SFM GS" => UNIMPLEMENTED, //TODO
+
+                "OGC GS" => UNIMPLEMENTED, //TODO
+                "HC GS Storms Grotto" => UNIMPLEMENTED, //TODO
+                "HC GS Tree" => UNIMPLEMENTED, //TODO
+                "Market GS Guard House" => UNIMPLEMENTED, //TODO
+
+                "DMC GS Bean Patch" => UNIMPLEMENTED, //TODO
+                "DMC GS Crate" => UNIMPLEMENTED, //TODO
+
+                "DMT GS Bean Patch" => UNIMPLEMENTED, //TODO
+                "DMT GS Near Kak" => UNIMPLEMENTED, //TODO
+                "DMT GS Above Dodongos Cavern" => UNIMPLEMENTED, //TODO
+                "DMT GS Falling Rocks Path" => UNIMPLEMENTED, //TODO
+
+                "GC GS Center Platform" => UNIMPLEMENTED, //TODO
+                "GC GS Boulder Maze" => UNIMPLEMENTED, //TODO
+
+                "Kak GS House Under Construction" => UNIMPLEMENTED, //TODO
+                "Kak GS Skulltula House" => UNIMPLEMENTED, //TODO
+                "Kak GS Guards House" => UNIMPLEMENTED, //TODO
+                "Kak GS Tree" => UNIMPLEMENTED, //TODO
+                "Kak GS Watchtower" => UNIMPLEMENTED, //TODO
+                "Kak GS Above Impas House" => UNIMPLEMENTED, //TODO
+
+                "Graveyard GS Wall" => UNIMPLEMENTED, //TODO
+                "Graveyard GS Bean Patch" => UNIMPLEMENTED, //TODO
+
+                "ZR GS Ladder" => UNIMPLEMENTED, //TODO
+                "ZR GS Tree" => UNIMPLEMENTED, //TODO
+                "ZR GS Above Bridge" => UNIMPLEMENTED, //TODO
+                "ZR GS Near Raised Grottos" => UNIMPLEMENTED, //TODO
+
+                "ZD GS Frozen Waterfall" => UNIMPLEMENTED, //TODO
+                "ZF GS Above the Log" => UNIMPLEMENTED, //TODO
+                "ZF GS Hidden Cave" => UNIMPLEMENTED, //TODO
+                "ZF GS Tree" => UNIMPLEMENTED, //TODO
+
+                "LH GS Bean Patch" => UNIMPLEMENTED, //TODO
+                "LH GS Small Island" => UNIMPLEMENTED, //TODO
+                "LH GS Lab Wall" => UNIMPLEMENTED, //TODO
+                "LH GS Lab Crate" => UNIMPLEMENTED, //TODO
+                "LH GS Tree" => UNIMPLEMENTED, //TODO
+
+                "GV GS Bean Patch" => UNIMPLEMENTED, //TODO
+                "GV GS Small Bridge" => UNIMPLEMENTED, //TODO
+                "GV GS Pillar" => UNIMPLEMENTED, //TODO
+                "GV GS Behind Tent" => UNIMPLEMENTED, //TODO
+
+                "GF GS Archery Range" => UNIMPLEMENTED, //TODO
+                "GF GS Top Floor" => UNIMPLEMENTED, //TODO
+
+                "Wasteland GS" => UNIMPLEMENTED, //TODO
+                "Colossus GS Bean Patch" => UNIMPLEMENTED, //TODO
+                "Colossus GS Hill" => UNIMPLEMENTED, //TODO
+                "Colossus GS Tree" => UNIMPLEMENTED, //TODO
 
                 // Shops
-                "KF Shop Item 1" => None, //TODO
-                "KF Shop Item 2" => None, //TODO
-                "KF Shop Item 3" => None, //TODO
-                "KF Shop Item 4" => None, //TODO
-                "KF Shop Item 5" => None, //TODO
-                "KF Shop Item 6" => None, //TODO
-                "KF Shop Item 7" => None, //TODO
-                "KF Shop Item 8" => None, //TODO
-
-                "Kak Potion Shop Item 1" => None, //TODO
-                "Kak Potion Shop Item 2" => None, //TODO
-                "Kak Potion Shop Item 3" => None, //TODO
-                "Kak Potion Shop Item 4" => None, //TODO
-                "Kak Potion Shop Item 5" => None, //TODO
-                "Kak Potion Shop Item 6" => None, //TODO
-                "Kak Potion Shop Item 7" => None, //TODO
-                "Kak Potion Shop Item 8" => None, //TODO
-
-                "Market Bombchu Shop Item 1" => None, //TODO
-                "Market Bombchu Shop Item 2" => None, //TODO
-                "Market Bombchu Shop Item 3" => None, //TODO
-                "Market Bombchu Shop Item 4" => None, //TODO
-                "Market Bombchu Shop Item 5" => None, //TODO
-                "Market Bombchu Shop Item 6" => None, //TODO
-                "Market Bombchu Shop Item 7" => None, //TODO
-                "Market Bombchu Shop Item 8" => None, //TODO
-
-                "Market Potion Shop Item 1" => None, //TODO
-                "Market Potion Shop Item 2" => None, //TODO
-                "Market Potion Shop Item 3" => None, //TODO
-                "Market Potion Shop Item 4" => None, //TODO
-                "Market Potion Shop Item 5" => None, //TODO
-                "Market Potion Shop Item 6" => None, //TODO
-                "Market Potion Shop Item 7" => None, //TODO
-                "Market Potion Shop Item 8" => None, //TODO
-
-                "Market Bazaar Item 1" => None, //TODO
-                "Market Bazaar Item 2" => None, //TODO
-                "Market Bazaar Item 3" => None, //TODO
-                "Market Bazaar Item 4" => None, //TODO
-                "Market Bazaar Item 5" => None, //TODO
-                "Market Bazaar Item 6" => None, //TODO
-                "Market Bazaar Item 7" => None, //TODO
-                "Market Bazaar Item 8" => None, //TODO
-
-                "Kak Bazaar Item 1" => None, //TODO
-                "Kak Bazaar Item 2" => None, //TODO
-                "Kak Bazaar Item 3" => None, //TODO
-                "Kak Bazaar Item 4" => None, //TODO
-                "Kak Bazaar Item 5" => None, //TODO
-                "Kak Bazaar Item 6" => None, //TODO
-                "Kak Bazaar Item 7" => None, //TODO
-                "Kak Bazaar Item 8" => None, //TODO
-
-                "ZD Shop Item 1" => None, //TODO
-                "ZD Shop Item 2" => None, //TODO
-                "ZD Shop Item 3" => None, //TODO
-                "ZD Shop Item 4" => None, //TODO
-                "ZD Shop Item 5" => None, //TODO
-                "ZD Shop Item 6" => None, //TODO
-                "ZD Shop Item 7" => None, //TODO
-                "ZD Shop Item 8" => None, //TODO
-
-                "GC Shop Item 1" => None, //TODO
-                "GC Shop Item 2" => None, //TODO
-                "GC Shop Item 3" => None, //TODO
-                "GC Shop Item 4" => None, //TODO
-                "GC Shop Item 5" => None, //TODO
-                "GC Shop Item 6" => None, //TODO
-                "GC Shop Item 7" => None, //TODO
-                "GC Shop Item 8" => None, //TODO
+                "KF Shop Item 1" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 2" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 3" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 4" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 5" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 6" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 7" => UNIMPLEMENTED, //TODO
+                "KF Shop Item 8" => UNIMPLEMENTED, //TODO
+
+                "Kak Potion Shop Item 1" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 2" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 3" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 4" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 5" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 6" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 7" => UNIMPLEMENTED, //TODO
+                "Kak Potion Shop Item 8" => UNIMPLEMENTED, //TODO
+
+                "Market Bombchu Shop Item 1" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 2" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 3" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 4" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 5" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 6" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 7" => UNIMPLEMENTED, //TODO
+                "Market Bombchu Shop Item 8" => UNIMPLEMENTED, //TODO
+
+                "Market Potion Shop Item 1" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 2" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 3" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 4" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 5" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 6" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 7" => UNIMPLEMENTED, //TODO
+                "Market Potion Shop Item 8" => UNIMPLEMENTED, //TODO
+
+                "Market Bazaar Item 1" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 2" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 3" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 4" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 5" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 6" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 7" => UNIMPLEMENTED, //TODO
+                "Market Bazaar Item 8" => UNIMPLEMENTED, //TODO
+
+                "Kak Bazaar Item 1" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 2" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 3" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 4" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 5" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 6" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 7" => UNIMPLEMENTED, //TODO
+                "Kak Bazaar Item 8" => UNIMPLEMENTED, //TODO
+
+                "ZD Shop Item 1" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 2" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 3" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 4" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 5" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 6" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 7" => UNIMPLEMENTED, //TODO
+                "ZD Shop Item 8" => UNIMPLEMENTED, //TODO
+
+                "GC Shop Item 1" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 2" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 3" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 4" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 5" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 6" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 7" => UNIMPLEMENTED, //TODO
+                "GC Shop Item 8" => UNIMPLEMENTED, //TODO
 
                 // NPC Scrubs are on the overworld, while GrottoNPC is a special handler for Grottos
                 // Grottos scrubs are the same scene and actor, so we use a unique grotto ID for the scene
 
-                "Deku Tree MQ Deku Scrub" => None, //TODO
+                "Deku Tree MQ Deku Scrub" => UNIMPLEMENTED, //TODO
 
-                "HF Deku Scrub Grotto" => None, //TODO
-                "LLR Deku Scrub Grotto Left" => None, //TODO
-                "LLR Deku Scrub Grotto Right" => None, //TODO
-                "LLR Deku Scrub Grotto Center" => None, //TODO
+                "HF Deku Scrub Grotto" => UNIMPLEMENTED, //TODO
+                "LLR Deku Scrub Grotto Left" => UNIMPLEMENTED, //TODO
+                "LLR Deku Scrub Grotto Right" => UNIMPLEMENTED, //TODO
+                "LLR Deku Scrub Grotto Center" => UNIMPLEMENTED, //TODO
 
-                "LW Deku Scrub Near Deku Theater Right" => None, //TODO
-                "LW Deku Scrub Near Deku Theater Left" => None, //TODO
-                "LW Deku Scrub Grotto Rear" => None, //TODO
-                "LW Deku Scrub Grotto Front" => None, //TODO
+                "LW Deku Scrub Near Deku Theater Right" => UNIMPLEMENTED, //TODO
+                "LW Deku Scrub Near Deku Theater Left" => UNIMPLEMENTED, //TODO
+                "LW Deku Scrub Grotto Rear" => UNIMPLEMENTED, //TODO
+                "LW Deku Scrub Grotto Front" => UNIMPLEMENTED, //TODO
 
-                "SFM Deku Scrub Grotto Rear" => None, //TODO
-                "SFM Deku Scrub Grotto Front" => None, //TODO
+                "SFM Deku Scrub Grotto Rear" => UNIMPLEMENTED, //TODO
+                "SFM Deku Scrub Grotto Front" => UNIMPLEMENTED, //TODO
 
-                "GC Deku Scrub Grotto Left" => None, //TODO
-                "GC Deku Scrub Grotto Right" => None, //TODO
-                "GC Deku Scrub Grotto Center" => None, //TODO
+                "GC Deku Scrub Grotto Left" => UNIMPLEMENTED, //TODO
+                "GC Deku Scrub Grotto Right" => UNIMPLEMENTED, //TODO
+                "GC Deku Scrub Grotto Center" => UNIMPLEMENTED, //TODO
 
-                "Dodongos Cavern Deku Scrub Near Bomb Bag Left" => None, //TODO
-                "Dodongos Cavern Deku Scrub Side Room Near Dodongos" => None, //TODO
-                "Dodongos Cavern Deku Scrub Near Bomb Bag Right" => None, //TODO
-                "Dodongos Cavern Deku Scrub Lobby" => None, //TODO
+                "Dodongos Cavern Deku Scrub Near Bomb Bag Left" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern Deku Scrub Side Room Near Dodongos" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern Deku Scrub Near Bomb Bag Right" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern Deku Scrub Lobby" => UNIMPLEMENTED, //TODO
 
-                "Dodongos Cavern MQ Deku Scrub Lobby Rear" => None, //TODO
-                "Dodongos Cavern MQ Deku Scrub Lobby Front" => None, //TODO
-                "Dodongos Cavern MQ Deku Scrub Staircase" => None, //TODO
-                "Dodongos Cavern MQ Deku Scrub Side Room Near Lower Lizalfos" => None, //TODO
+                "Dodongos Cavern MQ Deku Scrub Lobby Rear" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Deku Scrub Lobby Front" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Deku Scrub Staircase" => UNIMPLEMENTED, //TODO
+                "Dodongos Cavern MQ Deku Scrub Side Room Near Lower Lizalfos" => UNIMPLEMENTED, //TODO
 
-                "DMC Deku Scrub" => None, //TODO
-                "DMC Deku Scrub Grotto Left" => None, //TODO
-                "DMC Deku Scrub Grotto Right" => None, //TODO
-                "DMC Deku Scrub Grotto Center" => None, //TODO
+                "DMC Deku Scrub" => UNIMPLEMENTED, //TODO
+                "DMC Deku Scrub Grotto Left" => UNIMPLEMENTED, //TODO
+                "DMC Deku Scrub Grotto Right" => UNIMPLEMENTED, //TODO
+                "DMC Deku Scrub Grotto Center" => UNIMPLEMENTED, //TODO
 
-                "ZR Deku Scrub Grotto Rear" => None, //TODO
-                "ZR Deku Scrub Grotto Front" => None, //TODO
+                "ZR Deku Scrub Grotto Rear" => UNIMPLEMENTED, //TODO
+                "ZR Deku Scrub Grotto Front" => UNIMPLEMENTED, //TODO
 
-                "Jabu Jabus Belly Deku Scrub" => None, //TODO
+                "Jabu Jabus Belly Deku Scrub" => UNIMPLEMENTED, //TODO
 
-                "LH Deku Scrub Grotto Left" => None, //TODO
-                "LH Deku Scrub Grotto Right" => None, //TODO
-                "LH Deku Scrub Grotto Center" => None, //TODO
+                "LH Deku Scrub Grotto Left" => UNIMPLEMENTED, //TODO
+                "LH Deku Scrub Grotto Right" => UNIMPLEMENTED, //TODO
+                "LH Deku Scrub Grotto Center" => UNIMPLEMENTED, //TODO
 
-                "GV Deku Scrub Grotto Rear" => None, //TODO
-                "GV Deku Scrub Grotto Front" => None, //TODO
+                "GV Deku Scrub Grotto Rear" => UNIMPLEMENTED, //TODO
+                "GV Deku Scrub Grotto Front" => UNIMPLEMENTED, //TODO
 
-                "Colossus Deku Scrub Grotto Rear" => None, //TODO
-                "Colossus Deku Scrub Grotto Front" => None, //TODO
+                "Colossus Deku Scrub Grotto Rear" => UNIMPLEMENTED, //TODO
+                "Colossus Deku Scrub Grotto Front" => UNIMPLEMENTED, //TODO
 
-                "Ganons Castle Deku Scrub Center-Left" => None, //TODO
-                "Ganons Castle Deku Scrub Center-Right" => None, //TODO
-                "Ganons Castle Deku Scrub Right" => None, //TODO
-                "Ganons Castle Deku Scrub Left" => None, //TODO
+                "Ganons Castle Deku Scrub Center-Left" => UNIMPLEMENTED, //TODO
+                "Ganons Castle Deku Scrub Center-Right" => UNIMPLEMENTED, //TODO
+                "Ganons Castle Deku Scrub Right" => UNIMPLEMENTED, //TODO
+                "Ganons Castle Deku Scrub Left" => UNIMPLEMENTED, //TODO
 
-                "Ganons Castle MQ Deku Scrub Right" => None, //TODO
-                "Ganons Castle MQ Deku Scrub Center-Left" => None, //TODO
-                "Ganons Castle MQ Deku Scrub Center" => None, //TODO
-                "Ganons Castle MQ Deku Scrub Center-Right" => None, //TODO
-                "Ganons Castle MQ Deku Scrub Left" => None, //TODO
+                "Ganons Castle MQ Deku Scrub Right" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Deku Scrub Center-Left" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Deku Scrub Center" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Deku Scrub Center-Right" => UNIMPLEMENTED, //TODO
+                "Ganons Castle MQ Deku Scrub Left" => UNIMPLEMENTED, //TODO
 
-                "LLR Stables Left Cow" => None, //TODO
-                "LLR Stables Right Cow" => None, //TODO
-                "LLR Tower Right Cow" => None, //TODO
-                "LLR Tower Left Cow" => None, //TODO
-                "KF Links House Cow" => None, //TODO
-                "Kak Impas House Cow" => None, //TODO
-                "GV Cow" => None, //TODO
-                "DMT Cow Grotto Cow" => None, //TODO
-                "HF Cow Grotto Cow" => None, //TODO
+                "LLR Stables Left Cow" => UNIMPLEMENTED, //TODO
+                "LLR Stables Right Cow" => UNIMPLEMENTED, //TODO
+                "LLR Tower Right Cow" => UNIMPLEMENTED, //TODO
+                "LLR Tower Left Cow" => UNIMPLEMENTED, //TODO
+                "KF Links House Cow" => UNIMPLEMENTED, //TODO
+                "Kak Impas House Cow" => UNIMPLEMENTED, //TODO
+                "GV Cow" => UNIMPLEMENTED, //TODO
+                "DMT Cow Grotto Cow" => UNIMPLEMENTED, //TODO
+                "HF Cow Grotto Cow" => UNIMPLEMENTED, //TODO
 
                 // These are not actual locations, but are filler spots used for hint reachability
-                "DMC Gossip Stone" => None, //TODO check knowledge
-                "DMT Gossip Stone" => None, //TODO check knowledge
-                "Colossus Gossip Stone" => None, //TODO check knowledge
-                "Dodongos Cavern Gossip Stone" => None, //TODO check knowledge
-                "GV Gossip Stone" => None, //TODO check knowledge
-                "GC Maze Gossip Stone" => None, //TODO check knowledge
-                "GC Medigoron Gossip Stone" => None, //TODO check knowledge
-                "Graveyard Gossip Stone" => None, //TODO check knowledge
-                "HC Malon Gossip Stone" => None, //TODO check knowledge
-                "HC Rock Wall Gossip Stone" => None, //TODO check knowledge
-                "HC Storms Grotto Gossip Stone" => None, //TODO check knowledge
-                "HF Cow Grotto Gossip Stone" => None, //TODO check knowledge
-                "KF Deku Tree Gossip Stone (Left)" => None, //TODO check knowledge
-                "KF Deku Tree Gossip Stone (Right)" => None, //TODO check knowledge
-                "KF Gossip Stone" => None, //TODO check knowledge
-                "LH Lab Gossip Stone" => None, //TODO check knowledge
-                "LH Gossip Stone (Southeast)" => None, //TODO check knowledge
-                "LH Gossip Stone (Southwest)" => None, //TODO check knowledge
-                "LW Gossip Stone" => None, //TODO check knowledge
-                "SFM Maze Gossip Stone (Lower)" => None, //TODO check knowledge
-                "SFM Maze Gossip Stone (Upper)" => None, //TODO check knowledge
-                "SFM Saria Gossip Stone" => None, //TODO check knowledge
-                "ToT Gossip Stone (Left)" => None, //TODO check knowledge
-                "ToT Gossip Stone (Left-Center)" => None, //TODO check knowledge
-                "ToT Gossip Stone (Right)" => None, //TODO check knowledge
-                "ToT Gossip Stone (Right-Center)" => None, //TODO check knowledge
-                "ZD Gossip Stone" => None, //TODO check knowledge
-                "ZF Fairy Gossip Stone" => None, //TODO check knowledge
-                "ZF Jabu Gossip Stone" => None, //TODO check knowledge
-                "ZR Near Grottos Gossip Stone" => None, //TODO check knowledge
-                "ZR Near Domain Gossip Stone" => None, //TODO check knowledge
-
-                "HF Near Market Grotto Gossip Stone" => None, //TODO check knowledge
-                "HF Southeast Grotto Gossip Stone" => None, //TODO check knowledge
-                "HF Open Grotto Gossip Stone" => None, //TODO check knowledge
-                "Kak Open Grotto Gossip Stone" => None, //TODO check knowledge
-                "ZR Open Grotto Gossip Stone" => None, //TODO check knowledge
-                "KF Storms Grotto Gossip Stone" => None, //TODO check knowledge
-                "LW Near Shortcuts Grotto Gossip Stone" => None, //TODO check knowledge
-                "DMT Storms Grotto Gossip Stone" => None, //TODO check knowledge
-                "DMC Upper Grotto Gossip Stone" => None, //TODO check knowledge
-
-                "Ganondorf Hint" => None, //TODO check knowledge
-
-                _ => panic!("unknown location name: {}", loc),
+                "DMC Gossip Stone" |
+                "DMT Gossip Stone" |
+                "Colossus Gossip Stone" |
+                "Dodongos Cavern Gossip Stone" |
+                "GV Gossip Stone" |
+                "GC Maze Gossip Stone" |
+                "GC Medigoron Gossip Stone" |
+                "Graveyard Gossip Stone" |
+                "HC Malon Gossip Stone" |
+                "HC Rock Wall Gossip Stone" |
+                "HC Storms Grotto Gossip Stone" |
+                "HF Cow Grotto Gossip Stone" |
+                "KF Deku Tree Gossip Stone (Left)" |
+                "KF Deku Tree Gossip Stone (Right)" |
+                "KF Gossip Stone" |
+                "LH Lab Gossip Stone" |
+                "LH Gossip Stone (Southeast)" |
+                "LH Gossip Stone (Southwest)" |
+                "LW Gossip Stone" |
+                "SFM Maze Gossip Stone (Lower)" |
+                "SFM Maze Gossip Stone (Upper)" |
+                "SFM Saria Gossip Stone" |
+                "ToT Gossip Stone (Left)" |
+                "ToT Gossip Stone (Left-Center)" |
+                "ToT Gossip Stone (Right)" |
+                "ToT Gossip Stone (Right-Center)" |
+                "ZD Gossip Stone" |
+                "ZF Fairy Gossip Stone" |
+                "ZF Jabu Gossip Stone" |
+                "ZR Near Grottos Gossip Stone" |
+                "ZR Near Domain Gossip Stone" |
+                "HF Near Market Grotto Gossip Stone" |
+                "HF Southeast Grotto Gossip Stone" |
+                "HF Open Grotto Gossip Stone" |
+                "Kak Open Grotto Gossip Stone" |
+                "ZR Open Grotto Gossip Stone" |
+                "KF Storms Grotto Gossip Stone" |
+                "LW Near Shortcuts Grotto Gossip Stone" |
+                "DMT Storms Grotto Gossip Stone" |
+                "DMC Upper Grotto Gossip Stone" => CheckedState::from(model.knowledge.gossip_stones.contains_key(loc)),
+
+                "Ganondorf Hint" => CheckedState::from(model.knowledge.npc_hints.contains_key(&NpcHint::Ganondorf)),
+
+                _ => CheckedState::Unsupported(Cow::Owned(format!("unknown location name: {}", loc))),
             },
-            Check::LogicHelper(_) => panic!("logic helpers can't be checked"),
-            Check::Mq(_) => Some(false), //TODO disambiguate MQ-ness here instead?
-            Check::Setting(_) => panic!("setting checks not implemented"), //TODO check knowledge
-            Check::TrialActive(_) => panic!("trial-active checks not implemented"), //TODO check knowledge
-            Check::Trick(_) => panic!("trick checks not implemented"), //TODO check knowledge, allow the player to decide their own tricks if unknown
+            Check::LogicHelper(_) => CheckedState::Unknown(Cow::Borrowed("logic helpers can't be checked")),
+            Check::Mq(dungeon) => CheckedState::from(model.knowledge.mq.contains_key(dungeon)), //TODO disambiguate MQ-ness here instead?
+            Check::Setting { name, .. } => CheckedState::from(
+                model.knowledge.bool_settings.contains_key(name)
+                || model.knowledge.string_settings.get(name).map_or(false, |values| values.len() == 1)
+            ),
+            Check::TrialActive(trial) => CheckedState::from(model.knowledge.active_trials.contains_key(trial)),
+            Check::Trick(trick) => CheckedState::from(model.knowledge.tricks.contains_key(trick)), //TODO allow the player to decide their own tricks if unknown
+        }
+    }
+}
+
+/// A name from the randomizer's logic which the tracker's RAM flag tables (`scene_flags!` and `flags_list!`) refer to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferencedName {
+    Event(&'static str),
+    Exit {
+        from: &'static str,
+        to: &'static str,
+    },
+    Location(&'static str),
+    Region(&'static str),
+}
+
+impl fmt::Display for ReferencedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferencedName::Event(name) => write!(f, "event {:?}", name),
+            ReferencedName::Exit { from, to } => write!(f, "exit {:?} -> {:?}", from, to),
+            ReferencedName::Location(name) => write!(f, "location {:?}", name),
+            ReferencedName::Region(name) => write!(f, "region {:?}", name),
+        }
+    }
+}
+
+/// All names used in the tracker's RAM flag tables.
+pub fn referenced_names() -> impl Iterator<Item = ReferencedName> {
+    SceneFlags::REFERENCED_NAMES.iter()
+        .chain(EventChkInf::REFERENCED_NAMES)
+        .chain(ItemGetInf::REFERENCED_NAMES)
+        .chain(InfTable::REFERENCED_NAMES)
+        .copied()
+}
+
+/// Returns the names used in the tracker's RAM flag tables which don't exist in the given randomizer version's logic (glitchless or glitched).
+pub fn stale_names<R: Rando>(rando: &R) -> Result<BTreeSet<ReferencedName>, R::Err> {
+    let mut regions = HashSet::new();
+    let mut events = HashSet::new();
+    let mut locations = HashSet::new();
+    let mut exits = HashSet::new();
+    for glitched in [false, true] {
+        for region in &*rando.regions(glitched)? {
+            regions.insert(region.name.as_ref().to_owned());
+            events.extend(region.events.keys().cloned());
+            locations.extend(region.locations.keys().cloned());
+            exits.extend(region.exits.keys().map(|to| (region.name.as_ref().to_owned(), to.as_ref().to_owned())));
+        }
+    }
+    Ok(referenced_names().filter(|name| match *name {
+        ReferencedName::Event(event) => !events.contains(event),
+        ReferencedName::Exit { from, to } => !exits.contains(&(from.to_owned(), to.to_owned())),
+        ReferencedName::Location(location) => !locations.contains(location),
+        ReferencedName::Region(region) => !regions.contains(region),
+    }).collect())
+}
+
+/// The locations in the tracker's RAM flag tables which are checked in `new` but weren't in `old`, limited to those which exist in the given randomizer version's logic.
+pub fn newly_checked_locations<R: Rando>(rando: &R, old: &Ram, new: &Ram) -> Result<Vec<&'static str>, R::Err> {
+    let (old_flags, new_flags) = (old.scene_flags(), new.scene_flags());
+    let checked = |ram: &Ram, flags: &SceneFlags, check: &Check<R>| ram.save.gold_skulltulas.checked(check)
+        .or_else(|| flags.checked(check))
+        .or_else(|| ram.save.event_chk_inf.checked(check))
+        .or_else(|| ram.save.item_get_inf.checked(check))
+        .or_else(|| ram.save.inf_table.checked(check))
+        .unwrap_or(false);
+    let mut locations = referenced_names().filter_map(|name| match name {
+        ReferencedName::Location(location) => {
+            let check = Check::<R>::Location(location.to_owned());
+            (!checked(old, &old_flags, &check) && checked(new, &new_flags, &check)).then(|| location)
+        }
+        _ => None,
+    }).collect::<Vec<_>>();
+    if !locations.is_empty() {
+        let mut known_locations = HashSet::new();
+        for glitched in [false, true] {
+            for region in &*rando.regions(glitched)? {
+                known_locations.extend(region.locations.keys().cloned());
+            }
+        }
+        locations.retain(|location| known_locations.contains(*location));
+    }
+    Ok(locations)
+}
+
+/// How the tracker determines whether a check has been done.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Coverage {
+    /// Tracked using a RAM flag table generated by `scene_flags!`.
+    SceneFlags,
+    /// Tracked using a RAM flag table generated by `flags_list!`.
+    FlagsList,
+    /// Tracked using a hand-written match arm in [`CheckExt::checked_state`].
+    HandWritten,
+    /// Can't be tracked by design, see [`CheckedState::Unknown`].
+    Unknown(Cow<'static, str>),
+    /// Not tracked yet, see [`CheckedState::Unsupported`].
+    Unsupported(Cow<'static, str>),
+    /// An exit in the randomizer's entrance table. Its target is learned from scene transitions (see [`ModelState::learn_exit`]) rather than checked using RAM flags.
+    SceneTransition,
+    /// An exit which the randomizer never shuffles, so it always leads to its vanilla target.
+    VanillaExit,
+}
+
+impl Coverage {
+    /// Whether the tracker can tell if this check has been done.
+    pub fn is_tracked(&self) -> bool {
+        matches!(self, Coverage::SceneFlags | Coverage::FlagsList | Coverage::HandWritten)
+    }
+
+    /// Whether this is the coverage of an exit, which is reported separately since exits are learned rather than checked.
+    pub fn is_exit(&self) -> bool {
+        matches!(self, Coverage::SceneTransition | Coverage::VanillaExit)
+    }
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Coverage::SceneFlags => write!(f, "scene flags"),
+            Coverage::FlagsList => write!(f, "flags list"),
+            Coverage::HandWritten => write!(f, "hand-written"),
+            Coverage::Unknown(reason) => write!(f, "unknown ({})", reason),
+            Coverage::Unsupported(reason) => write!(f, "unsupported ({})", reason),
+            Coverage::SceneTransition => write!(f, "scene transition"),
+            Coverage::VanillaExit => write!(f, "vanilla exit"),
+        }
+    }
+}
+
+/// Determines how the tracker handles the given check, by running it against a default model state.
+///
+/// Exits are classified using the randomizer's entrance table, see [`coverage_report`].
+pub fn coverage<R: Rando>(check: &Check<R>) -> Coverage {
+    let model = ModelState::default();
+    if model.ram.save.gold_skulltulas.checked(check).is_some() || model.ram.scene_flags().checked(check).is_some() { return Coverage::SceneFlags }
+    if model.ram.save.event_chk_inf.checked(check).is_some() || model.ram.save.item_get_inf.checked(check).is_some() || model.ram.save.inf_table.checked(check).is_some() { return Coverage::FlagsList }
+    match check.checked_state(&model) {
+        CheckedState::Checked | CheckedState::NotChecked => Coverage::HandWritten,
+        CheckedState::Unknown(reason) => Coverage::Unknown(reason),
+        CheckedState::Unsupported(reason) => Coverage::Unsupported(reason),
+    }
+}
+
+/// Lists every event, location, and exit in the given randomizer version's logic along with how the tracker handles it.
+///
+/// Checks which appear in multiple regions are only listed once, in the order of first appearance.
+pub fn coverage_report<R: Rando>(rando: &R, glitched: bool) -> Result<Vec<(Check<R>, Coverage)>, R::Err> {
+    let mut seen = HashSet::new();
+    let mut report = Vec::default();
+    let shuffleable = rando.entrances()?.iter()
+        .flat_map(|EntrancePair { forward, reverse, .. }| iter::once(forward).chain(reverse))
+        .map(|EntranceInfo { from, to, .. }| (from.clone(), to.clone()))
+        .collect::<HashSet<_>>();
+    for region in &*rando.regions(glitched)? {
+        let checks = region.events.keys().map(|event| Check::Event(event.clone()))
+            .chain(region.locations.keys().map(|location| Check::Location(location.clone())))
+            .chain(region.exits.keys().map(|to| Check::Exit { from: region.name.clone(), from_mq: region.dungeon.map(|(_, mq)| mq), to: to.clone() }));
+        for check in checks {
+            if seen.insert(check.clone()) {
+                let coverage = if let Check::Exit { ref from, ref to, .. } = check {
+                    if shuffleable.contains(&(from.as_ref().to_owned(), to.as_ref().to_owned())) { Coverage::SceneTransition } else { Coverage::VanillaExit }
+                } else {
+                    coverage(&check)
+                };
+                report.push((check, coverage));
+            }
         }
     }
+    Ok(report)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CheckStatus {
     Checked,
     Reachable,
@@ -738,6 +973,8 @@ pub enum CheckStatus {
 #[derivative(Debug(bound = ""), Clone(bound = ""))]
 pub enum CheckStatusError<R: Rando> {
     Io(Arc<io::Error>),
+    #[from(ignore)]
+    Rando(R::Err),
     RegionLookup(RegionLookupError<R>),
 }
 
@@ -751,6 +988,7 @@ impl<R: Rando> fmt::Display for CheckStatusError<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             CheckStatusError::Io(e) => write!(f, "I/O error: {}", e),
+            CheckStatusError::Rando(e) => e.fmt(f),
             CheckStatusError::RegionLookup(e) => e.fmt(f),
         }
     }
@@ -47,6 +47,10 @@ use {
     crate::{
         ModelState,
         checks::CheckExt as _,
+        knowledge::{
+            Fact,
+            Source,
+        },
         ui::{
             TrackerCellId,
             TrackerCellKind::{
@@ -151,14 +155,17 @@ pub trait App: fmt::Debug + Send + Sync + 'static {
                     set(state, value * step);
                 }
             }
-            FortressMq => if value.as_bool().ok_or_else(|| value.clone())? {
-                state.knowledge.string_settings.insert(format!("gerudo_fortress"), collect![format!("normal")]);
-            } else {
-                // don't override local state that's consistent with the value received
-                if state.knowledge.string_settings.get("gerudo_fortress").map_or(false, |fort| fort.iter().eq(iter::once("normal"))) {
-                    state.knowledge.string_settings.remove("gerudo_fortress");
+            FortressMq => {
+                if value.as_bool().ok_or_else(|| value.clone())? {
+                    state.knowledge.string_settings.insert(format!("gerudo_fortress"), collect![format!("normal")]);
+                } else {
+                    // don't override local state that's consistent with the value received
+                    if state.knowledge.string_settings.get("gerudo_fortress").map_or(false, |fort| fort.iter().eq(iter::once("normal"))) {
+                        state.knowledge.string_settings.remove("gerudo_fortress");
+                    }
                 }
-            },
+                state.knowledge.update_source(Fact::Setting { name: format!("gerudo_fortress") }, Source::Manual);
+            }
             Medallion(med) => if value.as_bool().ok_or_else(|| value.clone())? {
                 state.ram.save.quest_items.insert(med.into());
             } else {
@@ -178,15 +185,19 @@ pub trait App: fmt::Debug + Send + Sync + 'static {
                     9 => state.knowledge.dungeon_reward_locations.insert(DungeonReward::Medallion(med), DungeonRewardLocation::LinksPocket),
                     _ => return Err(value),
                 };
+                state.knowledge.update_source(Fact::DungeonRewardLocation { reward: DungeonReward::Medallion(med) }, Source::Manual);
             }
-            Mq(dungeon) => if value.as_bool().ok_or_else(|| value.clone())? {
-                state.knowledge.mq.insert(dungeon, Mq::Mq);
-            } else {
-                // don't override local state that's consistent with the value received
-                if state.knowledge.mq.get(&dungeon).map_or(false, |&mq| mq == Mq::Mq) {
-                    state.knowledge.mq.remove(&dungeon);
+            Mq(dungeon) => {
+                if value.as_bool().ok_or_else(|| value.clone())? {
+                    state.knowledge.mq.insert(dungeon, Mq::Mq);
+                } else {
+                    // don't override local state that's consistent with the value received
+                    if state.knowledge.mq.get(&dungeon).map_or(false, |&mq| mq == Mq::Mq) {
+                        state.knowledge.mq.remove(&dungeon);
+                    }
                 }
-            },
+                state.knowledge.update_source(Fact::Mq { dungeon }, Source::Manual);
+            }
             OptionalOverlay { active, toggle_main, .. } | Overlay { active, toggle_main, .. } => if active(state).0 != value.as_bool().ok_or_else(|| value.clone())? {
                 toggle_main(state);
             },
@@ -238,6 +249,7 @@ pub trait App: fmt::Debug + Send + Sync + 'static {
                     9 => state.knowledge.dungeon_reward_locations.insert(DungeonReward::Stone(stone), DungeonRewardLocation::LinksPocket),
                     _ => return Err(value),
                 };
+                state.knowledge.update_source(Fact::DungeonRewardLocation { reward: DungeonReward::Stone(stone) }, Source::Manual);
             }
             BigPoeTriforce | CompositeKeys { .. } | GoBk | FreeReward | MagicLens | MedallionWithLocation(_) | StoneWithLocation(_) => unimplemented!(),
         }
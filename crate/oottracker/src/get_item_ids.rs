@@ -0,0 +1,16 @@
+//! The randomizer's get-item IDs, as found in `z64_link.get_item_id`. From the `item_table` in the randomizer's `ItemList.py`.
+
+use {
+    itertools::Itertools as _,
+    ootr::Rando,
+};
+
+/// The name of the randomizer item with the given get-item ID, or `None` if there isn't exactly one such item.
+pub(crate) fn item_name<R: Rando>(rando: &R, get_item_id: u16) -> Result<Option<String>, R::Err> {
+    Ok(rando.get_item_ids()?.iter().filter(|&(_, &id)| id == get_item_id).map(|(name, _)| name.clone()).exactly_one().ok())
+}
+
+/// The get-item ID of the randomizer item with the given name.
+pub(crate) fn get_item_id<R: Rando>(rando: &R, item_name: &str) -> Result<Option<u16>, R::Err> {
+    Ok(rando.get_item_ids()?.get(item_name).copied())
+}
@@ -6,8 +6,13 @@ use {
         },
         fmt,
         future::Future,
+        hash::Hash,
         io::prelude::*,
-        ops::BitAnd,
+        ops::{
+            AddAssign,
+            BitAnd,
+            Sub,
+        },
         pin::Pin,
     },
     async_proto::{
@@ -32,10 +37,16 @@ use {
         AsyncWrite,
     },
     ootr::{
+        Rando,
         item::Item,
         model::*,
         region::Mq,
+        setting::{
+            SettingType,
+            SettingValue,
+        },
     },
+    crate::settings_string::STARTING_ITEM_SETTINGS,
 };
 
 #[derive(Derivative, Debug, Clone, Copy, PartialEq, Eq, Protocol, Deserialize, Serialize)]
@@ -55,98 +66,236 @@ pub enum ProgressionMode {
     Done,
 }
 
-#[derive(Derivative, Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
-#[derivative(Default)]
+/// A hint as read from a gossip stone.
+///
+/// Locations, items, and entrances are kept as they appear in the hint text since the tracker doesn't know the randomizer's hint table.
+#[derive(Debug, Clone, PartialEq, Eq, Protocol, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Hint {
+    /// The given hint area is required to beat the game.
+    WayOfTheHero {
+        area: String,
+    },
+    /// The given hint area has no items required to beat the game.
+    Foolish {
+        area: String,
+    },
+    /// An always, sometimes, or dual hint.
+    Item {
+        location: String,
+        item: String,
+    },
+    Entrance {
+        entrance: String,
+        target: String,
+    },
+    /// A hint the tracker doesn't know how to parse.
+    Other {
+        text: String,
+    },
+}
+
+/// A source of hints other than gossip stones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Protocol, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NpcHint {
+    /// Ganondorf's hint for the Light Arrows.
+    Ganondorf,
+    /// The reward for the given number of Gold Skulltula Tokens.
+    SkulltulaHouse(u8),
+    /// The reward for the Frogs' ocarina game.
+    Frogs,
+    /// The reward for the Claim Check.
+    Biggoron,
+}
+
+impl fmt::Display for NpcHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpcHint::Ganondorf => write!(f, "Ganondorf's hint"),
+            NpcHint::SkulltulaHouse(count) => write!(f, "the hint for the {} Gold Skulltula reward", count),
+            NpcHint::Frogs => write!(f, "the frogs' hint"),
+            NpcHint::Biggoron => write!(f, "Biggoron's hint"),
+        }
+    }
+}
+
+/// Where a known fact came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Protocol, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    /// The text on the altar in the Temple of Time.
+    AltarText,
+    /// The text shown when finding a dungeon's compass.
+    CompassText,
+    /// Princess Ruto's text when she tells Link which stone is in Jabu Jabu's Belly.
+    RutoText,
+    /// Gossip stone or NPC hint text.
+    HintText,
+    /// The randomizer's tracker context in RAM, e.g. the dungeon info screen in the pause menu.
+    TrackerCtx,
+    /// Read from RAM by the auto-tracker, e.g. which item was received from a location or which exit was taken.
+    AutoTracker,
+    /// Derived from other knowledge, e.g. the last remaining dungeon reward location.
+    Inferred,
+    /// Entered by the user by clicking on a tracker cell.
+    Manual,
+    Plando,
+    Spoiler,
+    SettingsString,
+    Weights,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::AltarText => write!(f, "altar text"),
+            Source::CompassText => write!(f, "compass text"),
+            Source::RutoText => write!(f, "Ruto's text"),
+            Source::HintText => write!(f, "hint text"),
+            Source::TrackerCtx => write!(f, "tracker context"),
+            Source::AutoTracker => write!(f, "auto-tracker"),
+            Source::Inferred => write!(f, "inference"),
+            Source::Manual => write!(f, "manual input"),
+            Source::Plando => write!(f, "plando"),
+            Source::Spoiler => write!(f, "spoiler log"),
+            Source::SettingsString => write!(f, "settings string"),
+            Source::Weights => write!(f, "weights"),
+        }
+    }
+}
+
+/// Identifies a single piece of [`Knowledge`], for example to record its [`Source`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Protocol, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Fact {
+    /// A bool or string setting.
+    Setting {
+        name: String,
+    },
+    Trick {
+        name: String,
+    },
+    Mq {
+        dungeon: Dungeon,
+    },
+    Trial {
+        trial: Medallion,
+    },
+    DungeonRewardLocation {
+        reward: DungeonReward,
+    },
+    Location {
+        location: String,
+    },
+    Exit {
+        from: String,
+        to: String,
+    },
+    StartingItem {
+        name: String,
+    },
+    GossipStone {
+        stone: String,
+    },
+    NpcHint {
+        npc: NpcHint,
+    },
+}
+
+impl fmt::Display for Fact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fact::Setting { name } => write!(f, "setting {}", name),
+            Fact::Trick { name } => write!(f, "trick {}", name),
+            Fact::Mq { dungeon } => write!(f, "MQ status of {}", dungeon),
+            Fact::Trial { trial } => write!(f, "{} trial", trial.element()),
+            Fact::DungeonRewardLocation { reward } => write!(f, "location of {}", reward),
+            Fact::Location { location } => write!(f, "item at {}", location),
+            Fact::Exit { from, to } => write!(f, "exit from {} to {}", from, to),
+            Fact::StartingItem { name } => write!(f, "starting {}", name),
+            Fact::GossipStone { stone } => write!(f, "hint at {}", stone),
+            Fact::NpcHint { npc } => npc.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(try_from = "KnowledgeJson", into = "KnowledgeJson")]
 pub struct Knowledge {
     pub bool_settings: HashMap<String, bool>, //TODO hardcode settings instead? (or only hardcode some settings and fall back to this for unknown settings)
     pub string_settings: HashMap<String, HashSet<String>>, //TODO hardcode settings instead? (or only hardcode some settings and fall back to this for unknown settings)
-    #[derivative(Default(value = "Some(HashMap::default())"))]
-    pub tricks: Option<HashMap<String, bool>>, //TODO remove option wrapping
+    /// Whether each trick is enabled, by trick name. Tricks not listed here are unknown.
+    pub tricks: HashMap<String, bool>,
     pub mq: HashMap<Dungeon, Mq>,
     pub active_trials: HashMap<Medallion, bool>,
     pub dungeon_reward_locations: HashMap<DungeonReward, DungeonRewardLocation>,
-    #[derivative(Default(value = "Some(HashMap::default())"))] //TODO include exits that are never shuffled
-    pub exits: Option<HashMap<String, HashMap<String, String>>>, //TODO remove option wrapping
-    pub progression_mode: ProgressionMode, //TODO automatically determine from remaining model state
+    /// The item at each location, by location name. Locations not listed here are unknown. Dungeon rewards are tracked in `dungeon_reward_locations` instead.
+    pub locations: HashMap<String, Item>,
+    /// The region each exit leads to, by the name of the region it's in and the name of the region it leads to in vanilla. Exits not listed here are unknown, and logic assumes they're vanilla.
+    pub exits: HashMap<String, HashMap<String, String>>,
+    /// How many copies of each item, by name, the player starts with. Items not listed here are unknown.
+    pub starting_items: HashMap<String, u8>,
+    /// Recalculated from the rest of the model state by `ModelState::update_progression_mode`, but can also be set manually, e.g. on restream layouts.
+    pub progression_mode: ProgressionMode,
+    /// The hints read from gossip stones so far, by the stone's location name.
+    pub gossip_stones: HashMap<String, Hint>,
+    /// The hints given by characters other than gossip stones.
+    pub npc_hints: HashMap<NpcHint, Hint>,
+    /// Where each known fact came from. Facts not listed here have an unknown source, e.g. those implied by vanilla knowledge.
+    pub sources: HashMap<Fact, Source>,
+}
+
+/// Maps each exit in the given randomizer version's world graphs to its vanilla target.
+///
+/// Includes MQ dungeon regions as well as exits which only exist in glitched logic.
+pub(crate) fn vanilla_exits<R: Rando>(rando: &R) -> Result<HashMap<String, HashMap<String, String>>, R::Err> {
+    let mut exits = HashMap::<_, HashMap<_, _>>::default();
+    for glitched in [false, true] {
+        for region in &*rando.regions(glitched)? {
+            let region_exits = exits.entry(region.name.as_ref().to_owned()).or_default();
+            for to in region.exits.keys() {
+                region_exits.insert(to.as_ref().to_owned(), to.as_ref().to_owned());
+            }
+        }
+    }
+    Ok(exits)
 }
 
 impl Knowledge {
     /// We know that everything is vanilla. Used by auto-trackers when the base game, rather than rando, is detected.
-    pub fn vanilla() -> Knowledge {
-        Knowledge {
-            bool_settings: collect![
-                format!("open_door_of_time") => false,
-                format!("triforce_hunt") => false,
-                format!("all_reachable") => true,
-                format!("bombchus_in_logic") => false,
-                format!("one_item_per_dungeon") => false,
-                format!("trials_random") => false,
-                format!("skip_child_zelda") => false,
-                format!("no_escape_sequence") => false,
-                format!("no_guard_stealth") => false,
-                format!("no_epona_race") => false,
-                format!("skip_some_minigame_phases") => false,
-                format!("useful_cutscenes") => true,
-                format!("complete_mask_quest") => false,
-                format!("fast_chests") => false,
-                format!("logic_no_night_tokens_without_suns_song") => false,
-                format!("free_scarecrow") => false,
-                format!("fast_bunny_hood") => false,
-                format!("start_with_rupees") => false,
-                format!("start_with_consumables") => false,
-                format!("chicken_count_random") => false,
-                format!("big_poe_count_random") => false,
-                format!("shuffle_kokiri_sword") => false,
-                format!("shuffle_ocarinas") => false,
-                format!("shuffle_weird_egg") => false,
-                format!("shuffle_gerudo_card") => false,
-                format!("shuffle_cows") => false,
-                format!("shuffle_beans") => false,
-                format!("shuffle_medigoron_carpet_salesman") => false,
-                format!("shuffle_grotto_entrances") => false,
-                format!("shuffle_dungeon_entrances") => false,
-                format!("shuffle_overworld_entrances") => false,
-                format!("decouple_entrances") => false,
-                format!("owl_drops") => false,
-                format!("warp_songs") => false,
-                format!("spawn_positions") => false,
-                format!("enhance_map_compass") => false,
-                format!("mq_dungeons_random") => false,
-                format!("ocarina_songs") => false,
-                format!("correct_chest_sizes") => false,
-                format!("no_collectible_hearts") => false,
-            ],
-            string_settings: collect![
-                format!("open_forest") => collect![format!("closed")],
-                format!("open_kakariko") => collect![format!("closed")],
-                format!("zora_fountain") => collect![format!("closed")],
-                format!("gerudo_fortress") => collect![format!("normal")],
-                format!("bridge") => collect![format!("vanilla")],
-                format!("logic_rules") => collect![format!("glitchless")],
-                format!("shuffle_song_items") => collect![format!("song")],
-                format!("shuffle_interior_entrances") => collect![format!("off")],
-                format!("mix_entrance_pools") => collect![format!("off")],
-                format!("shuffle_scrubs") => collect![format!("off")],
-                format!("shopsanity") => collect![format!("off")],
-                format!("tokensanity") => collect![format!("off")],
-                format!("shuffle_mapcompass") => collect![format!("vanilla")],
-                format!("shuffle_smallkeys") => collect![format!("vanilla")],
-                format!("shuffle_fortresskeys") => collect![format!("vanilla")],
-                format!("shuffle_bosskeys") => collect![format!("vanilla")],
-                format!("shuffle_ganon_bosskey") => collect![format!("vanilla")],
-                format!("logic_earliest_adult_trade") => collect![format!("pocket_egg")],
-                format!("logic_latest_adult_trade") => collect![format!("pocket_egg")],
-                format!("hints") => collect![format!("none")],
-                format!("hint_dist") => collect![format!("useless")],
-                format!("text_shuffle") => collect![format!("none")],
-                format!("ice_trap_appearance") => collect![format!("junk_only")],
-                format!("junk_ice_traps") => collect![format!("normal")],
-                format!("item_pool_value") => collect![format!("balanced")],
-                format!("damage_multiplier") => collect![format!("normal")],
-                format!("starting_tod") => collect![format!("default")],
-            ],
-            tricks: None, //TODO properly initialize with all tricks set to false
+    ///
+    /// Settings take their defaults from the randomizer's settings list. Exits and item locations are taken from the randomizer's world graphs and location table.
+    /// The defaults of list settings are only known for tricks and starting items, other list settings such as `disabled_locations` are left unknown.
+    pub fn vanilla<R: Rando>(rando: &R) -> Result<Knowledge, R::Err> {
+        let mut bool_settings = HashMap::default();
+        let mut string_settings = HashMap::default();
+        let mut tricks = rando.logic_tricks()?.iter().map(|trick| (trick.clone(), false)).collect::<HashMap<_, _>>();
+        let starting_item_table = rando.starting_items()?;
+        let mut starting_items = starting_item_table.values().map(|item| (item.name().to_owned(), 0)).collect::<HashMap<_, _>>();
+        let setting_infos = rando.setting_infos()?;
+        for (name, info) in &*setting_infos {
+            match info.default {
+                Some(SettingValue::Bool(enabled)) => { bool_settings.insert(name.clone(), enabled); }
+                Some(SettingValue::Int(value)) => { string_settings.insert(name.clone(), collect![value.to_string()]); }
+                Some(SettingValue::Str(ref value)) => { string_settings.insert(name.clone(), collect![value.clone()]); }
+                Some(SettingValue::List(ref values)) => if name == "allowed_tricks" {
+                    for trick in values {
+                        tricks.insert(trick.clone(), true);
+                    }
+                } else if STARTING_ITEM_SETTINGS.contains(&&**name) {
+                    for value in values {
+                        if let Some(item) = starting_item_table.get(value) {
+                            *starting_items.entry(item.name().to_owned()).or_default() += 1;
+                        }
+                    }
+                },
+                None => {}
+            }
+        }
+        Ok(Knowledge {
+            bool_settings, string_settings, tricks, starting_items,
+            exits: vanilla_exits(rando)?,
             dungeon_reward_locations: collect![
                 DungeonReward::Stone(Stone::KokiriEmerald) => DungeonRewardLocation::Dungeon(MainDungeon::DekuTree),
                 DungeonReward::Stone(Stone::GoronRuby) => DungeonRewardLocation::Dungeon(MainDungeon::DodongosCavern),
@@ -172,7 +321,11 @@ impl Knowledge {
                 Dungeon::GerudoTrainingGround => Mq::Vanilla,
                 Dungeon::GanonsCastle => Mq::Vanilla,
             ],
-            exits: None, //TODO properly initialize with all exits
+            // dungeon rewards are tracked in `dungeon_reward_locations`
+            locations: rando.vanilla_items()?.iter()
+                .filter(|(_, item)| DungeonReward::try_from((*item).clone()).is_err())
+                .map(|(location, item)| (location.clone(), item.clone()))
+                .collect(),
             active_trials: collect![
                 Medallion::Light => true,
                 Medallion::Forest => true,
@@ -182,15 +335,141 @@ impl Knowledge {
                 Medallion::Spirit => true,
             ],
             progression_mode: ProgressionMode::Go,
+            gossip_stones: HashMap::default(),
+            npc_hints: HashMap::default(),
+            sources: HashMap::default(),
+        })
+    }
+
+    /// Whether the given fact is known.
+    pub fn knows(&self, fact: &Fact) -> bool {
+        match fact {
+            Fact::Setting { name } => self.bool_settings.contains_key(name) || self.string_settings.contains_key(name),
+            Fact::Trick { name } => self.tricks.contains_key(name),
+            Fact::Mq { dungeon } => self.mq.contains_key(dungeon),
+            Fact::Trial { trial } => self.active_trials.contains_key(trial),
+            Fact::DungeonRewardLocation { reward } => self.dungeon_reward_locations.contains_key(reward),
+            Fact::Location { location } => self.locations.contains_key(location),
+            Fact::Exit { from, to } => self.exits.get(from).map_or(false, |region_exits| region_exits.contains_key(to)),
+            Fact::StartingItem { name } => self.starting_items.contains_key(name),
+            Fact::GossipStone { stone } => self.gossip_stones.contains_key(stone),
+            Fact::NpcHint { npc } => self.npc_hints.contains_key(npc),
+        }
+    }
+
+    /// Records the source of the given fact if it's known, or forgets its source if it isn't. Used after a fact has been edited in place.
+    pub fn update_source(&mut self, fact: Fact, source: Source) {
+        if self.knows(&fact) {
+            self.sources.insert(fact, source);
+        } else {
+            self.sources.remove(&fact);
+        }
+    }
+
+    /// Records the given source for all known facts that don't have a source yet.
+    pub fn with_source(mut self, source: Source) -> Knowledge {
+        let facts = self.bool_settings.keys().chain(self.string_settings.keys()).map(|name| Fact::Setting { name: name.clone() })
+            .chain(self.tricks.keys().map(|name| Fact::Trick { name: name.clone() }))
+            .chain(self.mq.keys().map(|&dungeon| Fact::Mq { dungeon }))
+            .chain(self.active_trials.keys().map(|&trial| Fact::Trial { trial }))
+            .chain(self.dungeon_reward_locations.keys().map(|&reward| Fact::DungeonRewardLocation { reward }))
+            .chain(self.locations.keys().map(|location| Fact::Location { location: location.clone() }))
+            .chain(self.exits.iter().flat_map(|(from, region_exits)| region_exits.keys().map(move |to| Fact::Exit { from: from.clone(), to: to.clone() })))
+            .chain(self.starting_items.keys().map(|name| Fact::StartingItem { name: name.clone() }))
+            .chain(self.gossip_stones.keys().map(|stone| Fact::GossipStone { stone: stone.clone() }))
+            .chain(self.npc_hints.keys().map(|&npc| Fact::NpcHint { npc }))
+            .collect_vec();
+        for fact in facts {
+            self.sources.entry(fact).or_insert(source);
+        }
+        self
+    }
+
+    /// Whether the glitched world graph applies, according to the `logic_rules` setting. `None` if this is unknown.
+    pub fn glitched(&self) -> Option<bool> {
+        let logic_rules = self.string_settings.get("logic_rules")?;
+        if logic_rules.is_empty() {
+            None
+        } else if logic_rules.iter().all(|rules| rules == "glitched") {
+            Some(true)
+        } else if logic_rules.iter().all(|rules| rules != "glitched") {
+            Some(false)
+        } else {
+            None
         }
     }
 
     pub fn get_exit<'a>(&'a self, from: &str, to: &'a str) -> Option<&'a str> {
-        self.exits.as_ref().map_or(Some(to), |exits| exits.get(from).and_then(|region_exits| region_exits.get(to)).map(String::as_ref))
+        self.exits.get(from).and_then(|region_exits| region_exits.get(to)).map(String::as_ref)
+    }
+
+    /// Checks that all known settings and tricks exist in the given randomizer version and have values of the correct type.
+    pub fn validate<R: Rando>(&self, rando: &R) -> Result<(), ValidationError<R>> {
+        let setting_infos = rando.setting_infos().map_err(ValidationError::Rando)?;
+        for name in self.bool_settings.keys() {
+            let info = setting_infos.get(name).ok_or_else(|| ValidationError::UnknownSetting(name.clone()))?;
+            if info.ty != SettingType::Bool {
+                return Err(ValidationError::SettingType { name: name.clone(), expected: info.ty.clone() })
+            }
+        }
+        for (name, values) in &self.string_settings {
+            let info = setting_infos.get(name).ok_or_else(|| ValidationError::UnknownSetting(name.clone()))?;
+            match info.ty {
+                SettingType::Str | SettingType::List => for value in values {
+                    if !info.allows_choice(value) {
+                        return Err(ValidationError::InvalidChoice { name: name.clone(), value: value.clone() })
+                    }
+                },
+                SettingType::Int { min, max, .. } => for value in values {
+                    match value.parse::<i64>() {
+                        Ok(n) if min.map_or(true, |min| n >= min) && max.map_or(true, |max| n <= max) => {}
+                        _ => return Err(ValidationError::InvalidChoice { name: name.clone(), value: value.clone() }),
+                    }
+                },
+                SettingType::Bool | SettingType::Other => return Err(ValidationError::SettingType { name: name.clone(), expected: info.ty.clone() }),
+            }
+        }
+        let logic_tricks = rando.logic_tricks().map_err(ValidationError::Rando)?;
+        for name in self.tricks.keys() {
+            if !logic_tricks.contains(name) {
+                return Err(ValidationError::UnknownTrick(name.clone()))
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+pub enum ValidationError<R: Rando> {
+    InvalidChoice {
+        name: String,
+        value: String,
+    },
+    Rando(R::Err),
+    SettingType {
+        name: String,
+        expected: SettingType,
+    },
+    UnknownSetting(String),
+    UnknownTrick(String),
+}
+
+impl<R: Rando> fmt::Display for ValidationError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidChoice { name, value } => write!(f, "invalid value for setting {}: {:?}", name, value),
+            ValidationError::Rando(e) => write!(f, "error loading rando data: {}", e),
+            ValidationError::SettingType { name, expected } => write!(f, "setting {} has the wrong type, expected {}", name, expected),
+            ValidationError::UnknownSetting(name) => write!(f, "unknown setting: {}", name),
+            ValidationError::UnknownTrick(name) => write!(f, "unknown trick: {}", name),
+        }
     }
 }
 
-pub enum Contradiction {
+/// The specific fact on which two pieces of knowledge disagree, along with the conflicting values.
+#[derive(Debug, Clone)]
+pub enum ContradictionKind {
     BoolSetting {
         name: String,
         lhs_enabled: bool,
@@ -217,20 +496,126 @@ pub enum Contradiction {
         lhs_location: DungeonRewardLocation,
         rhs_location: DungeonRewardLocation,
     },
+    Location {
+        location: String,
+        lhs_item: Item,
+        rhs_item: Item,
+    },
+    Exit {
+        from: String,
+        to: String,
+        lhs_target: String,
+        rhs_target: String,
+    },
+    StartingItem {
+        name: String,
+        lhs_count: u8,
+        rhs_count: u8,
+    },
+    GossipStone {
+        stone: String,
+        lhs_hint: Hint,
+        rhs_hint: Hint,
+    },
+    NpcHint {
+        npc: NpcHint,
+        lhs_hint: Hint,
+        rhs_hint: Hint,
+    },
+}
+
+impl ContradictionKind {
+    pub fn fact(&self) -> Fact {
+        match self {
+            ContradictionKind::BoolSetting { name, .. } | ContradictionKind::StringSetting { name, .. } => Fact::Setting { name: name.clone() },
+            ContradictionKind::Trick { name, .. } => Fact::Trick { name: name.clone() },
+            ContradictionKind::Mq { dungeon, .. } => Fact::Mq { dungeon: *dungeon },
+            ContradictionKind::Trial { trial, .. } => Fact::Trial { trial: *trial },
+            ContradictionKind::DungeonRewardLocation { reward, .. } => Fact::DungeonRewardLocation { reward: *reward },
+            ContradictionKind::Location { location, .. } => Fact::Location { location: location.clone() },
+            ContradictionKind::Exit { from, to, .. } => Fact::Exit { from: from.clone(), to: to.clone() },
+            ContradictionKind::StartingItem { name, .. } => Fact::StartingItem { name: name.clone() },
+            ContradictionKind::GossipStone { stone, .. } => Fact::GossipStone { stone: stone.clone() },
+            ContradictionKind::NpcHint { npc, .. } => Fact::NpcHint { npc: *npc },
+        }
+    }
+
+    /// The conflicting values of the fact, formatted for display.
+    fn values(&self) -> (String, String) {
+        fn enabled(enabled: bool) -> String { if enabled { format!("enabled") } else { format!("disabled") } }
+        fn active(active: bool) -> String { if active { format!("active") } else { format!("inactive") } }
+
+        match self {
+            ContradictionKind::BoolSetting { lhs_enabled, .. } | ContradictionKind::Trick { lhs_enabled, .. } => (enabled(*lhs_enabled), enabled(!lhs_enabled)),
+            ContradictionKind::StringSetting { lhs_values, rhs_values, .. } => (lhs_values.iter().sorted().join(" or "), rhs_values.iter().sorted().join(" or ")),
+            ContradictionKind::Mq { lhs_mq, .. } => (lhs_mq.to_string(), match lhs_mq { Mq::Vanilla => Mq::Mq, Mq::Mq => Mq::Vanilla }.to_string()),
+            ContradictionKind::Trial { lhs_active, .. } => (active(*lhs_active), active(!lhs_active)),
+            ContradictionKind::DungeonRewardLocation { lhs_location, rhs_location, .. } => (lhs_location.to_string(), rhs_location.to_string()),
+            ContradictionKind::Location { lhs_item, rhs_item, .. } => (lhs_item.name().to_owned(), rhs_item.name().to_owned()),
+            ContradictionKind::Exit { lhs_target, rhs_target, .. } => (lhs_target.clone(), rhs_target.clone()),
+            ContradictionKind::StartingItem { lhs_count, rhs_count, .. } => (lhs_count.to_string(), rhs_count.to_string()),
+            ContradictionKind::GossipStone { lhs_hint, rhs_hint, .. } | ContradictionKind::NpcHint { lhs_hint, rhs_hint, .. } => (format!("{:?}", lhs_hint), format!("{:?}", rhs_hint)),
+        }
+    }
+}
+
+/// Returned when merging two pieces of knowledge that disagree on a fact.
+#[derive(Debug, Clone)]
+pub struct Contradiction {
+    pub kind: ContradictionKind,
+    /// The source of the fact in the left-hand side of the merge, or `None` if unknown.
+    pub lhs_source: Option<Source>,
+    /// The source of the fact in the right-hand side of the merge, or `None` if unknown.
+    pub rhs_source: Option<Source>,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lhs_value, rhs_value) = self.kind.values();
+        write!(f, "contradiction for {}: {} according to ", self.kind.fact(), lhs_value)?;
+        if let Some(lhs_source) = self.lhs_source { write!(f, "{}", lhs_source)? } else { write!(f, "an unknown source")? }
+        write!(f, " but {} according to ", rhs_value)?;
+        if let Some(rhs_source) = self.rhs_source { write!(f, "{}", rhs_source) } else { write!(f, "an unknown source") }
+    }
 }
 
 impl BitAnd for Knowledge {
     type Output = Result<Knowledge, Contradiction>;
 
-    fn bitand(self, rhs: Knowledge) -> Result<Knowledge, Contradiction> {
-        let Knowledge { bool_settings, string_settings, tricks, mq, active_trials, dungeon_reward_locations, exits: _ /*TODO*/, progression_mode: _ /*TODO*/ } = self;
+    fn bitand(mut self, mut rhs: Knowledge) -> Result<Knowledge, Contradiction> {
+        let mut sources = std::mem::take(&mut self.sources);
+        let rhs_sources = std::mem::take(&mut rhs.sources);
+        match self.merge(rhs) {
+            Ok(mut knowledge) => {
+                for (fact, source) in rhs_sources {
+                    sources.entry(fact).or_insert(source);
+                }
+                knowledge.sources = sources;
+                Ok(knowledge)
+            }
+            Err(kind) => {
+                let fact = kind.fact();
+                Err(Contradiction {
+                    lhs_source: sources.get(&fact).copied(),
+                    rhs_source: rhs_sources.get(&fact).copied(),
+                    kind,
+                })
+            }
+        }
+    }
+}
+
+impl Knowledge {
+    /// Merges the facts known by `self` and `rhs`, ignoring sources.
+    fn merge(self, rhs: Knowledge) -> Result<Knowledge, ContradictionKind> {
+        let Knowledge { bool_settings, string_settings, tricks, mq, active_trials, dungeon_reward_locations, locations, exits, starting_items, progression_mode, gossip_stones, npc_hints, sources: _ } = self;
         Ok(Knowledge {
             bool_settings: {
                 let mut bool_settings = bool_settings;
                 for (name, rhs_enabled) in rhs.bool_settings {
                     if let Some(&lhs_enabled) = bool_settings.get(&name) {
                         if lhs_enabled != rhs_enabled {
-                            return Err(Contradiction::BoolSetting { name, lhs_enabled })
+                            return Err(ContradictionKind::BoolSetting { name, lhs_enabled })
                         }
                     } else {
                         bool_settings.insert(name, rhs_enabled);
@@ -244,7 +629,7 @@ impl BitAnd for Knowledge {
                     if let Some(lhs_values) = string_settings.get(&name) {
                         let values = lhs_values & &rhs_values;
                         if values.is_empty() {
-                            return Err(Contradiction::StringSetting {
+                            return Err(ContradictionKind::StringSetting {
                                 name, rhs_values,
                                 lhs_values: lhs_values.clone(),
                             })
@@ -256,51 +641,25 @@ impl BitAnd for Knowledge {
                 }
                 string_settings
             },
-            tricks: if let Some(mut tricks) = tricks {
-                if let Some(rhs_tricks) = rhs.tricks {
-                    for (name, rhs_enabled) in rhs_tricks {
-                        if let Some(&lhs_enabled) = tricks.get(&name) {
-                            if lhs_enabled != rhs_enabled {
-                                return Err(Contradiction::Trick { name, lhs_enabled })
-                            }
-                        } else {
-                            tricks.insert(name, rhs_enabled);
+            tricks: {
+                let mut tricks = tricks;
+                for (name, rhs_enabled) in rhs.tricks {
+                    if let Some(&lhs_enabled) = tricks.get(&name) {
+                        if lhs_enabled != rhs_enabled {
+                            return Err(ContradictionKind::Trick { name, lhs_enabled })
                         }
-                    }
-                    Some(tricks)
-                } else {
-                    // rhs vanilla
-                    if let Some((lhs_trick, _)) = tricks.iter().find(|&(_, &enabled)| enabled) {
-                        return Err(Contradiction::Trick {
-                            name: lhs_trick.clone(),
-                            lhs_enabled: true,
-                        })
-                    } else {
-                        None
-                    }
-                }
-            } else {
-                // lhs vanilla
-                if let Some(rhs_tricks) = rhs.tricks {
-                    if let Some((rhs_trick, _)) = rhs_tricks.iter().find(|&(_, &enabled)| enabled) {
-                        return Err(Contradiction::Trick {
-                            name: rhs_trick.clone(),
-                            lhs_enabled: false,
-                        })
                     } else {
-                        None
+                        tricks.insert(name, rhs_enabled);
                     }
-                } else {
-                    // rhs vanilla
-                    None
                 }
+                tricks
             },
             mq: {
                 let mut mq = mq;
                 for (dungeon, rhs_mq) in rhs.mq {
                     if let Some(&lhs_mq) = mq.get(&dungeon) {
                         if lhs_mq != rhs_mq {
-                            return Err(Contradiction::Mq { dungeon, lhs_mq })
+                            return Err(ContradictionKind::Mq { dungeon, lhs_mq })
                         }
                     } else {
                         mq.insert(dungeon, rhs_mq);
@@ -313,7 +672,7 @@ impl BitAnd for Knowledge {
                 for (trial, rhs_active) in rhs.active_trials {
                     if let Some(&lhs_active) = active_trials.get(&trial) {
                         if lhs_active != rhs_active {
-                            return Err(Contradiction::Trial { trial, lhs_active })
+                            return Err(ContradictionKind::Trial { trial, lhs_active })
                         }
                     } else {
                         active_trials.insert(trial, rhs_active);
@@ -326,7 +685,7 @@ impl BitAnd for Knowledge {
                 for (reward, rhs_location) in rhs.dungeon_reward_locations {
                     if let Some(&lhs_location) = dungeon_reward_locations.get(&reward) {
                         if lhs_location != rhs_location {
-                            return Err(Contradiction::DungeonRewardLocation { reward, lhs_location, rhs_location })
+                            return Err(ContradictionKind::DungeonRewardLocation { reward, lhs_location, rhs_location })
                         }
                     } else {
                         dungeon_reward_locations.insert(reward, rhs_location);
@@ -334,8 +693,76 @@ impl BitAnd for Knowledge {
                 }
                 dungeon_reward_locations
             },
-            exits: None, //TODO
-            progression_mode: ProgressionMode::Normal, //TODO this should actually be recalculated from the rest of the knowledge, use a dummy value for now
+            locations: {
+                let mut locations = locations;
+                for (location, rhs_item) in rhs.locations {
+                    if let Some(lhs_item) = locations.get(&location) {
+                        if *lhs_item != rhs_item {
+                            return Err(ContradictionKind::Location { lhs_item: lhs_item.clone(), location, rhs_item })
+                        }
+                    } else {
+                        locations.insert(location, rhs_item);
+                    }
+                }
+                locations
+            },
+            starting_items: {
+                let mut starting_items = starting_items;
+                for (name, rhs_count) in rhs.starting_items {
+                    if let Some(&lhs_count) = starting_items.get(&name) {
+                        if lhs_count != rhs_count {
+                            return Err(ContradictionKind::StartingItem { name, lhs_count, rhs_count })
+                        }
+                    } else {
+                        starting_items.insert(name, rhs_count);
+                    }
+                }
+                starting_items
+            },
+            gossip_stones: {
+                let mut gossip_stones = gossip_stones;
+                for (stone, rhs_hint) in rhs.gossip_stones {
+                    if let Some(lhs_hint) = gossip_stones.get(&stone) {
+                        if *lhs_hint != rhs_hint {
+                            return Err(ContradictionKind::GossipStone { lhs_hint: lhs_hint.clone(), stone, rhs_hint })
+                        }
+                    } else {
+                        gossip_stones.insert(stone, rhs_hint);
+                    }
+                }
+                gossip_stones
+            },
+            npc_hints: {
+                let mut npc_hints = npc_hints;
+                for (npc, rhs_hint) in rhs.npc_hints {
+                    if let Some(lhs_hint) = npc_hints.get(&npc) {
+                        if *lhs_hint != rhs_hint {
+                            return Err(ContradictionKind::NpcHint { lhs_hint: lhs_hint.clone(), npc, rhs_hint })
+                        }
+                    } else {
+                        npc_hints.insert(npc, rhs_hint);
+                    }
+                }
+                npc_hints
+            },
+            exits: {
+                let mut exits = exits;
+                for (from, rhs_region_exits) in rhs.exits {
+                    let region_exits = exits.entry(from.clone()).or_default();
+                    for (to, rhs_target) in rhs_region_exits {
+                        if let Some(lhs_target) = region_exits.get(&to) {
+                            if *lhs_target != rhs_target {
+                                return Err(ContradictionKind::Exit { lhs_target: lhs_target.clone(), from, to, rhs_target })
+                            }
+                        } else {
+                            region_exits.insert(to, rhs_target);
+                        }
+                    }
+                }
+                exits
+            },
+            progression_mode, // recalculated from the rest of the model state by ModelState::update_progression_mode
+            sources: HashMap::default(),
         })
     }
 }
@@ -346,16 +773,20 @@ impl Protocol for Knowledge {
             Ok(match u8::read(stream).await? {
                 0 => Knowledge {
                     bool_settings: HashMap::read(stream).await?,
-                    tricks: Some(HashMap::read(stream).await?),
+                    tricks: HashMap::read(stream).await?,
                     dungeon_reward_locations: HashMap::read(stream).await?,
                     mq: HashMap::read(stream).await?,
-                    exits: Some(HashMap::read(stream).await?),
+                    exits: HashMap::read(stream).await?,
                     active_trials: HashMap::read(stream).await?,
                     string_settings: HashMap::read(stream).await?,
                     progression_mode: ProgressionMode::read(stream).await?,
+                    starting_items: HashMap::read(stream).await?,
+                    gossip_stones: HashMap::read(stream).await?,
+                    npc_hints: HashMap::read(stream).await?,
+                    locations: HashMap::read(stream).await?,
+                    sources: HashMap::read(stream).await?,
                 },
                 1 => Knowledge::default(),
-                2 => Knowledge::vanilla(),
                 n => return Err(ReadError::UnknownVariant8(n)),
             })
         })
@@ -365,18 +796,21 @@ impl Protocol for Knowledge {
         Box::pin(async move {
             if *self == Knowledge::default() {
                 1u8.write(sink).await?;
-            } else if *self == Knowledge::vanilla() {
-                2u8.write(sink).await?;
             } else {
                 0u8.write(sink).await?;
                 self.bool_settings.write(sink).await?;
-                self.tricks.as_ref().expect("non-vanilla Knowledge should have Some in tricks field").write(sink).await?;
+                self.tricks.write(sink).await?;
                 self.dungeon_reward_locations.write(sink).await?;
                 self.mq.write(sink).await?;
-                self.exits.as_ref().expect("non-vanilla Knowledge should have Some in exits field").write(sink).await?;
+                self.exits.write(sink).await?;
                 self.active_trials.write(sink).await?;
                 self.string_settings.write(sink).await?;
                 self.progression_mode.write(sink).await?;
+                self.starting_items.write(sink).await?;
+                self.gossip_stones.write(sink).await?;
+                self.npc_hints.write(sink).await?;
+                self.locations.write(sink).await?;
+                self.sources.write(sink).await?;
             }
             Ok(())
         })
@@ -386,16 +820,20 @@ impl Protocol for Knowledge {
         Ok(match u8::read_sync(stream)? {
             0 => Knowledge {
                 bool_settings: HashMap::read_sync(stream)?,
-                tricks: Some(HashMap::read_sync(stream)?),
+                tricks: HashMap::read_sync(stream)?,
                 dungeon_reward_locations: HashMap::read_sync(stream)?,
                 mq: HashMap::read_sync(stream)?,
-                exits: Some(HashMap::read_sync(stream)?),
+                exits: HashMap::read_sync(stream)?,
                 active_trials: HashMap::read_sync(stream)?,
                 string_settings: HashMap::read_sync(stream)?,
                 progression_mode: ProgressionMode::read_sync(stream)?,
+                starting_items: HashMap::read_sync(stream)?,
+                gossip_stones: HashMap::read_sync(stream)?,
+                npc_hints: HashMap::read_sync(stream)?,
+                locations: HashMap::read_sync(stream)?,
+                sources: HashMap::read_sync(stream)?,
             },
             1 => Knowledge::default(),
-            2 => Knowledge::vanilla(),
             n => return Err(ReadError::UnknownVariant8(n)),
         })
     }
@@ -403,48 +841,187 @@ impl Protocol for Knowledge {
     fn write_sync(&self, sink: &mut impl Write) -> Result<(), WriteError> {
         if *self == Knowledge::default() {
             1u8.write_sync(sink)?;
-        } else if *self == Knowledge::vanilla() {
-            2u8.write_sync(sink)?;
         } else {
             0u8.write_sync(sink)?;
             self.bool_settings.write_sync(sink)?;
-            self.tricks.as_ref().expect("non-vanilla Knowledge should have Some in tricks field").write_sync(sink)?;
+            self.tricks.write_sync(sink)?;
             self.dungeon_reward_locations.write_sync(sink)?;
             self.mq.write_sync(sink)?;
-            self.exits.as_ref().expect("non-vanilla Knowledge should have Some in exits field").write_sync(sink)?;
+            self.exits.write_sync(sink)?;
             self.active_trials.write_sync(sink)?;
             self.string_settings.write_sync(sink)?;
+            self.progression_mode.write_sync(sink)?;
+            self.starting_items.write_sync(sink)?;
+            self.gossip_stones.write_sync(sink)?;
+            self.npc_hints.write_sync(sink)?;
+            self.locations.write_sync(sink)?;
+            self.sources.write_sync(sink)?;
         }
         Ok(())
     }
 }
 
+/// The entries of `new` which differ from `old`, with `None` for entries that were removed.
+fn map_delta<K: Clone + Eq + Hash, V: Clone + PartialEq>(new: &HashMap<K, V>, old: &HashMap<K, V>) -> Vec<(K, Option<V>)> {
+    new.iter()
+        .filter(|&(key, value)| old.get(key) != Some(value))
+        .map(|(key, value)| (key.clone(), Some(value.clone())))
+        .chain(old.keys().filter(|&key| !new.contains_key(key)).map(|key| (key.clone(), None)))
+        .collect()
+}
+
+fn apply_map_delta<K: Eq + Hash, V>(map: &mut HashMap<K, V>, delta: Vec<(K, Option<V>)>) {
+    for (key, value) in delta {
+        if let Some(value) = value {
+            map.insert(key, value);
+        } else {
+            map.remove(&key);
+        }
+    }
+}
+
+impl AddAssign<Delta> for Knowledge {
+    fn add_assign(&mut self, rhs: Delta) {
+        let Delta { bool_settings, string_settings, tricks, mq, active_trials, dungeon_reward_locations, locations, exits, starting_items, progression_mode, gossip_stones, npc_hints, sources } = rhs;
+        apply_map_delta(&mut self.bool_settings, bool_settings);
+        apply_map_delta(&mut self.string_settings, string_settings);
+        apply_map_delta(&mut self.tricks, tricks);
+        apply_map_delta(&mut self.mq, mq);
+        apply_map_delta(&mut self.active_trials, active_trials);
+        apply_map_delta(&mut self.dungeon_reward_locations, dungeon_reward_locations);
+        apply_map_delta(&mut self.locations, locations);
+        for (from, to, target) in exits {
+            if let Some(target) = target {
+                self.exits.entry(from).or_default().insert(to, target);
+            } else if let Some(region_exits) = self.exits.get_mut(&from) {
+                region_exits.remove(&to);
+                if region_exits.is_empty() { self.exits.remove(&from); }
+            }
+        }
+        apply_map_delta(&mut self.starting_items, starting_items);
+        if let Some(progression_mode) = progression_mode { self.progression_mode = progression_mode }
+        apply_map_delta(&mut self.gossip_stones, gossip_stones);
+        apply_map_delta(&mut self.npc_hints, npc_hints);
+        apply_map_delta(&mut self.sources, sources);
+    }
+}
+
+impl<'a, 'b> Sub<&'b Knowledge> for &'a Knowledge {
+    type Output = Delta;
+
+    fn sub(self, rhs: &Knowledge) -> Delta {
+        let Knowledge { bool_settings, string_settings, tricks, mq, active_trials, dungeon_reward_locations, locations, exits, starting_items, progression_mode, gossip_stones, npc_hints, sources } = self;
+        Delta {
+            bool_settings: map_delta(bool_settings, &rhs.bool_settings),
+            string_settings: map_delta(string_settings, &rhs.string_settings),
+            tricks: map_delta(tricks, &rhs.tricks),
+            mq: map_delta(mq, &rhs.mq),
+            active_trials: map_delta(active_trials, &rhs.active_trials),
+            dungeon_reward_locations: map_delta(dungeon_reward_locations, &rhs.dungeon_reward_locations),
+            locations: map_delta(locations, &rhs.locations),
+            exits: {
+                let mut delta = Vec::default();
+                for (from, region_exits) in exits {
+                    for (to, target) in map_delta(region_exits, rhs.exits.get(from).unwrap_or(&HashMap::default())) {
+                        delta.push((from.clone(), to, target));
+                    }
+                }
+                for (from, rhs_region_exits) in &rhs.exits {
+                    if !exits.contains_key(from) {
+                        delta.extend(rhs_region_exits.keys().map(|to| (from.clone(), to.clone(), None)));
+                    }
+                }
+                delta
+            },
+            starting_items: map_delta(starting_items, &rhs.starting_items),
+            progression_mode: (*progression_mode != rhs.progression_mode).then(|| *progression_mode),
+            gossip_stones: map_delta(gossip_stones, &rhs.gossip_stones),
+            npc_hints: map_delta(npc_hints, &rhs.npc_hints),
+            sources: map_delta(sources, &rhs.sources),
+        }
+    }
+}
+
+/// The difference between two knowledge states.
+///
+/// Each map is represented by its changed entries, with `None` for entries that were removed.
+#[derive(Debug, Clone, Protocol)]
+pub struct Delta {
+    bool_settings: Vec<(String, Option<bool>)>,
+    string_settings: Vec<(String, Option<HashSet<String>>)>,
+    tricks: Vec<(String, Option<bool>)>,
+    mq: Vec<(Dungeon, Option<Mq>)>,
+    active_trials: Vec<(Medallion, Option<bool>)>,
+    dungeon_reward_locations: Vec<(DungeonReward, Option<DungeonRewardLocation>)>,
+    locations: Vec<(String, Option<Item>)>,
+    exits: Vec<(String, String, Option<String>)>,
+    starting_items: Vec<(String, Option<u8>)>,
+    progression_mode: Option<ProgressionMode>,
+    gossip_stones: Vec<(String, Option<Hint>)>,
+    npc_hints: Vec<(NpcHint, Option<Hint>)>,
+    sources: Vec<(Fact, Option<Source>)>,
+}
+
+/// An entry in the `locations` field of a plando file.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+pub(crate) enum Placement {
+    Item(Item),
+    Detailed {
+        item: Item,
+    },
+    /// Used by older versions of the tracker for multiple dungeon rewards in Link's pocket.
+    Multiple(Vec<Item>),
+}
+
+impl Placement {
+    pub(crate) fn into_items(self) -> Vec<Item> {
+        match self {
+            Placement::Item(item) | Placement::Detailed { item } => vec![item],
+            Placement::Multiple(items) => items,
+        }
+    }
+}
+
 #[derive(Default, Deserialize, Serialize)]
 #[serde(default)]
 struct KnowledgeJson { // knowledge in what should eventually be a superset of the plando format. TODO always use this type instead of `Knowledge`
     settings: HashMap<String, Json>,
     dungeons: HashMap<String, Mq>,
     trials: HashMap<Medallion, TrialActive>,
-    entrances: HashMap<String, Vec<Entrance>>,
-    locations: HashMap<String, Vec<Item>>,
+    entrances: HashMap<String, EntranceTarget>,
+    locations: HashMap<String, Placement>,
+    starting_items: HashMap<String, u8>,
     progression_mode: ProgressionMode,
+    hints: HashMap<String, Hint>,
+    npc_hints: Vec<(NpcHint, Hint)>, // a list since NPCs with data can't be JSON object keys
+    sources: Vec<(Fact, Source)>, // a list since facts can't be JSON object keys
 }
 
 impl From<Knowledge> for KnowledgeJson {
     fn from(knowledge: Knowledge) -> Self {
-        let Knowledge { bool_settings, string_settings, tricks, mq, active_trials, dungeon_reward_locations, exits: _, progression_mode } = knowledge;
+        let Knowledge { bool_settings, string_settings, tricks, mq, active_trials, dungeon_reward_locations, locations, exits, starting_items, progression_mode, gossip_stones, npc_hints, sources } = knowledge;
         let mut settings = bool_settings.into_iter().map(|(setting, enabled)| (setting, json!(enabled))).collect::<HashMap<_, _>>();
         settings.extend(string_settings.into_iter().map(|(setting, values)| (setting, json!(values))));
         settings.insert(format!("allowed_tricks"), json!(tricks));
-        let mut locations = HashMap::<_, Vec<Item>>::new();
+        let mut rewards = HashMap::<_, Vec<Item>>::new();
         for (reward, loc) in dungeon_reward_locations {
-            locations.entry(loc.as_str().to_owned()).or_default().push(reward.into());
+            rewards.entry(loc.as_str().to_owned()).or_default().push(reward.into());
+        }
+        let mut locations = locations.into_iter().map(|(loc, item)| (loc, Placement::Item(item))).collect::<HashMap<_, _>>();
+        for (loc, mut items) in rewards {
+            locations.insert(loc, if items.len() == 1 { Placement::Item(items.remove(0)) } else { Placement::Multiple(items) });
         }
         Self {
-            settings, progression_mode, locations,
+            settings, progression_mode, locations, starting_items,
+            hints: gossip_stones,
+            npc_hints: npc_hints.into_iter().collect(),
+            sources: sources.into_iter().collect(),
             dungeons: mq.into_iter().map(|(dungeon, mq)| (dungeon.rando_name().to_owned(), mq)).collect(),
             trials: active_trials.into_iter().map(|(trial, active)| (trial, active.into())).collect(),
-            entrances: HashMap::default(), //TODO
+            entrances: exits.into_iter()
+                .flat_map(|(from, region_exits)| region_exits.into_iter().map(move |(to, target)| (format!("{} -> {}", from, to), EntranceTarget::Region(target))))
+                .collect(),
         }
     }
 }
@@ -453,9 +1030,10 @@ impl From<Knowledge> for KnowledgeJson {
 enum KnowledgeFromJsonError {
     #[from]
     Json(serde_json::Error),
+    /// Multiple items are only allowed at dungeon reward locations, for compatibility with older versions of the tracker.
+    MultipleItems(String),
     UnknownDungeon(String),
-    UnknownItem(Item),
-    UnknownLocation(String),
+    UnknownEntrance(String),
     ValueType(Json),
 }
 
@@ -463,9 +1041,9 @@ impl fmt::Display for KnowledgeFromJsonError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Json(e) => e.fmt(f),
+            Self::MultipleItems(location) => write!(f, "multiple items at {}, which is not a dungeon reward location", location),
             Self::UnknownDungeon(name) => write!(f, "unknown dungeon: {}", name),
-            Self::UnknownItem(item) => write!(f, "unknown item: {}", item.0),
-            Self::UnknownLocation(name) => write!(f, "unknown location: {}", name),
+            Self::UnknownEntrance(name) => write!(f, "entrance {:?} is not of the form \"Region -> Region\"", name),
             Self::ValueType(value) => write!(f, "unexpected JSON value type for value {}", value),
         }
     }
@@ -475,7 +1053,7 @@ impl TryFrom<KnowledgeJson> for Knowledge {
     type Error = KnowledgeFromJsonError;
 
     fn try_from(knowledge: KnowledgeJson) -> Result<Self, KnowledgeFromJsonError> {
-        let KnowledgeJson { settings, dungeons, trials, entrances: _, locations, progression_mode } = knowledge;
+        let KnowledgeJson { settings, dungeons, trials, entrances, locations, starting_items, progression_mode, hints, npc_hints, sources } = knowledge;
         let mut bool_settings = HashMap::default();
         let mut string_settings = HashMap::default();
         let mut tricks = HashMap::default();
@@ -491,26 +1069,44 @@ impl TryFrom<KnowledgeJson> for Knowledge {
             }
         }
         let mut dungeon_reward_locations = HashMap::default();
-        for (loc, items) in locations {
-            let loc = loc.parse().map_err(|()| KnowledgeFromJsonError::UnknownLocation(loc))?;
+        let mut item_locations = HashMap::default();
+        for (loc, placement) in locations {
+            let items = placement.into_items();
+            let num_items = items.len();
             for item in items {
-                let item = item.clone().try_into().map_err(|()| KnowledgeFromJsonError::UnknownItem(item))?;
-                dungeon_reward_locations.insert(item, loc);
+                if let (Ok(reward_loc), Ok(reward)) = (loc.parse::<DungeonRewardLocation>(), DungeonReward::try_from(item.clone())) {
+                    dungeon_reward_locations.insert(reward, reward_loc);
+                } else if num_items > 1 {
+                    return Err(KnowledgeFromJsonError::MultipleItems(loc))
+                } else {
+                    item_locations.insert(loc.clone(), item);
+                }
             }
         }
+        let mut exits = HashMap::<_, HashMap<_, _>>::default();
+        for (entrance, target) in entrances {
+            let (from, to) = entrance.split_once(" -> ").ok_or_else(|| KnowledgeFromJsonError::UnknownEntrance(entrance.clone()))?;
+            let target = match target {
+                EntranceTarget::Region(region) | EntranceTarget::Entrance(Entrance { region, .. }) => region,
+            };
+            exits.entry(from.to_owned()).or_default().insert(to.to_owned(), target);
+        }
         Ok(Self {
-            bool_settings, string_settings, dungeon_reward_locations, progression_mode,
+            bool_settings, string_settings, dungeon_reward_locations, exits, starting_items, progression_mode,
             mq: dungeons.into_iter().map(|(dungeon, mq)| Ok::<_, KnowledgeFromJsonError>((dungeon.parse().map_err(|()| KnowledgeFromJsonError::UnknownDungeon(dungeon))?, mq))).try_collect()?,
             active_trials: trials.into_iter().map(|(trial, active)| (trial, active.into())).collect(),
-            exits: Some(HashMap::default()), //TODO
-            tricks: Some(tricks),
+            tricks,
+            gossip_stones: hints,
+            locations: item_locations,
+            npc_hints: npc_hints.into_iter().collect(),
+            sources: sources.into_iter().collect(),
         })
     }
 }
 
 #[derive(Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
-enum TrialActive {
+pub(crate) enum TrialActive {
     Inactive,
     Active,
 }
@@ -530,8 +1126,136 @@ impl From<TrialActive> for bool {
     }
 }
 
+/// The target of an entrance as given in plando files and spoiler logs: either just the region or the region along with the region the entrance is from.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+pub(crate) enum EntranceTarget {
+    Region(String),
+    Entrance(Entrance),
+}
+
 #[derive(Deserialize, Serialize)]
-struct Entrance {
-    region: String,
-    from: String,
+pub(crate) struct Entrance {
+    pub(crate) region: String,
+    pub(crate) from: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        async_proto::Protocol as _,
+        collect_mac::collect,
+        ootr::{
+            item::Item,
+            model::{
+                DungeonReward,
+                DungeonRewardLocation,
+                Medallion,
+            },
+        },
+        crate::test_rando::TestRando,
+        super::{
+            Contradiction,
+            ContradictionKind,
+            Delta,
+            Fact,
+            Knowledge,
+            Source,
+        },
+    };
+
+    fn knowledge() -> Knowledge {
+        let mut knowledge = Knowledge::default();
+        knowledge.bool_settings.insert(format!("shuffle_kokiri_sword"), true);
+        knowledge.string_settings.insert(format!("open_forest"), collect![format!("open"), format!("closed_deku")]);
+        knowledge.locations.insert(format!("KF Midos Top Left Chest"), Item(format!("Megaton Hammer")));
+        knowledge.exits.entry(format!("Root")).or_default().insert(format!("KF Midos House"), format!("Ganons Castle Tower"));
+        knowledge.dungeon_reward_locations.insert(DungeonReward::Medallion(Medallion::Light), DungeonRewardLocation::LinksPocket);
+        knowledge.with_source(Source::Plando)
+    }
+
+    #[test]
+    fn merge() {
+        let mut rhs = Knowledge::default();
+        rhs.string_settings.insert(format!("open_forest"), collect![format!("open"), format!("closed")]);
+        rhs.tricks.insert(format!("logic_test_trick"), true);
+        let merged = (knowledge() & rhs.with_source(Source::Spoiler)).unwrap();
+        assert_eq!(merged.string_settings["open_forest"], collect![format!("open")]);
+        assert!(merged.bool_settings["shuffle_kokiri_sword"]);
+        assert!(merged.tricks["logic_test_trick"]);
+        // facts known by both sides keep the source from the left-hand side
+        assert_eq!(merged.sources[&Fact::Setting { name: format!("open_forest") }], Source::Plando);
+        assert_eq!(merged.sources[&Fact::Trick { name: format!("logic_test_trick") }], Source::Spoiler);
+    }
+
+    #[test]
+    fn contradictions() {
+        let mut rhs = Knowledge::default();
+        rhs.locations.insert(format!("KF Midos Top Left Chest"), Item(format!("Bow")));
+        let contradiction = (knowledge() & rhs.with_source(Source::AutoTracker)).unwrap_err();
+        assert!(matches!(contradiction.kind, ContradictionKind::Location { ref rhs_item, .. } if rhs_item.name() == "Bow"));
+        assert_eq!(contradiction.lhs_source, Some(Source::Plando));
+        assert_eq!(contradiction.rhs_source, Some(Source::AutoTracker));
+        assert_eq!(contradiction.to_string(), "contradiction for item at KF Midos Top Left Chest: Megaton Hammer according to {} but Bow according to {}".replacen("{}", &Source::Plando.to_string(), 1).replacen("{}", &Source::AutoTracker.to_string(), 1));
+        let mut rhs = Knowledge::default();
+        rhs.string_settings.insert(format!("open_forest"), collect![format!("closed")]);
+        assert!(matches!((knowledge() & rhs).unwrap_err(), Contradiction { kind: ContradictionKind::StringSetting { .. }, rhs_source: None, .. }));
+    }
+
+    #[test]
+    fn exits() {
+        let mut rhs = Knowledge::default();
+        rhs.exits.entry(format!("Root")).or_default().insert(format!("Kokiri Forest"), format!("Kokiri Forest"));
+        let merged = (knowledge() & rhs).unwrap();
+        assert_eq!(merged.get_exit("Root", "KF Midos House"), Some("Ganons Castle Tower"));
+        assert_eq!(merged.get_exit("Root", "Kokiri Forest"), Some("Kokiri Forest"));
+        let mut rhs = Knowledge::default();
+        rhs.exits.entry(format!("Root")).or_default().insert(format!("KF Midos House"), format!("KF Midos House"));
+        assert!(matches!((knowledge() & rhs).unwrap_err().kind, ContradictionKind::Exit { .. }));
+    }
+
+    #[test]
+    fn delta() {
+        let old = Knowledge::default();
+        let new = knowledge();
+        let mut knowledge = old.clone();
+        knowledge += &new - &old;
+        assert_eq!(knowledge, new);
+        // removed entries are removed again when applying the delta
+        knowledge += &old - &new;
+        assert_eq!(knowledge, old);
+    }
+
+    #[test]
+    fn delta_protocol() {
+        let new = knowledge();
+        let mut buf = Vec::default();
+        (&new - &Knowledge::default()).write_sync(&mut buf).unwrap();
+        let mut knowledge = Knowledge::default();
+        knowledge += Delta::read_sync(&mut &*buf).unwrap();
+        assert_eq!(knowledge, new);
+    }
+
+    #[test]
+    fn json() {
+        let knowledge = knowledge();
+        assert_eq!(serde_json::from_value::<Knowledge>(serde_json::to_value(&knowledge).unwrap()).unwrap(), knowledge);
+        assert!(serde_json::from_value::<Knowledge>(serde_json::json!({"locations": {"KF Midos Top Left Chest": ["Megaton Hammer", "Bow"]}})).is_err());
+        // multiple dungeon rewards in Link's pocket are accepted for compatibility with older versions of the tracker
+        let knowledge = serde_json::from_value::<Knowledge>(serde_json::json!({"locations": {"Links Pocket": ["Light Medallion", "Kokiri Emerald"]}})).unwrap();
+        assert_eq!(knowledge.dungeon_reward_locations.len(), 2);
+    }
+
+    #[test]
+    fn vanilla() {
+        let knowledge = Knowledge::vanilla(&TestRando).unwrap();
+        assert!(!knowledge.bool_settings["shuffle_kokiri_sword"]);
+        assert_eq!(knowledge.string_settings["open_forest"], collect![format!("closed")]);
+        assert_eq!(knowledge.string_settings["big_poe_count"], collect![format!("10")]);
+        assert!(knowledge.tricks["logic_test_trick"]);
+        assert_eq!(knowledge.get_exit("Root", "KF Midos House"), Some("KF Midos House"));
+        assert_eq!(knowledge.locations["KF Midos Top Left Chest"], Item(format!("Megaton Hammer")));
+        assert_eq!(knowledge.starting_items["Megaton Hammer"], 1);
+        knowledge.validate(&TestRando).unwrap();
+    }
 }
@@ -2,11 +2,17 @@
 #![forbid(unsafe_code)]
 
 use {
-    std::ops::{
-        AddAssign,
-        Sub,
+    std::{
+        collections::HashSet,
+        fmt,
+        iter,
+        ops::{
+            AddAssign,
+            Sub,
+        },
     },
     async_proto::Protocol,
+    derivative::Derivative,
     enum_iterator::all,
     itertools::Itertools as _,
     semver::Version,
@@ -15,16 +21,25 @@ use {
         Serialize,
     },
     ootr::{
+        Rando,
         check::Check,
         model::*,
+        region::EntrancePair,
     },
     crate::{
         info_tables::InfTable55,
+        knowledge::{
+            Contradiction,
+            Fact,
+            ProgressionMode,
+            Source,
+        },
         ram::Pad,
         save::{
             DungeonItems,
             GameMode,
         },
+        scene::Scene,
     },
 };
 pub use crate::{
@@ -37,19 +52,54 @@ pub use crate::{
 pub mod checks;
 pub mod ctx;
 #[cfg(feature = "firebase")] pub mod firebase;
+mod get_item_ids;
 pub mod github;
 pub mod info_tables;
 mod item_ids;
 pub mod knowledge;
+pub mod logic;
 pub mod net;
+pub mod plando;
 pub mod proto;
 pub mod ram;
 pub mod region;
 pub mod save;
 mod scene;
+pub mod settings_string;
+pub mod spoiler;
+#[cfg(test)] mod test_rando;
 mod text;
 pub mod ui;
+pub mod versions;
 pub mod websocket;
+pub mod weights;
+
+/// An error that can occur while updating the model state from the game.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+pub enum UpdateError<R: Rando> {
+    /// Information read from the game contradicts what's already known. The existing knowledge is kept.
+    Contradiction(Contradiction),
+    Rando(R::Err),
+    /// The progression mode could not be determined.
+    Logic(checks::CheckStatusError<R>),
+}
+
+impl<R: Rando> From<Contradiction> for UpdateError<R> {
+    fn from(e: Contradiction) -> UpdateError<R> {
+        UpdateError::Contradiction(e)
+    }
+}
+
+impl<R: Rando> fmt::Display for UpdateError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateError::Contradiction(e) => e.fmt(f),
+            UpdateError::Rando(e) => write!(f, "error loading randomizer data: {}", e),
+            UpdateError::Logic(e) => write!(f, "error determining logic: {}", e),
+        }
+    }
+}
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Protocol, Deserialize, Serialize)]
 pub struct ModelState {
@@ -59,8 +109,89 @@ pub struct ModelState {
 }
 
 impl ModelState {
-    pub fn update_knowledge(&mut self) {
-        if self.ram.save.game_mode != GameMode::Gameplay { return } //TODO read knowledge from inventory preview on file select?
+    /// Replaces the RAM state, unless the game is not in gameplay mode, and updates knowledge, including the item at the location that was just checked and the exit that was just taken.
+    ///
+    /// Once the end credits start, the progression mode is set to `Done`.
+    ///
+    /// If information read from RAM contradicts what's already known, the contradiction is returned and the existing knowledge is kept.
+    pub fn update_ram<R: Rando>(&mut self, rando: &R, ram: Ram) -> Result<(), UpdateError<R>> {
+        let prev = self.clone();
+        match ram.save.game_mode {
+            GameMode::Gameplay => self.ram = ram,
+            GameMode::EndCredits => self.knowledge.progression_mode = ProgressionMode::Done,
+            GameMode::TitleScreen | GameMode::FileSelect => {}
+        }
+        let item_res = self.learn_item_placement(rando, &prev.ram);
+        let exit_res = self.learn_exit(rando, &prev.ram);
+        let knowledge_res = self.update_knowledge(rando, &prev);
+        item_res?;
+        exit_res?;
+        knowledge_res
+    }
+
+    /// If Link is receiving an item and exactly one location has been checked since `prev_ram`, records that item as being at that location.
+    pub fn learn_item_placement<R: Rando>(&mut self, rando: &R, prev_ram: &Ram) -> Result<(), UpdateError<R>> {
+        if self.ram.get_item_id == 0 { return Ok(()) }
+        let Some(item) = get_item_ids::item_name(rando, self.ram.get_item_id.unsigned_abs()).map_err(UpdateError::Rando)? else { return Ok(()) }; //TODO report unknown get-item IDs?
+        if let Ok(location) = checks::newly_checked_locations(rando, prev_ram, &self.ram).map_err(UpdateError::Rando)?.into_iter().exactly_one() {
+            let mut new_knowledge = Knowledge::default();
+            new_knowledge.locations.insert(location.to_owned(), ootr::item::Item(item));
+            self.knowledge = (self.knowledge.clone() & new_knowledge.with_source(Source::AutoTracker))?;
+        }
+        Ok(())
+    }
+
+    /// If the scene changed since `prev_ram`, records where the exit Link just took leads, based on the entrance index the game used.
+    ///
+    /// The exit taken is only known if it's the only exit from the previous scene in the randomizer's entrance table whose target is still unknown.
+    /// Warp songs, owls, spawns, and grottos are not tracked yet.
+    ///
+    /// If the exit taken contradicts what's already known, the contradiction is returned and the existing knowledge is kept.
+    pub fn learn_exit<R: Rando>(&mut self, rando: &R, prev_ram: &Ram) -> Result<(), UpdateError<R>> {
+        if self.ram.current_scene_id == prev_ram.current_scene_id { return Ok(()) }
+        let Ok(prev_scene) = Scene::current(prev_ram) else { return Ok(()) };
+        let entrances = rando.entrances().map_err(UpdateError::Rando)?;
+        // each entrance along with the one leading back
+        let directions = entrances.iter()
+            .flat_map(|EntrancePair { forward, reverse, .. }| iter::once((forward, reverse.as_ref())).chain(reverse.as_ref().map(|reverse| (reverse, Some(forward)))))
+            .collect::<Vec<_>>();
+        let Ok((target, target_back)) = directions.iter().filter(|(entrance, _)| entrance.index == self.ram.entrance_index).exactly_one() else { return Ok(()) };
+        let regions = rando.regions(self.knowledge.glitched().unwrap_or(false)).map_err(UpdateError::Rando)?; //TODO consider both world graphs if logic rules are unknown
+        let prev_regions = regions.iter()
+            .filter(|region| region.scene.as_deref() == Some(prev_scene.0) || region.dungeon.map_or(false, |(dungeon, _)| dungeon.rando_name() == prev_scene.0))
+            .map(|region| region.name.as_ref())
+            .collect::<HashSet<_>>();
+        let mut candidates = Vec::default();
+        for (entrance, _) in &directions {
+            if !prev_regions.contains(entrance.from.as_str()) { continue }
+            match self.knowledge.exits.get(&entrance.from).and_then(|region_exits| region_exits.get(&entrance.to)) {
+                Some(known_target) if *known_target == target.to => return Ok(()), // already known
+                Some(_) => {}
+                None => candidates.push(entrance),
+            }
+        }
+        let Ok(source) = candidates.into_iter().exactly_one() else { return Ok(()) };
+        let mut new_knowledge = Knowledge::default();
+        let new_exits = &mut new_knowledge.exits;
+        new_exits.entry(source.from.clone()).or_default().insert(source.to.clone(), target.to.clone());
+        if self.knowledge.bool_settings.get("decouple_entrances") == Some(&false) {
+            if let Some(target_back) = target_back {
+                // in coupled entrance rando, the way back leads to where Link came from
+                new_exits.entry(target_back.from.clone()).or_default().insert(target_back.to.clone(), source.from.clone());
+            }
+        }
+        self.knowledge = (self.knowledge.clone() & new_knowledge.with_source(Source::AutoTracker))?;
+        Ok(())
+    }
+
+    /// Updates knowledge from the current RAM state.
+    ///
+    /// If the text currently on screen contradicts what's already known, the contradiction is returned and the existing knowledge is kept.
+    ///
+    /// `prev` is the model state from before the changes that prompted this update.
+    /// The progression mode requires a logic search, so it's only recalculated if the inventory, checked locations, or knowledge changed since then.
+    pub fn update_knowledge<R: Rando>(&mut self, rando: &R, prev: &ModelState) -> Result<(), UpdateError<R>> {
+        if self.ram.save.game_mode != GameMode::Gameplay { return Ok(()) } //TODO read knowledge from inventory preview on file select?
         // immediate knowledge
         // read dungeon reward info if the player is looking at the dungeon info screen in the pause menu
         let button_pressed = match self.tracker_ctx.cfg_dungeon_info_enable {
@@ -85,35 +216,54 @@ impl ModelState {
                 }
                 if known {
                     self.knowledge.dungeon_reward_locations.insert(reward, location);
+                    self.knowledge.sources.insert(Fact::DungeonRewardLocation { reward }, Source::TrackerCtx);
                 }
             }
         }
         // read the current text box for various pieces of information
-        if self.ram.current_text_box_id != 0 {
-            if let Ok(new_knowledge) = self.knowledge.clone() & text::read_knowledge(&self.ram.text_box_contents[..]) {
-                self.knowledge = new_knowledge;
-            } else {
-                //TODO report/log error?
-            }
-        }
+        let text_result = if self.ram.current_text_box_id != 0 {
+            // on contradiction, keep the existing knowledge and report the error once the derived knowledge below has been updated
+            (self.knowledge.clone() & text::read_knowledge(self.ram.current_text_box_id, &self.ram.text_box_contents[..])).map(|new_knowledge| self.knowledge = new_knowledge)
+        } else {
+            Ok(())
+        };
 
         // derived knowledge
         // dungeon reward shuffle doesn't exist yet, so if we have exactly 1 reward, it must have been on Links Pocket
         if let Ok(reward) = all().filter(|reward| self.ram.save.quest_items.has(reward)).exactly_one() {
             self.knowledge.dungeon_reward_locations.insert(reward, DungeonRewardLocation::LinksPocket);
+            self.knowledge.sources.entry(Fact::DungeonRewardLocation { reward }).or_insert(Source::Inferred);
         }
         // dungeon reward shuffle doesn't exist yet, so if we know the locations of all but 1 reward, the 9th can be determined by process of elimination
         if let Some((reward,)) = all().filter(|reward| !self.knowledge.dungeon_reward_locations.contains_key(reward)).collect_tuple() {
             let (dungeon,) = all().filter(|dungeon| !self.knowledge.dungeon_reward_locations.values().any(|&loc| loc == DungeonRewardLocation::Dungeon(*dungeon))).collect_tuple().expect("exactly one reward left but not exactly one reward location left");
             self.knowledge.dungeon_reward_locations.insert(reward, DungeonRewardLocation::Dungeon(dungeon));
+            self.knowledge.sources.insert(Fact::DungeonRewardLocation { reward }, Source::Inferred);
         }
+        // progression mode
+        let progression_res = if self.progression_inputs_changed(prev) { self.update_progression_mode(rando) } else { Ok(()) };
+        text_result?;
+        progression_res.map_err(UpdateError::Logic)
+    }
+
+    /// Whether anything the progression mode depends on differs from `prev`. The time of day changes constantly, so it's ignored.
+    fn progression_inputs_changed(&self, prev: &ModelState) -> bool {
+        self.knowledge != prev.knowledge
+        || Save { time_of_day: prev.ram.save.time_of_day, ..self.ram.save } != prev.ram.save
+        || self.ram.scene_flags() != prev.ram.scene_flags()
+    }
+
+    /// Recalculates `knowledge.progression_mode` from the rest of the model state, see [`logic::progression_mode`].
+    pub fn update_progression_mode<R: Rando>(&mut self, rando: &R) -> Result<(), checks::CheckStatusError<R>> {
+        self.knowledge.progression_mode = logic::progression_mode(rando, self)?;
+        Ok(())
     }
 }
 
 impl AddAssign<ModelDelta> for ModelState {
     fn add_assign(&mut self, rhs: ModelDelta) {
         let ModelDelta { knowledge, tracker_ctx, ram } = rhs;
-        self.knowledge = knowledge;
+        self.knowledge += knowledge;
         if let Some(tracker_ctx) = tracker_ctx { self.tracker_ctx = tracker_ctx }
         self.ram += ram;
     }
@@ -125,7 +275,7 @@ impl<'a, 'b> Sub<&'b ModelState> for &'a ModelState {
     fn sub(self, rhs: &ModelState) -> ModelDelta {
         let ModelState { knowledge, tracker_ctx, ram } = self;
         ModelDelta {
-            knowledge: knowledge.clone(), //TODO only include new knowledge?
+            knowledge: knowledge - &rhs.knowledge,
             tracker_ctx: (*tracker_ctx != rhs.tracker_ctx).then(|| tracker_ctx.clone()),
             ram: ram - &rhs.ram,
         }
@@ -135,7 +285,7 @@ impl<'a, 'b> Sub<&'b ModelState> for &'a ModelState {
 /// The difference between two model states.
 #[derive(Debug, Clone, Protocol)]
 pub struct ModelDelta {
-    knowledge: Knowledge, //TODO use a separate knowledge delta format?\
+    knowledge: knowledge::Delta,
     tracker_ctx: Option<TrackerCtx>,
     ram: ram::Delta,
 }
@@ -0,0 +1,502 @@
+//! Determines which checks are in logic by evaluating the randomizer's access rules against a [`ModelState`].
+
+use {
+    std::{
+        borrow::Cow,
+        collections::{
+            HashMap,
+            HashSet,
+            hash_map,
+        },
+        sync::Arc,
+    },
+    bitflags::bitflags,
+    enum_iterator::all,
+    itertools::Itertools as _,
+    ootr::{
+        Rando,
+        access::{
+            Age,
+            ArithOp,
+            CmpOp,
+            Expr,
+            LogicHelper,
+        },
+        item::Item,
+        model::{
+            DungeonReward,
+            Medallion,
+            TimeRange,
+        },
+        region::Region,
+        setting::SettingInfo,
+    },
+    crate::{
+        Check,
+        ModelState,
+        checks::{
+            CheckExt as _,
+            CheckStatus,
+            CheckStatusError,
+            CheckedState,
+        },
+        get_item_ids,
+        knowledge::ProgressionMode,
+        region::RegionExt as _,
+    },
+};
+
+/// The result of evaluating an expression. Rules can refer to information the tracker doesn't have, so any value may be `Unknown`.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    Unknown,
+}
+
+impl Value {
+    fn truthy(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            Value::Num(n) => Some(*n != 0.0),
+            Value::Str(s) => Some(!s.is_empty()),
+            Value::Unknown => None,
+        }
+    }
+}
+
+impl From<Option<bool>> for Value {
+    fn from(b: Option<bool>) -> Value {
+        b.map_or(Value::Unknown, Value::Bool)
+    }
+}
+
+bitflags! {
+    /// The time-of-day windows in which a region can be reached.
+    #[derive(Default)]
+    pub struct TimesOfDay: u8 {
+        const DAY = 0x01;
+        const NIGHT = 0x02;
+        const DAMPE = 0x04;
+        const ALL = 0x07;
+    }
+}
+
+impl From<TimeRange> for TimesOfDay {
+    fn from(range: TimeRange) -> TimesOfDay {
+        match range {
+            TimeRange::Day => TimesOfDay::DAY,
+            TimeRange::Night => TimesOfDay::NIGHT,
+            TimeRange::Dampe => TimesOfDay::DAMPE,
+        }
+    }
+}
+
+/// The regions and events which are currently in logic.
+///
+/// Child and adult access are tracked separately, as are the time-of-day windows in which each region can be reached as each age.
+pub struct Reachability<'a, R: Rando> {
+    /// Owned if items have been collected for the progression mode search, see [`progression_mode`].
+    model: Cow<'a, ModelState>,
+    logic_helpers: Arc<HashMap<String, LogicHelper>>,
+    logic_tricks: Arc<HashSet<String>>,
+    setting_infos: Arc<HashMap<String, SettingInfo>>,
+    all_regions: Vec<Arc<Region<R>>>,
+    /// `all_regions` indexed by name. Dungeon regions can have the same name in vanilla and MQ, so there may be multiple regions per name.
+    regions_by_name: HashMap<String, Vec<Arc<Region<R>>>>,
+    regions: HashMap<Arc<Region<R>>, HashMap<Age, TimesOfDay>>,
+    events: HashSet<String>,
+}
+
+impl<'a, R: Rando> Reachability<'a, R> {
+    /// Walks the region graph starting at the root region until no more regions, ages, times of day, or events become reachable.
+    pub fn new(rando: &R, model: &'a ModelState) -> Result<Reachability<'a, R>, CheckStatusError<R>> {
+        let glitched = model.knowledge.glitched().unwrap_or(false); //TODO consider both world graphs if logic rules are unknown
+        let root = Region::root(rando, glitched)?;
+        let all_regions = Region::all(rando, glitched)?.iter()
+            .filter(|region| region.dungeon.map_or(true, |(dungeon, mq)| model.knowledge.mq.get(&dungeon).map_or(true, |&known_mq| known_mq == mq)))
+            .cloned()
+            .collect_vec();
+        let mut regions_by_name = HashMap::<_, Vec<_>>::default();
+        for region in &all_regions {
+            regions_by_name.entry(region.name.as_ref().to_owned()).or_default().push(Arc::clone(region));
+        }
+        let mut reachability = Reachability {
+            logic_helpers: rando.logic_helpers().map_err(CheckStatusError::Rando)?,
+            logic_tricks: rando.logic_tricks().map_err(CheckStatusError::Rando)?,
+            setting_infos: rando.setting_infos().map_err(CheckStatusError::Rando)?,
+            regions: HashMap::default(),
+            events: HashSet::default(),
+            model: Cow::Borrowed(model),
+            all_regions, regions_by_name,
+        };
+        // The player is at the root with both ages, but the exits from the root only allow the starting age until time travel is available.
+        // The current time of day is available there since the player can savewarp.
+        let root_times = if root.time_passes {
+            TimesOfDay::ALL
+        } else {
+            all::<TimeRange>().filter(|&range| model.ram.save.time_of_day.matches(range)).map(TimesOfDay::from).collect()
+        };
+        reachability.regions.insert(root, all::<Age>().map(|age| (age, root_times)).collect());
+        reachability.expand();
+        Ok(reachability)
+    }
+
+    /// Continues walking the region graph from what's already reachable until nothing new becomes reachable.
+    ///
+    /// Collecting items can only make more things reachable, so this is used to update the reachability after collecting items instead of starting over.
+    fn expand(&mut self) {
+        loop {
+            let mut new_access = Vec::default();
+            let mut new_events = Vec::default();
+            for (region, ages) in &self.regions {
+                for (&age, &times) in ages {
+                    for (event, rule) in &region.events {
+                        if !self.events.contains(event) && !new_events.contains(event) && self.eval(rule, region, age, times).truthy() == Some(true) {
+                            new_events.push(event.clone());
+                        }
+                    }
+                    for (to, rule) in &region.exits {
+                        // unknown exits are assumed to be vanilla, so that knowledge which doesn't list any exits (e.g. the default) doesn't leave the player stuck at the root
+                        //TODO treat unknown exits as only possibly reachable once there's a distinction between definitely and possibly reachable
+                        let to = self.model.knowledge.get_exit(region.name.as_ref(), to.as_ref()).unwrap_or(to.as_ref());
+                        if self.eval(rule, region, age, times).truthy() == Some(true) {
+                            for target in self.regions_by_name.get(to).into_iter().flatten() {
+                                let target_times = if target.time_passes { TimesOfDay::ALL } else { times };
+                                if !self.times(target, age).map_or(false, |known_times| known_times.contains(target_times)) {
+                                    new_access.push((Arc::clone(target), age, target_times));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if new_access.is_empty() && new_events.is_empty() { break }
+            for (region, age, times) in new_access {
+                match self.regions.entry(region).or_default().entry(age) {
+                    hash_map::Entry::Occupied(mut entry) => entry.get_mut().insert(times),
+                    hash_map::Entry::Vacant(entry) => { entry.insert(times); }
+                }
+            }
+            self.events.extend(new_events);
+        }
+    }
+
+    /// Returns the time-of-day windows in which the given region can be reached as the given age, or `None` if it can't be reached as that age at all.
+    pub fn times(&self, region: &Region<R>, age: Age) -> Option<TimesOfDay> {
+        self.regions.get(region)?.get(&age).copied()
+    }
+
+    pub fn region_reachable(&self, region: &Region<R>, age: Age) -> bool {
+        self.times(region, age).is_some()
+    }
+
+    pub fn event_reachable(&self, event: &str) -> bool {
+        self.events.contains(event)
+    }
+
+    /// Returns the status of every check: the events, locations, and exits in the region graph, whether each dungeon is MQ, and the settings, tricks, and trials logic depends on.
+    ///
+    /// Settings, tricks, and trials can be learned at any time (e.g. from the settings string or a spoiler log), so they're reachable until known.
+    pub fn check_statuses(&self) -> HashMap<Check<R>, CheckStatus> {
+        let mut statuses = HashMap::default();
+        let mut insert = |check: Check<R>, reachable: bool| {
+            let status = if check.checked(&self.model).unwrap_or(false) {
+                CheckStatus::Checked
+            } else if reachable {
+                CheckStatus::Reachable
+            } else {
+                CheckStatus::NotYetReachable
+            };
+            // the same check can appear in multiple regions, so keep the best status
+            statuses.entry(check)
+                .and_modify(|old_status| if status < *old_status { *old_status = status })
+                .or_insert(status);
+        };
+        for region in &self.all_regions {
+            for (event, rule) in &region.events {
+                insert(Check::Event(event.clone()), self.rule_reachable(region, rule));
+            }
+            for (location, rule) in &region.locations {
+                insert(Check::Location(location.clone()), self.rule_reachable(region, rule));
+            }
+            for (to, rule) in &region.exits {
+                insert(Check::Exit { from: region.name.clone(), from_mq: region.dungeon.map(|(_, mq)| mq), to: to.clone() }, self.rule_reachable(region, rule));
+            }
+            if let Some((dungeon, _)) = region.dungeon {
+                insert(Check::Mq(dungeon), self.regions.contains_key(region));
+            }
+        }
+        for (name, info) in &*self.setting_infos {
+            insert(Check::Setting { name: name.clone(), display_name: info.display_name.clone() }, true);
+        }
+        for trick in &*self.logic_tricks {
+            insert(Check::Trick(trick.clone()), true);
+        }
+        for trial in all::<Medallion>() {
+            insert(Check::TrialActive(trial), true);
+        }
+        statuses
+    }
+
+    /// The locations which are currently in logic, including ones that have already been checked. Locations which appear in multiple regions may be listed multiple times.
+    fn reachable_locations(&self) -> impl Iterator<Item = &String> + '_ {
+        self.all_regions.iter().flat_map(move |region| region.locations.iter()
+            .filter(move |(_, rule)| self.rule_reachable(region, rule))
+            .map(|(location, _)| location)
+        )
+    }
+
+    /// Whether the given rule is satisfied in the given region as any age at which the region can currently be reached.
+    fn rule_reachable(&self, region: &Arc<Region<R>>, rule: &Expr) -> bool {
+        self.regions.get(region).map_or(false, |ages| ages.iter().any(|(&age, &times)| self.eval(rule, region, age, times).truthy() == Some(true)))
+    }
+
+    /// Evaluates the given expression in the context of the given region, reached as the given age during the given time-of-day windows.
+    fn eval(&self, expr: &Expr, region: &Region<R>, age: Age, times: TimesOfDay) -> Value {
+        let knowledge = &self.model.knowledge;
+        let save = &self.model.ram.save;
+        match expr {
+            Expr::All(exprs) => {
+                let mut result = Some(true);
+                for expr in exprs {
+                    match self.eval(expr, region, age, times).truthy() {
+                        Some(true) => {}
+                        Some(false) => return Value::Bool(false),
+                        None => result = None,
+                    }
+                }
+                result.into()
+            }
+            Expr::Any(exprs) => {
+                let mut result = Some(false);
+                for expr in exprs {
+                    match self.eval(expr, region, age, times).truthy() {
+                        Some(true) => return Value::Bool(true),
+                        Some(false) => {}
+                        None => result = None,
+                    }
+                }
+                result.into()
+            }
+            Expr::Age(expected) => Value::Bool(age == *expected),
+            Expr::Arith(lhs, op, rhs) => match (self.eval(lhs, region, age, times), self.eval(rhs, region, age, times)) {
+                (Value::Num(lhs), Value::Num(rhs)) => Value::Num(match op {
+                    ArithOp::Add => lhs + rhs,
+                    ArithOp::Sub => lhs - rhs,
+                    ArithOp::Mul => lhs * rhs,
+                    ArithOp::Div => lhs / rhs,
+                    ArithOp::FloorDiv => (lhs / rhs).floor(),
+                    ArithOp::Mod => lhs.rem_euclid(rhs),
+                }),
+                (_, _) => Value::Unknown,
+            },
+            Expr::At(at_region, expr) => {
+                let mut result = Some(false);
+                for at_region in self.regions_by_name.get(at_region).into_iter().flatten() {
+                    if let Some(&at_times) = self.regions.get(at_region).and_then(|ages| ages.get(&age)) {
+                        match self.eval(expr, at_region, age, at_times).truthy() {
+                            Some(true) => return Value::Bool(true),
+                            Some(false) => {}
+                            None => result = None,
+                        }
+                    }
+                }
+                result.into()
+            }
+            Expr::Compare(lhs, op, rhs) => match (self.eval(lhs, region, age, times), op, self.eval(rhs, region, age, times)) {
+                (Value::Unknown, _, _) | (_, _, Value::Unknown) => Value::Unknown,
+                (lhs, CmpOp::Eq, rhs) => Value::Bool(lhs == rhs),
+                (lhs, CmpOp::Ne, rhs) => Value::Bool(lhs != rhs),
+                (Value::Num(lhs), CmpOp::Lt, Value::Num(rhs)) => Value::Bool(lhs < rhs),
+                (Value::Num(lhs), CmpOp::Le, Value::Num(rhs)) => Value::Bool(lhs <= rhs),
+                (Value::Num(lhs), CmpOp::Gt, Value::Num(rhs)) => Value::Bool(lhs > rhs),
+                (Value::Num(lhs), CmpOp::Ge, Value::Num(rhs)) => Value::Bool(lhs >= rhs),
+                (_, _, _) => Value::Unknown, //TODO list settings for `in`
+            },
+            Expr::Event(event) => Value::Bool(self.events.contains(event)),
+            Expr::Here(expr) => self.eval(expr, region, age, times),
+            Expr::Item(item, count) => match (save.item_count(item), self.eval(count, region, age, times)) {
+                (Some(found), Value::Num(required)) => Value::Bool(f64::from(found) >= required),
+                (_, _) => Value::Unknown,
+            },
+            Expr::LitBool(b) => Value::Bool(*b),
+            Expr::LitFloat(n) => Value::Num(*n),
+            Expr::LitInt(n) => Value::Num(*n as f64),
+            Expr::LitStr(s) => Value::Str(s.clone()),
+            Expr::LogicHelper(name, args) => match self.logic_helpers.get(name).and_then(|helper| helper.call(args)) {
+                Some(body) => self.eval(&body, region, age, times),
+                None => Value::Unknown, // not a helper defined in LogicHelpers.json, e.g. a method of the randomizer's State class
+            },
+            Expr::Not(expr) => self.eval(expr, region, age, times).truthy().map(|b| !b).into(),
+            Expr::Param(_) => Value::Unknown, // parameters are substituted when calling a helper, so this is unbound
+            Expr::Setting(setting) => self.setting(setting),
+            Expr::StartingAge => match self.setting("starting_age") {
+                Value::Str(starting_age) => Value::Bool(starting_age == age.to_string()),
+                _ => Value::Unknown,
+            },
+            Expr::TimeOfDay(range) => Value::Bool(times.intersects(TimesOfDay::from(*range))),
+            Expr::Trick(trick) => knowledge.tricks.get(trick).copied().into(),
+            Expr::TrialActive(med) => knowledge.active_trials.get(med).copied().into(),
+        }
+    }
+
+    fn setting(&self, setting: &str) -> Value {
+        let knowledge = &self.model.knowledge;
+        if let Some(&enabled) = knowledge.bool_settings.get(setting) {
+            Value::Bool(enabled)
+        } else if let Some(values) = knowledge.string_settings.get(setting) {
+            if values.len() == 1 {
+                Value::Str(values.iter().next().expect("just checked").clone())
+            } else {
+                Value::Unknown
+            }
+        } else {
+            Value::Unknown
+        }
+    }
+}
+
+/// Returns the status of every check, using the rando data to determine which ones are currently reachable.
+pub fn check_statuses<R: Rando>(rando: &R, model: &ModelState) -> Result<HashMap<Check<R>, CheckStatus>, CheckStatusError<R>> {
+    Ok(Reachability::new(rando, model)?.check_statuses())
+}
+
+/// Determines the progression mode from the inventory, knowledge, and logic.
+///
+/// The player is in BK mode if no unchecked location is reachable, and otherwise in go mode if Ganon is reachable after collecting the items at all reachable known locations.
+/// See <https://github.com/fenhl/oottracker/issues/9#issuecomment-783503311> for a more detailed definition.
+///
+/// Logic can't be evaluated meaningfully without knowing the settings, so the progression mode is left as is if no settings are known.
+/// Beating the game is detected by [`ModelState::update_ram`] when the end credits start, so `Done` is also left as is.
+pub fn progression_mode<R: Rando>(rando: &R, model: &ModelState) -> Result<ProgressionMode, CheckStatusError<R>> {
+    if model.knowledge.progression_mode == ProgressionMode::Done { return Ok(ProgressionMode::Done) }
+    if model.knowledge.bool_settings.is_empty() && model.knowledge.string_settings.is_empty() { return Ok(model.knowledge.progression_mode) }
+    let mut reachability = Reachability::new(rando, model)?;
+    // only count locations the tracker can see being checked, otherwise locations with an unknown checked state would prevent BK mode forever
+    let unchecked = |location: &String| Check::<R>::Location(location.clone()).checked_state(model) == CheckedState::NotChecked;
+    //TODO ignore locations which can't have progression items, e.g. in areas hinted foolish
+    if !reachability.reachable_locations().any(unchecked) { return Ok(ProgressionMode::Bk) }
+    let mut known_items = model.knowledge.locations.clone();
+    known_items.extend(model.knowledge.dungeon_reward_locations.iter().map(|(&reward, location)| (location.as_str().to_owned(), Item::from(reward))));
+    loop {
+        if reachability.reachable_locations().any(|location| location == "Ganon") { return Ok(ProgressionMode::Go) }
+        // collect the items at reachable unchecked known locations and see if more becomes reachable
+        let locations = reachability.reachable_locations().filter(|location| known_items.contains_key(*location) && unchecked(*location)).cloned().collect_vec();
+        let mut collected_any = false;
+        for location in locations {
+            let Some(item) = known_items.remove(&location) else { continue }; // listed multiple times
+            let save = &mut reachability.model.to_mut().ram.save;
+            if let Ok(reward) = DungeonReward::try_from(item.clone()) {
+                save.quest_items.insert(reward.into());
+                collected_any = true;
+            } else if let Some(get_item_id) = get_item_ids::get_item_id(rando, item.name()).map_err(CheckStatusError::Rando)? {
+                collected_any |= save.recv_mw_item(get_item_id).is_ok();
+            }
+        }
+        if !collected_any { return Ok(ProgressionMode::Normal) }
+        reachability.expand();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        ootr::item::Item,
+        crate::{
+            Check,
+            ModelState,
+            checks::{
+                CheckExt as _,
+                CheckStatus,
+            },
+            knowledge::ProgressionMode,
+            ram::Ram,
+            save::GameMode,
+            test_rando::TestRando,
+        },
+        super::{
+            Reachability,
+            check_statuses,
+            progression_mode,
+        },
+    };
+
+    fn model() -> ModelState {
+        let mut model = ModelState::default();
+        model.knowledge.bool_settings.insert(format!("shuffle_kokiri_sword"), true); // the progression mode is only determined once settings are known
+        model
+    }
+
+    fn reachable(model: &ModelState, location: &str) -> bool {
+        Reachability::new(&TestRando, model).unwrap().reachable_locations().any(|reachable| reachable == location)
+    }
+
+    #[test]
+    fn unknown_exits_are_vanilla() {
+        let model = ModelState::default();
+        assert!(reachable(&model, "KF Midos Top Left Chest"));
+        assert!(!reachable(&model, "KF Midos Top Right Chest"));
+        assert!(!reachable(&model, "Ganon"));
+    }
+
+    #[test]
+    fn known_exit() {
+        let mut model = ModelState::default();
+        model.knowledge.exits.entry(format!("Root")).or_default().insert(format!("KF Midos House"), format!("Ganons Castle Tower"));
+        assert!(!reachable(&model, "KF Midos Top Left Chest"));
+        assert!(reachable(&model, "Ganon"));
+    }
+
+    #[test]
+    fn items() {
+        let mut model = ModelState::default();
+        model.ram.save.recv_mw_item(0x000d).unwrap(); // Megaton Hammer
+        assert!(reachable(&model, "KF Midos Top Right Chest"));
+        assert!(reachable(&model, "Ganon"));
+    }
+
+    #[test]
+    fn statuses() {
+        let mut model = ModelState::default();
+        model.ram.current_scene_id = 0x28;
+        model.ram.current_scene_chest_flags = 0x0000_0001;
+        let statuses = check_statuses(&TestRando, &model).unwrap();
+        assert_eq!(statuses[&Check::Location(format!("KF Midos Top Left Chest"))], CheckStatus::Checked);
+        assert_eq!(statuses[&Check::Location(format!("Test Location"))], CheckStatus::Reachable);
+        assert_eq!(statuses[&Check::Location(format!("KF Midos Top Right Chest"))], CheckStatus::NotYetReachable);
+    }
+
+    #[test]
+    fn normal() {
+        assert_eq!(progression_mode(&TestRando, &model()).unwrap(), ProgressionMode::Normal);
+    }
+
+    #[test]
+    fn bk() {
+        let mut model = model();
+        // check the only chest that's in logic
+        model.ram.current_scene_id = 0x28;
+        model.ram.current_scene_chest_flags = 0x0000_0001;
+        assert_eq!(progression_mode(&TestRando, &model).unwrap(), ProgressionMode::Bk);
+    }
+
+    #[test]
+    fn go() {
+        let mut model = model();
+        model.knowledge.locations.insert(format!("KF Midos Top Left Chest"), Item(format!("Megaton Hammer")));
+        assert_eq!(progression_mode(&TestRando, &model).unwrap(), ProgressionMode::Go);
+    }
+
+    #[test]
+    fn done() {
+        let mut model = model();
+        let mut ram = Ram::default();
+        ram.save.game_mode = GameMode::EndCredits;
+        model.update_ram(&TestRando, ram).unwrap();
+        assert_eq!(model.knowledge.progression_mode, ProgressionMode::Done);
+        assert_eq!(progression_mode(&TestRando, &model).unwrap(), ProgressionMode::Done);
+        assert_eq!(Check::<TestRando>::Location(format!("Ganon")).checked(&model), Some(true));
+    }
+}
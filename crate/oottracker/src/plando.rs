@@ -0,0 +1,264 @@
+//! Importing the randomizer's plandomizer files (also called distribution files) as [`Knowledge`].
+
+use {
+    std::{
+        collections::HashMap,
+        fmt,
+        fs::File,
+        io::{
+            self,
+            BufReader,
+        },
+        path::Path,
+        sync::Arc,
+    },
+    collect_mac::collect,
+    derivative::Derivative,
+    enum_iterator::all,
+    serde_json::Value as Json,
+    ootr::{
+        Rando,
+        model::{
+            Dungeon,
+            DungeonReward,
+            DungeonRewardLocation,
+            Medallion,
+        },
+        region::Mq,
+    },
+    crate::knowledge::{
+        Knowledge,
+        Source,
+        ValidationError,
+    },
+};
+
+/// List settings whose choices are the keys of [`Rando::starting_items`].
+const STARTING_ITEM_SETTINGS: [&str; 3] = ["starting_equipment", "starting_inventory", "starting_songs"];
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+pub enum PlandoError<R: Rando> {
+    Io(Arc<io::Error>),
+    Json(Arc<serde_json::Error>),
+    Rando(R::Err),
+    /// The plando has a field the tracker can't represent yet.
+    Unsupported {
+        field: String,
+        reason: &'static str,
+    },
+    UnknownDungeon(String),
+    UnknownEntrance(String),
+    UnknownItem(String),
+    UnknownTrial(String),
+    Validation(ValidationError<R>),
+    ValueType {
+        field: String,
+        value: Json,
+    },
+}
+
+impl<R: Rando> fmt::Display for PlandoError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlandoError::Io(e) => write!(f, "I/O error: {}", e),
+            PlandoError::Json(e) => write!(f, "JSON error: {}", e),
+            PlandoError::Rando(e) => write!(f, "error loading rando data: {}", e),
+            PlandoError::Unsupported { field, reason } => write!(f, "plando field {} is not supported: {}", field, reason),
+            PlandoError::UnknownDungeon(name) => write!(f, "unknown dungeon: {}", name),
+            PlandoError::UnknownEntrance(name) => write!(f, "entrance {:?} is not of the form \"Region -> Region\"", name),
+            PlandoError::UnknownItem(name) => write!(f, "unknown item: {}", name),
+            PlandoError::UnknownTrial(name) => write!(f, "unknown trial: {}", name),
+            PlandoError::Validation(e) => write!(f, "invalid settings in plando: {}", e),
+            PlandoError::ValueType { field, value } => write!(f, "unexpected JSON value type for plando field {}: {}", field, value),
+        }
+    }
+}
+
+/// Reads the plando file at the given path.
+pub fn load<R: Rando>(rando: &R, path: impl AsRef<Path>) -> Result<Knowledge, PlandoError<R>> {
+    let file = File::open(path).map_err(|e| PlandoError::Io(Arc::new(e)))?;
+    let json = serde_json::from_reader(BufReader::new(file)).map_err(|e| PlandoError::Json(Arc::new(e)))?;
+    from_json(rando, json)
+}
+
+/// Converts the contents of a plando file.
+pub fn from_json<R: Rando>(rando: &R, json: Json) -> Result<Knowledge, PlandoError<R>> {
+    let value_type = |field: &str, value: &Json| PlandoError::<R>::ValueType { field: field.to_owned(), value: value.clone() };
+    let fields = match json {
+        Json::Object(fields) => fields,
+        _ => return Err(value_type("", &json)),
+    };
+    let mut knowledge = Knowledge::default();
+    for (field, value) in fields {
+        if field.starts_with(':') { continue } // metadata like `:version` or `:seed`
+        match &*field {
+            "settings" | "randomized_settings" => {
+                let settings = value.as_object().ok_or_else(|| value_type(&field, &value))?;
+                for (name, value) in settings {
+                    read_setting(rando, &mut knowledge, name, value)?;
+                }
+            }
+            "dungeons" => for (name, mq) in value.as_object().ok_or_else(|| value_type(&field, &value))? {
+                let dungeon = name.parse::<Dungeon>().map_err(|()| PlandoError::UnknownDungeon(name.clone()))?;
+                let mq = serde_json::from_value::<Mq>(mq.clone()).map_err(|_| value_type(name, mq))?;
+                knowledge.mq.insert(dungeon, mq);
+            },
+            "trials" => for (name, active) in value.as_object().ok_or_else(|| value_type(&field, &value))? {
+                let trial = all::<Medallion>().find(|med| med.element() == name).ok_or_else(|| PlandoError::UnknownTrial(name.clone()))?;
+                let active = match active.as_str() {
+                    Some("active") => true,
+                    Some("inactive") => false,
+                    _ => return Err(value_type(name, active)),
+                };
+                knowledge.active_trials.insert(trial, active);
+            },
+            "entrances" => for (entrance, target) in value.as_object().ok_or_else(|| value_type(&field, &value))? {
+                let (from, to) = entrance.split_once(" -> ").ok_or_else(|| PlandoError::UnknownEntrance(entrance.clone()))?;
+                let target = match target {
+                    Json::String(region) => region.as_str(),
+                    Json::Object(target) => target.get("region").and_then(Json::as_str).ok_or_else(|| value_type(entrance, &Json::Object(target.clone())))?,
+                    _ => return Err(value_type(entrance, target)),
+                };
+                knowledge.exits
+                    .entry(from.to_owned()).or_default()
+                    .insert(to.to_owned(), target.to_owned());
+            },
+            "locations" | "songs" => for (location, item) in value.as_object().ok_or_else(|| value_type(&field, &value))? {
+                let item_name = match item {
+                    Json::String(item) => item.as_str(),
+                    Json::Object(item) => item.get("item").and_then(Json::as_str).ok_or_else(|| value_type(location, &Json::Object(item.clone())))?,
+                    _ => return Err(value_type(location, item)),
+                };
+                match (location.parse::<DungeonRewardLocation>(), item_name.parse::<DungeonReward>()) {
+                    (Ok(location), Ok(reward)) => { knowledge.dungeon_reward_locations.insert(reward, location); }
+                    (_, _) if item_name.starts_with('#') => return Err(PlandoError::Unsupported { field: format!("{}.{}", field, location), reason: "item groups can't be tracked" }),
+                    (_, _) => {
+                        let item = rando.item_table().map_err(PlandoError::Rando)?.get(item_name).cloned().ok_or_else(|| PlandoError::UnknownItem(item_name.to_owned()))?;
+                        knowledge.locations.insert(location.clone(), item);
+                    }
+                }
+            },
+            "starting_items" => read_starting_items::<R>(&mut knowledge, &field, &value)?,
+            "item_pool" => return Err(PlandoError::Unsupported { field, reason: "the item pool can't be tracked" }),
+            "gossip_stones" => return Err(PlandoError::Unsupported { field, reason: "hints can't be tracked" }),
+            "empty_dungeons" => return Err(PlandoError::Unsupported { field, reason: "empty dungeons can't be tracked" }),
+            _ => return Err(PlandoError::Unsupported { field, reason: "unknown plando field" }),
+        }
+    }
+    knowledge.validate(rando).map_err(PlandoError::Validation)?;
+    Ok(knowledge.with_source(Source::Plando))
+}
+
+pub(crate) fn read_setting<R: Rando>(rando: &R, knowledge: &mut Knowledge, name: &str, value: &Json) -> Result<(), PlandoError<R>> {
+    let value_type = || PlandoError::<R>::ValueType { field: format!("settings.{}", name), value: value.clone() };
+    match (name, value) {
+        ("allowed_tricks", Json::Array(tricks)) => {
+            let mut known_tricks = rando.logic_tricks().map_err(PlandoError::Rando)?.iter().map(|trick| (trick.clone(), false)).collect::<HashMap<_, _>>();
+            for trick in tricks {
+                known_tricks.insert(trick.as_str().ok_or_else(value_type)?.to_owned(), true);
+            }
+            knowledge.tricks = known_tricks;
+        }
+        ("starting_items", _) => read_starting_items::<R>(knowledge, &format!("settings.{}", name), value)?,
+        (_, Json::Array(values)) if STARTING_ITEM_SETTINGS.contains(&name) => {
+            let starting_items = rando.starting_items().map_err(PlandoError::Rando)?;
+            for value in values {
+                let choice = value.as_str().ok_or_else(value_type)?;
+                let item = starting_items.get(choice).ok_or_else(|| PlandoError::UnknownItem(choice.to_owned()))?;
+                *knowledge.starting_items.entry(item.name().to_owned()).or_default() += 1;
+            }
+        }
+        (_, Json::Bool(enabled)) => { knowledge.bool_settings.insert(name.to_owned(), *enabled); }
+        (_, Json::String(value)) => { knowledge.string_settings.insert(name.to_owned(), collect![value.clone()]); }
+        (_, Json::Number(value)) => { knowledge.string_settings.insert(name.to_owned(), collect![value.to_string()]); }
+        (_, Json::Array(_)) => return Err(PlandoError::Unsupported { field: format!("settings.{}", name), reason: "list settings can't be tracked" }),
+        (_, _) => return Err(value_type()),
+    }
+    Ok(())
+}
+
+fn read_starting_items<R: Rando>(knowledge: &mut Knowledge, field: &str, value: &Json) -> Result<(), PlandoError<R>> {
+    let value_type = |value: &Json| PlandoError::<R>::ValueType { field: field.to_owned(), value: value.clone() };
+    for (item, count) in value.as_object().ok_or_else(|| value_type(value))? {
+        let count = count.as_u64().and_then(|count| u8::try_from(count).ok()).ok_or_else(|| value_type(count))?;
+        *knowledge.starting_items.entry(item.clone()).or_default() += count;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        collect_mac::collect,
+        serde_json::json,
+        ootr::{
+            item::Item,
+            model::{
+                Dungeon,
+                DungeonReward,
+                DungeonRewardLocation,
+                MainDungeon,
+                Medallion,
+            },
+            region::Mq,
+        },
+        crate::{
+            knowledge::{
+                Fact,
+                Source,
+                ValidationError,
+            },
+            test_rando::TestRando,
+        },
+        super::{
+            PlandoError,
+            from_json,
+        },
+    };
+
+    #[test]
+    fn fields() {
+        let knowledge = from_json(&TestRando, json!({
+            ":version": "6.2.0 f.LUM",
+            "settings": {
+                "open_forest": "open",
+                "shuffle_kokiri_sword": true,
+                "big_poe_count": 3,
+                "allowed_tricks": ["logic_test_trick"],
+                "starting_inventory": ["megaton_hammer"],
+            },
+            "dungeons": {"Forest Temple": "mq"},
+            "trials": {"Forest": "active", "Light": "inactive"},
+            "entrances": {"Root -> KF Midos House": {"region": "Ganons Castle Tower", "from": "KF Midos House"}},
+            "locations": {
+                "KF Midos Top Left Chest": "Megaton Hammer",
+                "Links Pocket": {"item": "Light Medallion"},
+            },
+        })).unwrap();
+        assert_eq!(knowledge.string_settings["open_forest"], collect![format!("open")]);
+        assert!(knowledge.bool_settings["shuffle_kokiri_sword"]);
+        assert_eq!(knowledge.string_settings["big_poe_count"], collect![format!("3")]);
+        assert!(knowledge.tricks["logic_test_trick"]);
+        assert_eq!(knowledge.starting_items["Megaton Hammer"], 1);
+        assert_eq!(knowledge.mq[&Dungeon::Main(MainDungeon::ForestTemple)], Mq::Mq);
+        assert!(knowledge.active_trials[&Medallion::Forest]);
+        assert!(!knowledge.active_trials[&Medallion::Light]);
+        assert_eq!(knowledge.get_exit("Root", "KF Midos House"), Some("Ganons Castle Tower"));
+        assert_eq!(knowledge.locations["KF Midos Top Left Chest"], Item(format!("Megaton Hammer")));
+        assert_eq!(knowledge.dungeon_reward_locations[&DungeonReward::Medallion(Medallion::Light)], DungeonRewardLocation::LinksPocket);
+        assert_eq!(knowledge.sources[&Fact::Location { location: format!("KF Midos Top Left Chest") }], Source::Plando);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(from_json(&TestRando, json!([])), Err(PlandoError::ValueType { .. })));
+        assert!(matches!(from_json(&TestRando, json!({"item_pool": {}})), Err(PlandoError::Unsupported { .. })));
+        assert!(matches!(from_json(&TestRando, json!({"locations": {"KF Midos Top Left Chest": "#Junk"}})), Err(PlandoError::Unsupported { .. })));
+        assert!(matches!(from_json(&TestRando, json!({"locations": {"KF Midos Top Left Chest": "Triforce Piece"}})), Err(PlandoError::UnknownItem(_))));
+        assert!(matches!(from_json(&TestRando, json!({"entrances": {"Root": "KF Midos House"}})), Err(PlandoError::UnknownEntrance(_))));
+        assert!(matches!(from_json(&TestRando, json!({"dungeons": {"Forest Temple": "master"}})), Err(PlandoError::ValueType { .. })));
+        assert!(matches!(from_json(&TestRando, json!({"settings": {"bridge": "open"}})), Err(PlandoError::Validation(ValidationError::UnknownSetting(_)))));
+        assert!(matches!(from_json(&TestRando, json!({"settings": {"open_forest": "closed_fountain"}})), Err(PlandoError::Validation(ValidationError::InvalidChoice { .. }))));
+    }
+}
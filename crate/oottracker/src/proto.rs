@@ -25,7 +25,7 @@ use {
 };
 
 pub const TCP_PORT: u16 = 24801;
-pub const VERSION: u8 = 5;
+pub const VERSION: u8 = 14;
 
 #[derive(Debug, Clone, Protocol)]
 pub enum Packet {
@@ -33,6 +33,7 @@ pub enum Packet {
     SaveDelta(save::Delta),
     SaveInit(save::Save),
     KnowledgeInit(knowledge::Knowledge),
+    KnowledgeDelta(knowledge::Delta),
     RamInit(Ram),
     UpdateCell(TrackerCellId, Json),
     ModelInit(ModelState),
@@ -46,7 +46,7 @@ use {
 };
 
 pub const SIZE: usize = 0x80_0000;
-pub const NUM_RANGES: usize = 8;
+pub const NUM_RANGES: usize = 10;
 pub const TEXT_LEN: usize = 0xc0;
 pub const PAUSE_CTX_LEN: usize = 0x16;
 pub static RANGES: [u32; NUM_RANGES * 2] = [
@@ -58,6 +58,8 @@ pub static RANGES: [u32; NUM_RANGES * 2] = [
     0x1d8870, 2, // current text box ID
     0x1d887e, TEXT_LEN as u32, // current/most recent text box contents
     0x1d8dd4, PAUSE_CTX_LEN as u32, // relevant parts of z64_game.pause_ctxt
+    0x1dae54, 2, // z64_link.get_item_id
+    save::ADDR + 2, 2, // z64_file.entrance_index (only the lower half is read since the upper half is always 0)
 ];
 
 #[derive(Debug, From, Clone)]
@@ -130,6 +132,10 @@ pub struct Ram {
     pub pause_state: u16,
     pub pause_changing: bool,
     pub pause_screen_idx: u16,
+    /// The get-item ID of the item Link is currently receiving, negative while opening a chest, or 0 if none.
+    pub get_item_id: i16,
+    /// The entrance index of the most recent scene transition, spawn, or warp.
+    pub entrance_index: u16,
 }
 
 impl Default for Ram {
@@ -146,6 +152,8 @@ impl Default for Ram {
             pause_state: 0,
             pause_changing: false,
             pause_screen_idx: 0,
+            get_item_id: 0,
+            entrance_index: 0,
         }
     }
 }
@@ -163,6 +171,8 @@ impl Ram {
         pause_state: &[u8],
         pause_changing: &[u8],
         pause_screen_idx: &[u8],
+        get_item_id: &[u8],
+        entrance_index: &[u8],
     ) -> Result<Self, DecodeError> {
         Ok(Self {
             save: Save::from_save_data(save)?,
@@ -176,6 +186,8 @@ impl Ram {
             pause_state: BigEndian::read_u16(pause_state),
             pause_changing: BigEndian::read_u16(pause_changing) != 0,
             pause_screen_idx: BigEndian::read_u16(pause_screen_idx),
+            get_item_id: BigEndian::read_i16(get_item_id),
+            entrance_index: BigEndian::read_u16(entrance_index),
         })
     }
 
@@ -189,6 +201,8 @@ impl Ram {
             current_text_box_id,
             text_box_contents,
             pause_ctx,
+            get_item_id,
+            entrance_index,
         )) = ranges.into_iter().collect_tuple() {
             let current_scene_id = match current_scene_id[..] {
                 [current_scene_id] => current_scene_id,
@@ -207,6 +221,8 @@ impl Ram {
                 pause_ctx.get(0x00..0x02).ok_or(DecodeError::Index(RANGES[12]))?,
                 pause_ctx.get(0x10..0x12).ok_or(DecodeError::Index(RANGES[12]))?,
                 pause_ctx.get(0x14..0x16).ok_or(DecodeError::Index(RANGES[12]))?,
+                &get_item_id,
+                &entrance_index,
             )?)
         } else {
             Err(DecodeError::Ranges)
@@ -223,6 +239,8 @@ impl Ram {
             current_text_box_id,
             text_box_contents,
             pause_ctx,
+            get_item_id,
+            entrance_index,
         )) = ranges.into_iter().map(Borrow::borrow).collect_tuple() {
             let (chest_flags, room_clear_flags) = chest_and_room_clear.split_at(4);
             Ok(Self::new(
@@ -237,6 +255,8 @@ impl Ram {
                 pause_ctx.get(0x00..0x02).ok_or(DecodeError::Index(RANGES[12]))?,
                 pause_ctx.get(0x10..0x12).ok_or(DecodeError::Index(RANGES[12]))?,
                 pause_ctx.get(0x14..0x16).ok_or(DecodeError::Index(RANGES[12]))?,
+                get_item_id,
+                entrance_index,
             )?)
         } else {
             Err(DecodeError::Ranges)
@@ -272,6 +292,8 @@ impl Ram {
             self.current_text_box_id.to_be_bytes().into(),
             self.text_box_contents.into(),
             pause_ctx,
+            self.get_item_id.to_be_bytes().into(),
+            self.entrance_index.to_be_bytes().into(),
         ]
     }
 
@@ -339,9 +361,11 @@ impl Protocol for Ram {
 
 impl AddAssign<Delta> for Ram {
     fn add_assign(&mut self, rhs: Delta) {
-        let Delta { save, input_p1_raw_pad, current_scene_data, text_box_data, pause_data } = rhs;
+        let Delta { save, input_p1_raw_pad, current_scene_data, text_box_data, pause_data, get_item_id, entrance_index } = rhs;
         self.save = &self.save + &save;
         self.input_p1_raw_pad = input_p1_raw_pad;
+        self.get_item_id = get_item_id;
+        self.entrance_index = entrance_index;
         if let Some((current_scene_id, current_scene_switch_flags, current_scene_chest_flags, current_scene_room_clear_flags)) = current_scene_data {
             self.current_scene_id = current_scene_id;
             self.current_scene_switch_flags = current_scene_switch_flags;
@@ -364,10 +388,10 @@ impl<'a, 'b> Sub<&'b Ram> for &'a Ram {
     type Output = Delta;
 
     fn sub(self, rhs: &Ram) -> Delta {
-        let Ram { ref save, input_p1_raw_pad, current_scene_id, current_scene_switch_flags, current_scene_chest_flags, current_scene_room_clear_flags, current_text_box_id, text_box_contents, pause_state, pause_changing, pause_screen_idx } = *self;
+        let Ram { ref save, input_p1_raw_pad, current_scene_id, current_scene_switch_flags, current_scene_chest_flags, current_scene_room_clear_flags, current_text_box_id, text_box_contents, pause_state, pause_changing, pause_screen_idx, get_item_id, entrance_index } = *self;
         Delta {
             save: save - &rhs.save,
-            input_p1_raw_pad,
+            input_p1_raw_pad, get_item_id, entrance_index,
             current_scene_data: if current_scene_id == rhs.current_scene_id
                 && current_scene_switch_flags == rhs.current_scene_switch_flags
                 && current_scene_chest_flags == rhs.current_scene_chest_flags
@@ -392,6 +416,8 @@ pub struct Delta {
     current_scene_data: Option<(u8, u32, u32, u32)>,
     text_box_data: Option<(u16, [u8; TEXT_LEN])>,
     pause_data: Option<(u16, bool, u16)>,
+    get_item_id: i16,
+    entrance_index: u16,
 }
 
 impl From<Ram> for Vec<Vec<u8>> {
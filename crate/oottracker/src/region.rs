@@ -86,9 +86,9 @@ impl<R: Rando> RegionLookup<R> {
         })
     }
 
-    pub fn by_name<N: ?Sized>(rando: &R, name: &N) -> Result<RegionLookup<R>, RegionLookupError<R>>
+    pub fn by_name<N: ?Sized>(rando: &R, name: &N, glitched: bool) -> Result<RegionLookup<R>, RegionLookupError<R>>
     where R::RegionName: PartialEq<N> {
-        let all_regions = rando.regions().map_err(RegionLookupError::Rando)?;
+        let all_regions = rando.regions(glitched).map_err(RegionLookupError::Rando)?;
         let candidates = all_regions.iter().filter(|region| region.name == *name).cloned().collect_vec();
         RegionLookup::new(candidates)
     }
@@ -108,25 +108,25 @@ impl std::error::Error for MissingRegionError {}
 pub trait RegionExt {
     type R: Rando;
 
-    fn new<'a, N: ?Sized>(rando: &'a Self::R, name: &N) -> Result<RegionLookup<Self::R>, RegionLookupError<Self::R>> where <Self::R as Rando>::RegionName: PartialEq<N>;
+    fn new<'a, N: ?Sized>(rando: &'a Self::R, name: &N, glitched: bool) -> Result<RegionLookup<Self::R>, RegionLookupError<Self::R>> where <Self::R as Rando>::RegionName: PartialEq<N>;
     /// A thin wrapper around [`Rando::regions`] with this module's error type.
-    fn all<'a>(rando: &'a Self::R) -> Result<Arc<Vec<Arc<Region<Self::R>>>>, RegionLookupError<Self::R>>;
-    fn root(rando: &Self::R) -> Result<Arc<Region<Self::R>>, RegionLookupError<Self::R>>; //TODO glitched param
+    fn all<'a>(rando: &'a Self::R, glitched: bool) -> Result<Arc<Vec<Arc<Region<Self::R>>>>, RegionLookupError<Self::R>>;
+    fn root(rando: &Self::R, glitched: bool) -> Result<Arc<Region<Self::R>>, RegionLookupError<Self::R>>;
 }
 
 impl<R: Rando> RegionExt for Region<R> {
     type R = R;
 
-    fn new<'a, N: ?Sized>(rando: &'a R, name: &N) -> Result<RegionLookup<R>, RegionLookupError<R>>
+    fn new<'a, N: ?Sized>(rando: &'a R, name: &N, glitched: bool) -> Result<RegionLookup<R>, RegionLookupError<R>>
     where R::RegionName: PartialEq<N> {
-        RegionLookup::by_name(rando, name)
+        RegionLookup::by_name(rando, name, glitched)
     }
 
-    fn all<'a>(rando: &'a R) -> Result<Arc<Vec<Arc<Region<R>>>>, RegionLookupError<R>> {
-        rando.regions().map_err(RegionLookupError::Rando)
+    fn all<'a>(rando: &'a R, glitched: bool) -> Result<Arc<Vec<Arc<Region<R>>>>, RegionLookupError<R>> {
+        rando.regions(glitched).map_err(RegionLookupError::Rando)
     }
 
-    fn root(rando: &R) -> Result<Arc<Region<R>>, RegionLookupError<R>> {
-        Ok(Arc::clone(Region::all(rando)?.iter().find(|region| region.name == "Root").ok_or(RegionLookupError::NotFound)?))
+    fn root(rando: &R, glitched: bool) -> Result<Arc<Region<R>>, RegionLookupError<R>> {
+        Ok(Arc::clone(Region::all(rando, glitched)?.iter().find(|region| region.name == "Root").ok_or(RegionLookupError::NotFound)?))
     }
 }
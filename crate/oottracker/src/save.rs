@@ -27,13 +27,16 @@ use {
         AsyncWrite,
         AsyncWriteExt as _,
     },
-    ootr::model::{
-        Dungeon,
-        DungeonReward,
-        MainDungeon,
-        Medallion,
-        Stone,
-        TimeRange,
+    ootr::{
+        item::Item,
+        model::{
+            Dungeon,
+            DungeonReward,
+            MainDungeon,
+            Medallion,
+            Stone,
+            TimeRange,
+        },
     },
     crate::{
         info_tables::{
@@ -1004,6 +1007,8 @@ pub enum GameMode {
     Gameplay,
     TitleScreen,
     FileSelect,
+    /// The ending, which only plays after Ganon has been defeated.
+    EndCredits,
 }
 
 impl TryFrom<Vec<u8>> for GameMode {
@@ -1014,6 +1019,7 @@ impl TryFrom<Vec<u8>> for GameMode {
             [0, 0, 0, 0] => GameMode::Gameplay,
             [0, 0, 0, 1] => GameMode::TitleScreen,
             [0, 0, 0, 2] => GameMode::FileSelect,
+            [0, 0, 0, 3] => GameMode::EndCredits,
             _ => return Err(raw_data),
         })
     }
@@ -1025,6 +1031,7 @@ impl<'a> From<&'a GameMode> for [u8; 4] {
             GameMode::Gameplay => [0, 0, 0, 0],
             GameMode::TitleScreen => [0, 0, 0, 1],
             GameMode::FileSelect => [0, 0, 0, 2],
+            GameMode::EndCredits => [0, 0, 0, 3],
         }
     }
 }
@@ -1474,6 +1481,147 @@ impl Save {
         }
         Ok(())
     }
+
+    /// Returns how many copies of the given item the player has, or `None` if this can't be determined from the save data.
+    ///
+    /// Item names are as in the randomizer's `ItemList.py`.
+    pub fn item_count(&self, item: &Item) -> Option<u8> {
+        fn has(b: bool) -> u8 { if b { 1 } else { 0 } }
+
+        if let Ok(reward) = item.name().parse::<DungeonReward>() { return Some(has(self.quest_items.has(reward))) }
+        Some(match item.name() {
+            "Progressive Hookshot" => match self.inv.hookshot {
+                Hookshot::None => 0,
+                Hookshot::Hookshot => 1,
+                Hookshot::Longshot => 2,
+            },
+            "Progressive Strength Upgrade" => match self.upgrades.strength() {
+                Upgrades::GORON_BRACELET => 1,
+                Upgrades::SILVER_GAUNTLETS => 2,
+                Upgrades::GOLD_GAUNTLETS => 3,
+                _ => 0,
+            },
+            "Progressive Scale" => match self.upgrades.scale() {
+                Upgrades::SILVER_SCALE => 1,
+                Upgrades::GOLD_SCALE => 2,
+                _ => 0,
+            },
+            "Progressive Wallet" => match self.upgrades.wallet() {
+                Upgrades::ADULTS_WALLET => 1,
+                Upgrades::GIANTS_WALLET => 2,
+                Upgrades::TYCOONS_WALLET => 3,
+                _ => 0,
+            },
+            "Bomb Bag" => match self.upgrades.bomb_bag() {
+                Upgrades::BOMB_BAG_20 => 1,
+                Upgrades::BOMB_BAG_30 => 2,
+                Upgrades::BOMB_BAG_40 => 3,
+                _ => 0,
+            },
+            "Bow" => match self.upgrades.quiver() {
+                Upgrades::QUIVER_30 => 1,
+                Upgrades::QUIVER_40 => 2,
+                Upgrades::QUIVER_50 => 3,
+                _ => has(self.inv.bow),
+            },
+            "Slingshot" => match self.upgrades.bullet_bag() {
+                Upgrades::BULLET_BAG_30 => 1,
+                Upgrades::BULLET_BAG_40 => 2,
+                Upgrades::BULLET_BAG_50 => 3,
+                _ => has(self.inv.slingshot),
+            },
+            "Deku Nut Capacity" => match self.upgrades.nut_capacity() {
+                Upgrades::DEKU_NUT_CAPACITY_30 => 1,
+                Upgrades::DEKU_NUT_CAPACITY_40 => 2,
+                _ => 0,
+            },
+            "Deku Stick Capacity" => match self.upgrades.stick_capacity() {
+                Upgrades::DEKU_STICK_CAPACITY_20 => 1,
+                Upgrades::DEKU_STICK_CAPACITY_30 => 2,
+                _ => 0,
+            },
+            "Magic Meter" => match self.magic {
+                MagicCapacity::None => 0,
+                MagicCapacity::Small => 1,
+                MagicCapacity::Large => 2,
+            },
+            "Ocarina" => match self.inv.ocarina {
+                Ocarina::None => 0,
+                Ocarina::FairyOcarina => 1,
+                Ocarina::OcarinaOfTime => 2,
+            },
+            "Boomerang" => has(self.inv.boomerang),
+            "Lens of Truth" => has(self.inv.lens),
+            "Megaton Hammer" => has(self.inv.hammer),
+            "Magic Bean" => has(self.inv.beans), //TODO number of beans
+            "Bombchus" => has(self.inv.bombchus),
+            "Fire Arrows" => has(self.inv.fire_arrows),
+            "Ice Arrows" => has(self.inv.ice_arrows),
+            "Light Arrows" => has(self.inv.light_arrows),
+            "Dins Fire" => has(self.inv.dins_fire),
+            "Farores Wind" => has(self.inv.farores_wind),
+            "Nayrus Love" => has(self.inv.nayrus_love),
+            "Bottle" => self.inv.emptiable_bottles(),
+            "Rutos Letter" => has(self.inv.has_rutos_letter()),
+            "Kokiri Sword" => has(self.equipment.contains(Equipment::KOKIRI_SWORD)),
+            "Giants Knife" => has(self.equipment.contains(Equipment::GIANTS_KNIFE)),
+            "Biggoron Sword" => has(self.biggoron_sword),
+            "Deku Shield" => has(self.equipment.contains(Equipment::DEKU_SHIELD)),
+            "Hylian Shield" => has(self.equipment.contains(Equipment::HYLIAN_SHIELD)),
+            "Mirror Shield" => has(self.equipment.contains(Equipment::MIRROR_SHIELD)),
+            "Goron Tunic" => has(self.equipment.contains(Equipment::GORON_TUNIC)),
+            "Zora Tunic" => has(self.equipment.contains(Equipment::ZORA_TUNIC)),
+            "Iron Boots" => has(self.equipment.contains(Equipment::IRON_BOOTS)),
+            "Hover Boots" => has(self.equipment.contains(Equipment::HOVER_BOOTS)),
+            "Stone of Agony" => has(self.quest_items.contains(QuestItems::STONE_OF_AGONY)),
+            "Gerudo Membership Card" => has(self.quest_items.contains(QuestItems::GERUDO_CARD)),
+            "Zeldas Lullaby" => has(self.quest_items.contains(QuestItems::ZELDAS_LULLABY)),
+            "Eponas Song" => has(self.quest_items.contains(QuestItems::EPONAS_SONG)),
+            "Sarias Song" => has(self.quest_items.contains(QuestItems::SARIAS_SONG)),
+            "Suns Song" => has(self.quest_items.contains(QuestItems::SUNS_SONG)),
+            "Song of Time" => has(self.quest_items.contains(QuestItems::SONG_OF_TIME)),
+            "Song of Storms" => has(self.quest_items.contains(QuestItems::SONG_OF_STORMS)),
+            "Minuet of Forest" => has(self.quest_items.contains(QuestItems::MINUET_OF_FOREST)),
+            "Bolero of Fire" => has(self.quest_items.contains(QuestItems::BOLERO_OF_FIRE)),
+            "Serenade of Water" => has(self.quest_items.contains(QuestItems::SERENADE_OF_WATER)),
+            "Requiem of Spirit" => has(self.quest_items.contains(QuestItems::REQUIEM_OF_SPIRIT)),
+            "Nocturne of Shadow" => has(self.quest_items.contains(QuestItems::NOCTURNE_OF_SHADOW)),
+            "Prelude of Light" => has(self.quest_items.contains(QuestItems::PRELUDE_OF_LIGHT)),
+            "Gold Skulltula Token" => self.skull_tokens,
+            "Triforce Piece" => self.triforce_pieces(),
+            // trade items are only known while they're in the inventory
+            "Weird Egg" => has(self.inv.child_trade_item == ChildTradeItem::WeirdEgg),
+            "Zeldas Letter" => has(self.inv.child_trade_item == ChildTradeItem::ZeldasLetter),
+            "Pocket Egg" => has(self.inv.adult_trade_item == AdultTradeItem::PocketEgg),
+            "Pocket Cucco" => has(self.inv.adult_trade_item == AdultTradeItem::PocketCucco),
+            "Cojiro" => has(self.inv.adult_trade_item == AdultTradeItem::Cojiro),
+            "Odd Mushroom" => has(self.inv.adult_trade_item == AdultTradeItem::OddMushroom),
+            "Odd Potion" => has(self.inv.adult_trade_item == AdultTradeItem::OddPotion),
+            "Poachers Saw" => has(self.inv.adult_trade_item == AdultTradeItem::PoachersSaw),
+            "Broken Sword" => has(self.inv.adult_trade_item == AdultTradeItem::BrokenSword),
+            "Prescription" => has(self.inv.adult_trade_item == AdultTradeItem::Prescription),
+            "Eyeball Frog" => has(self.inv.adult_trade_item == AdultTradeItem::EyeballFrog),
+            "Eyedrops" => has(self.inv.adult_trade_item == AdultTradeItem::Eyedrops),
+            "Claim Check" => has(self.inv.adult_trade_item == AdultTradeItem::ClaimCheck),
+            // the save file only has the current number of small keys, not the number collected
+            "Small Key (Forest Temple)" => self.small_keys.forest_temple,
+            "Small Key (Fire Temple)" => self.small_keys.fire_temple,
+            "Small Key (Water Temple)" => self.small_keys.water_temple,
+            "Small Key (Spirit Temple)" => self.small_keys.spirit_temple,
+            "Small Key (Shadow Temple)" => self.small_keys.shadow_temple,
+            "Small Key (Bottom of the Well)" => self.small_keys.bottom_of_the_well,
+            "Small Key (Gerudo Training Ground)" => self.small_keys.gerudo_training_ground,
+            "Small Key (Thieves Hideout)" => self.small_keys.thieves_hideout,
+            "Small Key (Ganons Castle)" => self.small_keys.ganons_castle,
+            "Boss Key (Forest Temple)" => has(self.dungeon_items.forest_temple.contains(DungeonItems::BOSS_KEY)),
+            "Boss Key (Fire Temple)" => has(self.dungeon_items.fire_temple.contains(DungeonItems::BOSS_KEY)),
+            "Boss Key (Water Temple)" => has(self.dungeon_items.water_temple.contains(DungeonItems::BOSS_KEY)),
+            "Boss Key (Spirit Temple)" => has(self.dungeon_items.spirit_temple.contains(DungeonItems::BOSS_KEY)),
+            "Boss Key (Shadow Temple)" => has(self.dungeon_items.shadow_temple.contains(DungeonItems::BOSS_KEY)),
+            "Boss Key (Ganons Castle)" => has(self.dungeon_items.ganons_castle.contains(DungeonItems::BOSS_KEY)),
+            _ => return None,
+        })
+    }
 }
 
 impl Protocol for Save {
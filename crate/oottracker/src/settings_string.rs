@@ -0,0 +1,239 @@
+//! Decoding the randomizer's settings strings into [`Knowledge`].
+
+use {
+    std::fmt,
+    collect_mac::collect,
+    derivative::Derivative,
+    itertools::Itertools as _,
+    ootr::{
+        Rando,
+        setting::{
+            SettingInfo,
+            SettingType,
+        },
+    },
+    crate::knowledge::{
+        Contradiction,
+        Fact,
+        Knowledge,
+        Source,
+    },
+};
+
+/// The alphabet used by settings strings, each character encoding 5 bits.
+const LETTERS: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// List settings whose choices are the keys of [`Rando::starting_items`].
+pub(crate) const STARTING_ITEM_SETTINGS: [&str; 4] = ["starting_equipment", "starting_inventory", "starting_items", "starting_songs"];
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+pub enum DecodeError<R: Rando> {
+    ChoiceIndex {
+        setting: String,
+        index: usize,
+    },
+    /// The settings string contradicts what's known about settings from another source, e.g. a plando.
+    Contradiction(Contradiction),
+    InvalidChar(char),
+    Rando(R::Err),
+    UnexpectedEnd,
+}
+
+impl<R: Rando> fmt::Display for DecodeError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::ChoiceIndex { setting, index } => write!(f, "settings string has choice #{} for setting {}, which doesn't exist", index, setting),
+            DecodeError::Contradiction(e) => e.fmt(f),
+            DecodeError::InvalidChar(c) => write!(f, "invalid character in settings string: {:?}", c),
+            DecodeError::Rando(e) => write!(f, "error loading rando data: {}", e),
+            DecodeError::UnexpectedEnd => write!(f, "settings string is too short"),
+        }
+    }
+}
+
+struct Bits {
+    bits: Vec<bool>,
+    pos: usize,
+}
+
+impl Bits {
+    /// Reads an unsigned integer of the given width, least significant bit first.
+    fn read(&mut self, width: u32) -> Option<usize> {
+        let mut value = 0;
+        for b in 0..width {
+            if *self.bits.get(self.pos)? {
+                value |= 1 << b;
+            }
+            self.pos += 1;
+        }
+        Some(value)
+    }
+}
+
+/// Decodes the given settings string into knowledge about the seed's settings, tricks, and starting items.
+pub fn decode<R: Rando>(rando: &R, settings_string: &str) -> Result<Knowledge, DecodeError<R>> {
+    let mut bits = Bits { bits: Vec::with_capacity(settings_string.len() * 5), pos: 0 };
+    for c in settings_string.chars() {
+        let index = LETTERS.find(c).ok_or(DecodeError::InvalidChar(c))?;
+        bits.bits.extend((0..5).map(|b| index & (1 << b) != 0));
+    }
+    let mut read = |width| bits.read(width).ok_or(DecodeError::<R>::UnexpectedEnd);
+    let choice = |setting: &SettingInfo, index| setting.choices.get(index)
+        .map(|choice| choice.value.clone())
+        .ok_or_else(|| DecodeError::<R>::ChoiceIndex { setting: setting.name.clone(), index });
+    let setting_infos = rando.setting_infos().map_err(DecodeError::Rando)?;
+    let starting_items = rando.starting_items().map_err(DecodeError::Rando)?;
+    let mut knowledge = Knowledge {
+        tricks: rando.logic_tricks().map_err(DecodeError::Rando)?.iter().map(|trick| (trick.clone(), false)).collect(),
+        ..Knowledge::default()
+    };
+    for setting in setting_infos.values().filter(|setting| setting.shared && setting.bitwidth > 0).sorted_by_key(|setting| setting.index) {
+        match setting.ty {
+            SettingType::Bool => { knowledge.bool_settings.insert(setting.name.clone(), read(setting.bitwidth)? & 1 == 1); }
+            SettingType::Int { min, step, .. } => {
+                let value = read(setting.bitwidth)? as i64 * step + min.unwrap_or(0);
+                knowledge.string_settings.insert(setting.name.clone(), collect![value.to_string()]);
+            }
+            SettingType::Str => {
+                let value = choice(setting, read(setting.bitwidth)?)?;
+                knowledge.string_settings.insert(setting.name.clone(), collect![value]);
+            }
+            SettingType::List => {
+                let mut values = Vec::default();
+                if setting.choices.len() <= 64 {
+                    // encoded as a bitmap of the choices
+                    let bitmap = read(setting.bitwidth)?;
+                    for (i, choice) in setting.choices.iter().enumerate() {
+                        if bitmap & (1 << i) != 0 {
+                            values.push(choice.value.clone());
+                        }
+                    }
+                } else {
+                    // encoded as a list of choice indices, terminated by the maximum index
+                    let max_index = (1 << setting.bitwidth) - 1;
+                    loop {
+                        let index = read(setting.bitwidth)?;
+                        if index == max_index { break }
+                        values.push(choice(setting, index)?);
+                    }
+                }
+                if setting.name == "allowed_tricks" {
+                    for trick in values {
+                        knowledge.tricks.insert(trick, true);
+                    }
+                } else if STARTING_ITEM_SETTINGS.contains(&&*setting.name) {
+                    for choice in &setting.choices {
+                        if let Some(item) = starting_items.get(&choice.value) {
+                            knowledge.starting_items.entry(item.name().to_owned()).or_default();
+                        }
+                    }
+                    for value in values {
+                        if let Some(item) = starting_items.get(&value) {
+                            *knowledge.starting_items.entry(item.name().to_owned()).or_default() += 1;
+                        }
+                    }
+                } //TODO other list settings, e.g. disabled locations
+            }
+            SettingType::Other => { read(setting.bitwidth)?; }
+        }
+    }
+    Ok(knowledge.with_source(Source::SettingsString))
+}
+
+impl Knowledge {
+    /// Replaces everything known about settings, tricks, and starting items with the contents of the given settings string.
+    ///
+    /// If the settings string contradicts a setting, trick, or starting item known from another source (other than manual input or an earlier settings string), the contradiction is returned and the existing knowledge is kept.
+    pub fn apply_settings_string<R: Rando>(&mut self, rando: &R, settings_string: &str) -> Result<(), DecodeError<R>> {
+        let decoded = decode(rando, settings_string)?;
+        let mut others = Knowledge::default();
+        for (fact, &source) in &self.sources {
+            if matches!(source, Source::Manual | Source::SettingsString) { continue }
+            match fact {
+                Fact::Setting { name } => {
+                    if let Some(&value) = self.bool_settings.get(name) { others.bool_settings.insert(name.clone(), value); }
+                    if let Some(values) = self.string_settings.get(name) { others.string_settings.insert(name.clone(), values.clone()); }
+                }
+                Fact::Trick { name } => if let Some(&enabled) = self.tricks.get(name) { others.tricks.insert(name.clone(), enabled); },
+                Fact::StartingItem { name } => if let Some(&count) = self.starting_items.get(name) { others.starting_items.insert(name.clone(), count); },
+                _ => continue,
+            }
+            others.sources.insert(fact.clone(), source);
+        }
+        (others & decoded.clone()).map_err(DecodeError::Contradiction)?;
+        let Knowledge { bool_settings, string_settings, tricks, starting_items, sources, .. } = decoded;
+        self.bool_settings = bool_settings;
+        self.string_settings = string_settings;
+        self.tricks = tricks;
+        self.starting_items = starting_items;
+        self.sources.retain(|fact, _| !matches!(fact, Fact::Setting { .. } | Fact::Trick { .. } | Fact::StartingItem { .. }));
+        self.sources.extend(sources);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        collect_mac::collect,
+        crate::test_rando::TestRando,
+        crate::knowledge::{
+            Fact,
+            Knowledge,
+            Source,
+        },
+        super::{
+            DecodeError,
+            decode,
+        },
+    };
+
+    #[test]
+    fn all_types() {
+        // open_forest = closed, shuffle_kokiri_sword = true, big_poe_count = 3, starting_inventory = [megaton_hammer], allowed_tricks = [logic_test_trick]
+        let knowledge = decode(&TestRando, "YN").unwrap();
+        assert_eq!(knowledge.string_settings["open_forest"], collect![format!("closed")]);
+        assert!(knowledge.bool_settings["shuffle_kokiri_sword"]);
+        assert_eq!(knowledge.string_settings["big_poe_count"], collect![format!("3")]);
+        assert_eq!(knowledge.starting_items["Megaton Hammer"], 1);
+        assert!(knowledge.tricks["logic_test_trick"]);
+    }
+
+    #[test]
+    fn unselected_list_choices() {
+        // open_forest = open, everything else zero
+        let knowledge = decode(&TestRando, "AA").unwrap();
+        assert_eq!(knowledge.string_settings["open_forest"], collect![format!("open")]);
+        assert_eq!(knowledge.string_settings["big_poe_count"], collect![format!("1")]);
+        assert_eq!(knowledge.starting_items["Megaton Hammer"], 0);
+        assert!(!knowledge.tricks["logic_test_trick"]);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(decode(&TestRando, "Y"), Err(DecodeError::UnexpectedEnd)));
+        assert!(matches!(decode(&TestRando, "I"), Err(DecodeError::InvalidChar('I'))));
+        assert!(matches!(decode(&TestRando, "DA"), Err(DecodeError::ChoiceIndex { index: 3, .. })));
+    }
+
+    #[test]
+    fn contradiction() {
+        let mut knowledge = Knowledge::default();
+        knowledge.string_settings.insert(format!("open_forest"), collect![format!("open")]);
+        knowledge.sources.insert(Fact::Setting { name: format!("open_forest") }, Source::Plando);
+        let prev = knowledge.clone();
+        match knowledge.apply_settings_string(&TestRando, "YN") {
+            Err(DecodeError::Contradiction(e)) => {
+                assert_eq!(e.lhs_source, Some(Source::Plando));
+                assert_eq!(e.rhs_source, Some(Source::SettingsString));
+            }
+            res => panic!("expected contradiction, got {:?}", res),
+        }
+        assert_eq!(knowledge, prev);
+        // a settings string can replace an earlier one
+        knowledge.sources.insert(Fact::Setting { name: format!("open_forest") }, Source::SettingsString);
+        knowledge.apply_settings_string(&TestRando, "YN").unwrap();
+        assert_eq!(knowledge.string_settings["open_forest"], collect![format!("closed")]);
+    }
+}
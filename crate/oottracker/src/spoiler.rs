@@ -0,0 +1,239 @@
+//! Loading the randomizer's spoiler logs, e.g. for practice runs or post-race review.
+
+use {
+    std::{
+        collections::{
+            BTreeMap,
+            HashMap,
+            HashSet,
+        },
+        fmt,
+        fs::File,
+        io::{
+            self,
+            BufReader,
+        },
+        path::Path,
+        sync::Arc,
+    },
+    derivative::Derivative,
+    serde::Deserialize,
+    serde_json::Value as Json,
+    ootr::{
+        Rando,
+        model::{
+            Dungeon,
+            DungeonReward,
+            DungeonRewardLocation,
+            Medallion,
+        },
+        region::Mq,
+    },
+    crate::{
+        ModelState,
+        knowledge::{
+            self,
+            Entrance,
+            EntranceTarget,
+            Knowledge,
+            Placement,
+            Source,
+            TrialActive,
+        },
+        plando::{
+            self,
+            PlandoError,
+        },
+    },
+};
+
+#[derive(Deserialize)]
+struct SpoilerJson {
+    #[serde(rename = ":version")]
+    version: String,
+    settings: HashMap<String, Json>,
+    #[serde(default)]
+    randomized_settings: HashMap<String, Json>,
+    #[serde(default)]
+    dungeons: HashMap<String, Mq>,
+    #[serde(default)]
+    trials: HashMap<Medallion, TrialActive>,
+    #[serde(default)]
+    entrances: HashMap<String, EntranceTarget>,
+    locations: HashMap<String, Placement>,
+    #[serde(rename = ":playthrough", default)]
+    playthrough: BTreeMap<String, HashMap<String, Placement>>,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+pub enum SpoilerError<R: Rando> {
+    Io(Arc<io::Error>),
+    Json(Arc<serde_json::Error>),
+    /// The spoiler log lists more than one item at the given location.
+    MultipleItems(String),
+    Multiworld,
+    Rando(R::Err),
+    Setting(PlandoError<R>),
+    UnknownDungeon(String),
+    UnknownEntrance(String),
+}
+
+impl<R: Rando> fmt::Display for SpoilerError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpoilerError::Io(e) => write!(f, "I/O error: {}", e),
+            SpoilerError::Json(e) => write!(f, "JSON error: {}", e),
+            SpoilerError::MultipleItems(location) => write!(f, "multiple items listed at {}", location),
+            SpoilerError::Multiworld => write!(f, "multiworld spoiler logs are not supported"),
+            SpoilerError::Rando(e) => e.fmt(f),
+            SpoilerError::Setting(e) => e.fmt(f),
+            SpoilerError::UnknownDungeon(name) => write!(f, "unknown dungeon: {}", name),
+            SpoilerError::UnknownEntrance(name) => write!(f, "entrance {:?} is not of the form \"Region -> Region\"", name),
+        }
+    }
+}
+
+/// The contents of a spoiler log.
+pub struct SpoilerLog {
+    /// The randomizer version which generated the seed.
+    pub version: String,
+    pub knowledge: Knowledge,
+    /// Locations which hold items required to beat the game, according to the spoiler log's playthrough.
+    pub progression_locations: HashSet<String>,
+}
+
+impl SpoilerLog {
+    /// Reads the spoiler log at the given path.
+    pub fn load<R: Rando>(rando: &R, path: impl AsRef<Path>) -> Result<SpoilerLog, SpoilerError<R>> {
+        let file = File::open(path).map_err(|e| SpoilerError::Io(Arc::new(e)))?;
+        let json = serde_json::from_reader(BufReader::new(file)).map_err(|e| SpoilerError::Json(Arc::new(e)))?;
+        SpoilerLog::from_json(rando, json)
+    }
+
+    /// Converts the contents of a spoiler log.
+    pub fn from_json<R: Rando>(rando: &R, json: Json) -> Result<SpoilerLog, SpoilerError<R>> {
+        let SpoilerJson { version, settings, randomized_settings, dungeons, trials, entrances, locations, playthrough } = serde_json::from_value(json).map_err(|e| SpoilerError::Json(Arc::new(e)))?;
+        if settings.get("world_count").and_then(Json::as_u64).map_or(false, |world_count| world_count > 1) {
+            return Err(SpoilerError::Multiworld)
+        }
+        let mut knowledge = Knowledge::default();
+        for (name, value) in settings.iter().chain(&randomized_settings) {
+            match plando::read_setting(rando, &mut knowledge, name, value) {
+                Ok(()) | Err(PlandoError::Unsupported { .. }) => {} // list settings aren't needed for full-knowledge sessions
+                Err(e) => return Err(SpoilerError::Setting(e)),
+            }
+        }
+        for (dungeon, mq) in dungeons {
+            knowledge.mq.insert(dungeon.parse::<Dungeon>().map_err(|()| SpoilerError::UnknownDungeon(dungeon))?, mq);
+        }
+        knowledge.active_trials = trials.into_iter().map(|(trial, active)| (trial, active.into())).collect();
+        // entrances which aren't listed in the spoiler log aren't shuffled
+        knowledge.exits = knowledge::vanilla_exits(rando).map_err(SpoilerError::Rando)?;
+        let exits = &mut knowledge.exits;
+        for (entrance, target) in entrances {
+            let (from, to) = entrance.split_once(" -> ").ok_or_else(|| SpoilerError::UnknownEntrance(entrance.clone()))?;
+            let target = match target {
+                EntranceTarget::Region(region) | EntranceTarget::Entrance(Entrance { region, .. }) => region,
+            };
+            exits.entry(from.to_owned()).or_default().insert(to.to_owned(), target);
+        }
+        for (location, placement) in locations {
+            let mut items = placement.into_items();
+            let Some(item) = items.pop() else { continue };
+            if !items.is_empty() { return Err(SpoilerError::MultipleItems(location)) }
+            if let (Ok(reward_location), Ok(reward)) = (location.parse::<DungeonRewardLocation>(), DungeonReward::try_from(item.clone())) {
+                knowledge.dungeon_reward_locations.insert(reward, reward_location);
+            } else {
+                knowledge.locations.insert(location, item);
+            }
+        }
+        Ok(SpoilerLog {
+            progression_locations: playthrough.into_values().flat_map(|sphere| sphere.into_keys()).collect(),
+            knowledge: knowledge.with_source(Source::Spoiler),
+            version,
+        })
+    }
+
+    /// A model state for a session in which everything about the seed is known but nothing has been collected yet.
+    pub fn model_state(&self) -> ModelState {
+        ModelState {
+            knowledge: self.knowledge.clone(),
+            ..ModelState::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        serde_json::json,
+        ootr::{
+            item::Item,
+            model::{
+                DungeonReward,
+                DungeonRewardLocation,
+                Medallion,
+            },
+        },
+        crate::{
+            knowledge::{
+                Fact,
+                Source,
+            },
+            test_rando::TestRando,
+        },
+        super::{
+            SpoilerError,
+            SpoilerLog,
+        },
+    };
+
+    #[test]
+    fn single_world() {
+        let spoiler = SpoilerLog::from_json(&TestRando, json!({
+            ":version": "6.2.0 f.LUM",
+            "settings": {"open_forest": "open", "world_count": 1, "disabled_locations": []},
+            "randomized_settings": {"shuffle_kokiri_sword": true},
+            "trials": {"Forest": "active"},
+            "entrances": {"Root -> KF Midos House": "Ganons Castle Tower"},
+            "locations": {
+                "KF Midos Top Left Chest": "Megaton Hammer",
+                "Links Pocket": "Light Medallion",
+            },
+            ":playthrough": {"0": {"KF Midos Top Left Chest": "Megaton Hammer"}},
+        })).unwrap();
+        assert_eq!(spoiler.version, "6.2.0 f.LUM");
+        assert!(spoiler.knowledge.bool_settings["shuffle_kokiri_sword"]);
+        assert!(spoiler.knowledge.active_trials[&Medallion::Forest]);
+        assert_eq!(spoiler.knowledge.get_exit("Root", "KF Midos House"), Some("Ganons Castle Tower"));
+        // entrances not listed in the spoiler log are vanilla
+        assert_eq!(spoiler.knowledge.get_exit("KF Midos House", "Ganons Castle Tower"), Some("Ganons Castle Tower"));
+        assert_eq!(spoiler.knowledge.locations["KF Midos Top Left Chest"], Item(format!("Megaton Hammer")));
+        assert_eq!(spoiler.knowledge.dungeon_reward_locations[&DungeonReward::Medallion(Medallion::Light)], DungeonRewardLocation::LinksPocket);
+        assert_eq!(spoiler.knowledge.sources[&Fact::Exit { from: format!("Root"), to: format!("KF Midos House") }], Source::Spoiler);
+        assert!(spoiler.progression_locations.contains("KF Midos Top Left Chest"));
+        assert_eq!(spoiler.model_state().knowledge, spoiler.knowledge);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(SpoilerLog::from_json(&TestRando, json!({
+            ":version": "6.2.0 f.LUM",
+            "settings": {"world_count": 2},
+            "locations": {},
+        })), Err(SpoilerError::Multiworld)));
+        assert!(matches!(SpoilerLog::from_json(&TestRando, json!({
+            ":version": "6.2.0 f.LUM",
+            "settings": {},
+            "locations": {"KF Midos Top Left Chest": ["Megaton Hammer", "Megaton Hammer"]},
+        })), Err(SpoilerError::MultipleItems(_))));
+        assert!(matches!(SpoilerLog::from_json(&TestRando, json!({
+            ":version": "6.2.0 f.LUM",
+            "settings": {},
+            "entrances": {"Root": "KF Midos House"},
+            "locations": {},
+        })), Err(SpoilerError::UnknownEntrance(_))));
+        assert!(matches!(SpoilerLog::from_json(&TestRando, json!({"settings": {}})), Err(SpoilerError::Json(_))));
+    }
+}
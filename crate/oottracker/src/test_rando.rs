@@ -0,0 +1,132 @@
+//! A tiny hand-written world graph so logic can be unit tested without the randomizer's data.
+
+use {
+    std::{
+        collections::{
+            HashMap,
+            HashSet,
+        },
+        fmt,
+        sync::Arc,
+    },
+    ootr::{
+        Rando,
+        RandoErr,
+        access::{
+            Expr,
+            LogicHelper,
+        },
+        item::Item,
+        region::{
+            EntrancePair,
+            Region,
+        },
+        setting::{
+            SettingChoice,
+            SettingInfo,
+            SettingType,
+            SettingValue,
+        },
+    },
+};
+
+#[derive(Debug, Clone)]
+pub(crate) enum TestRandoErr {
+    ItemNotFound,
+}
+
+impl RandoErr for TestRandoErr {
+    const ITEM_NOT_FOUND: TestRandoErr = TestRandoErr::ItemNotFound;
+}
+
+impl fmt::Display for TestRandoErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestRandoErr::ItemNotFound => write!(f, "no such item"),
+        }
+    }
+}
+
+/// The root leads to Mido's house, which has a chest that's always in logic, a location whose checked state the tracker can't read, and the way to Ganon, which requires the Megaton Hammer.
+///
+/// There are a few settings of each type, one trick, and the Megaton Hammer as the only starting item choice. The list settings default to all of their choices.
+pub(crate) struct TestRando;
+
+fn region(name: &'static str, locations: Vec<(&str, Expr)>, exits: Vec<(&'static str, Expr)>) -> Arc<Region<TestRando>> {
+    Arc::new(Region {
+        name,
+        dungeon: None,
+        scene: None,
+        hint: None,
+        time_passes: false,
+        events: HashMap::default(),
+        locations: locations.into_iter().map(|(location, rule)| (location.to_owned(), rule)).collect(),
+        exits: exits.into_iter().collect(),
+    })
+}
+
+fn setting(name: &str, index: usize, bitwidth: u32, ty: SettingType, choices: &[&str], default: SettingValue) -> (String, SettingInfo) {
+    (name.to_owned(), SettingInfo {
+        name: name.to_owned(),
+        shared: true,
+        display_name: None,
+        choices: choices.iter().map(|&choice| SettingChoice { value: choice.to_owned(), display_name: choice.to_owned() }).collect(),
+        default: Some(default),
+        index, bitwidth, ty,
+    })
+}
+
+fn hammer() -> Expr {
+    Expr::Item(Item(format!("Megaton Hammer")), Box::new(Expr::LitInt(1)))
+}
+
+impl Rando for TestRando {
+    type Err = TestRandoErr;
+    type RegionName = &'static str;
+
+    fn entrances(&self) -> Result<Arc<Vec<EntrancePair>>, TestRandoErr> { Ok(Arc::default()) }
+    fn escaped_items(&self) -> Result<Arc<HashMap<String, Item>>, TestRandoErr> { Ok(Arc::default()) }
+
+    fn get_item_ids(&self) -> Result<Arc<HashMap<String, u16>>, TestRandoErr> {
+        Ok(Arc::new(HashMap::from([(format!("Megaton Hammer"), 0x000d)])))
+    }
+
+    fn item_table(&self) -> Result<Arc<HashMap<String, Item>>, TestRandoErr> {
+        Ok(Arc::new(HashMap::from([(format!("Megaton Hammer"), Item(format!("Megaton Hammer")))])))
+    }
+
+    fn logic_helpers(&self) -> Result<Arc<HashMap<String, LogicHelper>>, TestRandoErr> { Ok(Arc::default()) }
+    fn logic_tricks(&self) -> Result<Arc<HashSet<String>>, TestRandoErr> { Ok(Arc::new(HashSet::from([format!("logic_test_trick")]))) }
+
+    fn regions(&self, _: bool) -> Result<Arc<Vec<Arc<Region<TestRando>>>>, TestRandoErr> {
+        Ok(Arc::new(vec![
+            region("Root", vec![], vec![("KF Midos House", Expr::All(vec![]))]),
+            region("KF Midos House", vec![
+                ("KF Midos Top Left Chest", Expr::All(vec![])),
+                ("KF Midos Top Right Chest", hammer()),
+                ("Test Location", Expr::All(vec![])),
+            ], vec![("Ganons Castle Tower", hammer())]),
+            region("Ganons Castle Tower", vec![("Ganon", Expr::All(vec![]))], vec![]),
+        ]))
+    }
+
+    fn root() -> &'static str { "Root" }
+
+    fn setting_infos(&self) -> Result<Arc<HashMap<String, SettingInfo>>, TestRandoErr> {
+        Ok(Arc::new(HashMap::from([
+            setting("open_forest", 0, 2, SettingType::Str, &["open", "closed_deku", "closed"], SettingValue::Str(format!("closed"))),
+            setting("shuffle_kokiri_sword", 1, 1, SettingType::Bool, &[], SettingValue::Bool(false)),
+            setting("big_poe_count", 2, 4, SettingType::Int { min: Some(1), max: Some(10), step: 1 }, &[], SettingValue::Int(10)),
+            setting("starting_inventory", 3, 1, SettingType::List, &["megaton_hammer"], SettingValue::List(vec![format!("megaton_hammer")])),
+            setting("allowed_tricks", 4, 1, SettingType::List, &["logic_test_trick"], SettingValue::List(vec![format!("logic_test_trick")])),
+        ])))
+    }
+
+    fn starting_items(&self) -> Result<Arc<HashMap<String, Item>>, TestRandoErr> {
+        Ok(Arc::new(HashMap::from([(format!("megaton_hammer"), Item(format!("Megaton Hammer")))])))
+    }
+
+    fn vanilla_items(&self) -> Result<Arc<HashMap<String, Item>>, TestRandoErr> {
+        Ok(Arc::new(HashMap::from([(format!("KF Midos Top Left Chest"), Item(format!("Megaton Hammer")))])))
+    }
+}
@@ -7,9 +7,60 @@ use {
         Medallion,
         Stone,
     },
-    crate::knowledge::Knowledge,
+    collect_mac::collect,
+    crate::knowledge::{
+        Fact,
+        Hint,
+        Knowledge,
+        NpcHint,
+        Source,
+    },
 };
 
+/// The message IDs of gossip stone hints, by the stone's location name. From `gossipLocations` in the randomizer's `HintList.py`.
+const GOSSIP_STONES: [(u16, &str); 40] = [
+    (0x0405, "DMC Gossip Stone"),
+    (0x0404, "DMT Gossip Stone"),
+    (0x041a, "Colossus Gossip Stone"),
+    (0x0414, "Dodongos Cavern Gossip Stone"),
+    (0x0411, "GV Gossip Stone"),
+    (0x0415, "GC Maze Gossip Stone"),
+    (0x0419, "GC Medigoron Gossip Stone"),
+    (0x040a, "Graveyard Gossip Stone"),
+    (0x0412, "HC Malon Gossip Stone"),
+    (0x040b, "HC Rock Wall Gossip Stone"),
+    (0x0413, "HC Storms Grotto Gossip Stone"),
+    (0x041f, "KF Deku Tree Gossip Stone (Left)"),
+    (0x0420, "KF Deku Tree Gossip Stone (Right)"),
+    (0x041e, "KF Gossip Stone"),
+    (0x0403, "LH Lab Gossip Stone"),
+    (0x040f, "LH Gossip Stone (Southeast)"),
+    (0x0408, "LH Gossip Stone (Southwest)"),
+    (0x041d, "LW Gossip Stone"),
+    (0x0416, "SFM Maze Gossip Stone (Lower)"),
+    (0x0417, "SFM Maze Gossip Stone (Upper)"),
+    (0x041c, "SFM Saria Gossip Stone"),
+    (0x0406, "ToT Gossip Stone (Left)"),
+    (0x0407, "ToT Gossip Stone (Left-Center)"),
+    (0x0410, "ToT Gossip Stone (Right)"),
+    (0x040e, "ToT Gossip Stone (Right-Center)"),
+    (0x0409, "ZD Gossip Stone"),
+    (0x0401, "ZF Fairy Gossip Stone"),
+    (0x0402, "ZF Jabu Gossip Stone"),
+    (0x040d, "ZR Near Grottos Gossip Stone"),
+    (0x040c, "ZR Near Domain Gossip Stone"),
+    (0x041b, "HF Cow Grotto Gossip Stone"),
+    (0x0430, "HF Near Market Grotto Gossip Stone"),
+    (0x0432, "HF Southeast Grotto Gossip Stone"),
+    (0x0433, "HF Open Grotto Gossip Stone"),
+    (0x0438, "Kak Open Grotto Gossip Stone"),
+    (0x0439, "ZR Open Grotto Gossip Stone"),
+    (0x043c, "KF Storms Grotto Gossip Stone"),
+    (0x0444, "LW Near Shortcuts Grotto Gossip Stone"),
+    (0x0447, "DMT Storms Grotto Gossip Stone"),
+    (0x044a, "DMC Upper Grotto Gossip Stone"),
+];
+
 fn eat_str(s: &mut &[u8], prefix: &[u8]) -> bool {
     if s.starts_with(prefix) {
         *s = &s[prefix.len()..];
@@ -173,25 +224,201 @@ impl DungeonRewardLocationExt for DungeonRewardLocation {
     }
 }
 
-pub(crate) fn read_knowledge(mut text: &[u8]) -> Knowledge {
+/// Splits a message into its plain text, with each colored part replaced by `{}`, and the colored parts.
+///
+/// Line and box breaks are read as spaces, other control codes are skipped.
+fn split_colors(text: &[u8]) -> (String, Vec<String>) {
+    let mut plain = String::default();
+    let mut colored = Vec::<String>::default();
+    let mut in_color = false;
+    let mut iter = text.iter().copied();
+    while let Some(c) = iter.next() {
+        match c {
+            0x01 | 0x04 => if in_color { colored.last_mut().expect("in colored part").push(' ') } else { plain.push(' ') },
+            0x02 => break,
+            0x05 => match iter.next() {
+                Some(0x40) | None => in_color = false,
+                Some(_) => if !in_color {
+                    in_color = true;
+                    plain.push_str("{}");
+                    colored.push(String::default());
+                },
+            },
+            0x06 | 0x0c | 0x0e | 0x13 | 0x14 | 0x1e => { iter.next(); } // control codes with a 1-byte argument
+            0x07 | 0x11 | 0x12 => { iter.next(); iter.next(); } // control codes with a 2-byte argument
+            0x15 => { iter.next(); iter.next(); iter.next(); } // background, which has a 3-byte argument
+            0x20..=0x7e => if in_color { colored.last_mut().expect("in colored part").push(c.into()) } else { plain.push(c.into()) },
+            _ => {}
+        }
+    }
+    (plain, colored)
+}
+
+/// Returns the colored part which immediately follows the given phrase.
+fn colored_after<'a>(plain: &str, colored: &'a [String], phrase: &str) -> Option<&'a str> {
+    let start = plain.find(phrase)? + phrase.len();
+    let rest = &plain[start..];
+    let rest = rest.strip_prefix("the ").unwrap_or(rest);
+    if rest.starts_with("{}") {
+        colored.get(plain[..plain.len() - rest.len()].matches("{}").count()).map(String::as_str)
+    } else {
+        None
+    }
+}
+
+/// Parses a condition from the Temple of Time altar text as a setting value and, if applicable, a count.
+fn parse_condition(condition: &str) -> Option<(&'static str, Option<&str>)> {
+    if condition == "Shadow and Spirit Medallions" {
+        return Some(("vanilla", None))
+    }
+    let (count, kind) = condition.split_once(' ')?;
+    count.parse::<u8>().ok()?;
+    let value = if kind.starts_with("Spiritual Stone") && kind.contains("Medallion") {
+        "dungeons"
+    } else if kind.starts_with("Spiritual Stone") {
+        "stones"
+    } else if kind.starts_with("Medallion") {
+        "medallions"
+    } else if kind.starts_with("Gold Skulltula Token") {
+        "tokens"
+    } else if kind.starts_with("heart") {
+        "hearts"
+    } else {
+        return None
+    };
+    Some((value, Some(count)))
+}
+
+/// Records a condition as the given setting and its count setting, e.g. `bridge` and `bridge_stones`.
+fn insert_condition(knowledge: &mut Knowledge, setting: &str, count_prefix: &str, (value, count): (&str, Option<&str>)) {
+    knowledge.string_settings.insert(setting.to_owned(), collect![value.to_owned()]);
+    if let Some(count) = count {
+        let count_setting = match value {
+            "dungeons" => format!("{}_rewards", count_prefix),
+            _ => format!("{}_{}", count_prefix, value),
+        };
+        knowledge.string_settings.insert(count_setting, collect![count.to_owned()]);
+    }
+}
+
+fn parse_hint(text: &[u8]) -> Hint {
+    let (plain, colored) = split_colors(text);
+    let other = || Hint::Other { text: colored.iter().fold(plain.clone(), |text, part| text.replacen("{}", part, 1)) };
+    let Some(hint) = plain.trim().strip_prefix("They say that ") else { return other() };
+    match (hint, &colored[..]) {
+        ("{} is on the way of the hero.", [area]) => Hint::WayOfTheHero { area: area.clone() },
+        ("{} is on {}.", [area, woth]) if woth == "the way of the hero" => Hint::WayOfTheHero { area: area.clone() },
+        ("plundering {} is a foolish choice.", [area]) | ("{} is a foolish choice.", [area]) => Hint::Foolish { area: area.clone() },
+        ("plundering {} is {}.", [area, foolish]) | ("{} is {}.", [area, foolish]) if foolish == "a foolish choice" => Hint::Foolish { area: area.clone() },
+        ("{} leads to {}.", [entrance, target]) => Hint::Entrance { entrance: entrance.clone(), target: target.clone() },
+        (_, [.., item]) if hint.ends_with("{}.") => {
+            let location = hint[..hint.len() - "{}.".len()].trim_end();
+            Hint::Item {
+                location: colored[..colored.len() - 1].iter().fold(location.to_owned(), |text, part| text.replacen("{}", part, 1)),
+                item: item.clone(),
+            }
+        }
+        (_, _) => other(),
+    }
+}
+
+pub(crate) fn read_knowledge(text_box_id: u16, mut text: &[u8]) -> Knowledge {
     let mut knowledge = Knowledge::default();
+    if let Some(&(_, stone)) = GOSSIP_STONES.iter().find(|&&(id, _)| id == text_box_id) {
+        knowledge.gossip_stones.insert(stone.to_owned(), parse_hint(text));
+        return knowledge.with_source(Source::HintText)
+    }
+    let (plain, colored) = split_colors(text);
+    if plain.contains("The awakened ones will have {} to the castle") {
+        knowledge.string_settings.insert(format!("bridge"), collect![format!("open")]);
+    } else if let Some(condition) = colored_after(&plain, &colored, "The awakened ones will await for the Hero to collect ").and_then(parse_condition) {
+        insert_condition(&mut knowledge, "bridge", "bridge", condition);
+    }
+    if let Some(condition) = colored_after(&plain, &colored, "key will be given by the Sage of Time once ").and_then(parse_condition) {
+        insert_condition(&mut knowledge, "lacs_condition", "lacs", condition);
+    }
+    knowledge = knowledge.with_source(Source::AltarText);
+    if let Some(location) = colored_after(&plain, &colored, "unleashing the arrows from ") {
+        knowledge.npc_hints.insert(NpcHint::Ganondorf, Hint::Item { location: location.to_owned(), item: format!("Light Arrows") });
+    } else if let (Some(spiders), Some(item)) = (colored_after(&plain, &colored, "Please save me by destroying "), colored_after(&plain, &colored, "and I will give you ")) {
+        if let Some(count) = spiders.strip_suffix(" Spiders of the Curse").and_then(|count| count.parse().ok()) {
+            knowledge.npc_hints.insert(NpcHint::SkulltulaHouse(count), Hint::Item { location: format!("Kak {} Gold Skulltula Reward", count), item: item.to_owned() });
+        }
+    } else if let Some(item) = colored_after(&plain, &colored, "Some frogs holding ") {
+        knowledge.npc_hints.insert(NpcHint::Frogs, Hint::Item { location: format!("ZR Frogs Ocarina Game"), item: item.to_owned() });
+    } else if plain.starts_with("Arrrrrg!") {
+        if let Some(item) = colored.last() {
+            knowledge.npc_hints.insert(NpcHint::Biggoron, Hint::Item { location: format!("DMT Biggoron"), item: item.clone() });
+        }
+    }
+    //TODO Saria's hints (same text format as gossip stone hints, so they can only be told apart by message ID, which needs to be looked up in the randomizer's hint code and added like `GOSSIP_STONES`)
+    knowledge = knowledge.with_source(Source::HintText);
     if eat_str(&mut text, b"\x08Princess Ruto got the \x01") {
         if let Some(reward) = DungeonReward::eat_ruto_hint_text(&mut text) {
             knowledge.dungeon_reward_locations.insert(reward, DungeonRewardLocation::Dungeon(MainDungeon::JabuJabu));
+            knowledge.sources.insert(Fact::DungeonRewardLocation { reward }, Source::RutoText);
         }
     } else if eat_str(&mut text, b"\x08\x13\x75You found the \x05\x41Compass\x05\x40\x01for ") {
         if let Some(Dungeon::Main(dungeon)) = Dungeon::eat_compass_hint_text(&mut text) {
             if eat_str(&mut text, b"\x05\x40!\x01It holds the ") {
                 if let Some(reward) = DungeonReward::eat_compass_hint_text(&mut text) {
                     knowledge.dungeon_reward_locations.insert(reward, DungeonRewardLocation::Dungeon(dungeon));
+                    knowledge.sources.insert(Fact::DungeonRewardLocation { reward }, Source::CompassText);
                 }
             }
         }
     } else if let Some(reward) = DungeonReward::eat_altar_hint_text(&mut text) {
         if let Some(loc) = DungeonRewardLocation::eat_altar_hint_text(&mut text) {
             knowledge.dungeon_reward_locations.insert(reward, loc);
+            knowledge.sources.insert(Fact::DungeonRewardLocation { reward }, Source::AltarText);
         }
     }
-    //TODO other info (e.g. Jabu dungeon reward)
+    //TODO other info
     knowledge
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        collect_mac::collect,
+        crate::knowledge::{
+            Fact,
+            Hint,
+            NpcHint,
+            Source,
+        },
+        super::{
+            parse_hint,
+            read_knowledge,
+        },
+    };
+
+    #[test]
+    fn hints() {
+        assert_eq!(parse_hint(b"They say that \x05\x42Kokiri Forest\x05\x40 is on the way of the hero.\x02"), Hint::WayOfTheHero { area: format!("Kokiri Forest") });
+        assert_eq!(parse_hint(b"They say that plundering \x05\x41Lost Woods\x05\x40 is \x05\x44a foolish choice\x05\x40.\x02"), Hint::Foolish { area: format!("Lost Woods") });
+        assert_eq!(parse_hint(b"They say that \x05\x41Lake Hylia\x05\x40 leads to\x01\x05\x42Fire Temple\x05\x40.\x02"), Hint::Entrance { entrance: format!("Lake Hylia"), target: format!("Fire Temple") });
+        assert_eq!(parse_hint(b"\x05\x41Hello\x05\x40 world!\x02"), Hint::Other { text: format!("Hello world!") });
+    }
+
+    #[test]
+    fn gossip_stone() {
+        let knowledge = read_knowledge(0x041e, b"They say that \x05\x42Kokiri Forest\x05\x40 is on the way of the hero.\x02");
+        assert_eq!(knowledge.gossip_stones["KF Gossip Stone"], Hint::WayOfTheHero { area: format!("Kokiri Forest") });
+        assert_eq!(knowledge.sources[&Fact::GossipStone { stone: format!("KF Gossip Stone") }], Source::HintText);
+    }
+
+    #[test]
+    fn altar() {
+        let knowledge = read_knowledge(0x7040, b"The awakened ones will await for the Hero to collect \x05\x416 Medallions\x05\x40.\x02");
+        assert_eq!(knowledge.string_settings["bridge"], collect![format!("medallions")]);
+        assert_eq!(knowledge.string_settings["bridge_medallions"], collect![format!("6")]);
+        assert_eq!(knowledge.sources[&Fact::Setting { name: format!("bridge") }], Source::AltarText);
+    }
+
+    #[test]
+    fn ganondorf() {
+        let knowledge = read_knowledge(0x70cc, b"Dead Hero? Then I'll be unleashing the arrows from the \x05\x41Graveyard\x05\x40!\x02");
+        assert_eq!(knowledge.npc_hints[&NpcHint::Ganondorf], Hint::Item { location: format!("Graveyard"), item: format!("Light Arrows") });
+    }
+}
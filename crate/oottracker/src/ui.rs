@@ -44,7 +44,11 @@ use {
         ModelState,
         checks::CheckExt as _,
         info_tables::*,
-        knowledge::ProgressionMode,
+        knowledge::{
+            Fact,
+            ProgressionMode,
+            Source,
+        },
         save::*,
     },
 };
@@ -673,11 +677,14 @@ impl TrackerCellKind {
                 let current = get(state);
                 set(state, if current == *max { 0 } else { current.saturating_add(*step).min(*max) });
             }
-            FortressMq => if state.knowledge.string_settings.get("gerudo_fortress").map_or(false, |fort| fort.iter().eq(iter::once("normal"))) {
-                state.knowledge.string_settings.remove("gerudo_fortress");
-            } else {
-                state.knowledge.string_settings.insert(format!("gerudo_fortress"), collect![format!("normal")]);
-            },
+            FortressMq => {
+                if state.knowledge.string_settings.get("gerudo_fortress").map_or(false, |fort| fort.iter().eq(iter::once("normal"))) {
+                    state.knowledge.string_settings.remove("gerudo_fortress");
+                } else {
+                    state.knowledge.string_settings.insert(format!("gerudo_fortress"), collect![format!("normal")]);
+                }
+                state.knowledge.update_source(Fact::Setting { name: format!("gerudo_fortress") }, Source::Manual);
+            }
             GoBk => state.knowledge.progression_mode = match state.knowledge.progression_mode {
                 ProgressionMode::Normal => ProgressionMode::Go,
                 ProgressionMode::Go => ProgressionMode::Bk,
@@ -693,13 +700,19 @@ impl TrackerCellKind {
                 }
             }
             Medallion(med) => state.ram.save.quest_items.toggle(QuestItems::from(med)),
-            MedallionLocation(med) => state.knowledge.dungeon_reward_locations.increment(DungeonReward::Medallion(*med)),
-            MedallionWithLocation(med) => state.knowledge.dungeon_reward_locations.increment(DungeonReward::Medallion(*med)),
-            Mq(dungeon) => if state.knowledge.mq.get(dungeon) == Some(&Mq::Mq) {
-                state.knowledge.mq.remove(dungeon);
-            } else {
-                state.knowledge.mq.insert(*dungeon, Mq::Mq);
-            },
+            MedallionLocation(med) | MedallionWithLocation(med) => {
+                let reward = DungeonReward::Medallion(*med);
+                state.knowledge.dungeon_reward_locations.increment(reward);
+                state.knowledge.update_source(Fact::DungeonRewardLocation { reward }, Source::Manual);
+            }
+            Mq(dungeon) => {
+                if state.knowledge.mq.get(dungeon) == Some(&Mq::Mq) {
+                    state.knowledge.mq.remove(dungeon);
+                } else {
+                    state.knowledge.mq.insert(*dungeon, Mq::Mq);
+                }
+                state.knowledge.update_source(Fact::Mq { dungeon: *dungeon }, Source::Manual);
+            }
             Sequence { increment, .. } => increment(state),
             TrackerCellKind::SmallKeys { get, set, max_vanilla, max_mq } => {
                 let num = get(&state.ram.save.small_keys);
@@ -715,8 +728,11 @@ impl TrackerCellKind {
                 state.ram.save.inv.dins_fire = !state.ram.save.inv.dins_fire;
             }
             Stone(stone) => state.ram.save.quest_items.toggle(QuestItems::from(stone)),
-            StoneLocation(stone) => state.knowledge.dungeon_reward_locations.increment(DungeonReward::Stone(*stone)),
-            StoneWithLocation(stone) => state.knowledge.dungeon_reward_locations.increment(DungeonReward::Stone(*stone)),
+            StoneLocation(stone) | StoneWithLocation(stone) => {
+                let reward = DungeonReward::Stone(*stone);
+                state.knowledge.dungeon_reward_locations.increment(reward);
+                state.knowledge.update_source(Fact::DungeonRewardLocation { reward }, Source::Manual);
+            }
             FreeReward => {}
             BigPoeTriforce | BossKey { .. } | SongCheck { .. } => unimplemented!(),
         }
@@ -792,7 +808,11 @@ impl TrackerCellKind {
                 },
                 MagicLens => state.ram.save.inv.lens = !state.ram.save.inv.lens,
                 Medallion(_) => unreachable!("already handled above"),
-                MedallionLocation(med) => state.knowledge.dungeon_reward_locations.decrement(DungeonReward::Medallion(*med)),
+                MedallionLocation(med) => {
+                    let reward = DungeonReward::Medallion(*med);
+                    state.knowledge.dungeon_reward_locations.decrement(reward);
+                    state.knowledge.update_source(Fact::DungeonRewardLocation { reward }, Source::Manual);
+                }
                 MedallionWithLocation(med) => state.ram.save.quest_items.toggle(QuestItems::from(med)),
                 Sequence { decrement, .. } => decrement(state),
                 TrackerCellKind::SmallKeys { get, set, max_vanilla, max_mq } => {
@@ -805,7 +825,11 @@ impl TrackerCellKind {
                 }
                 Song { toggle_overlay, .. } => toggle_overlay(&mut state.ram.save.event_chk_inf),
                 Spells => state.ram.save.inv.farores_wind = !state.ram.save.inv.farores_wind,
-                StoneLocation(stone) => state.knowledge.dungeon_reward_locations.decrement(DungeonReward::Stone(*stone)),
+                StoneLocation(stone) => {
+                    let reward = DungeonReward::Stone(*stone);
+                    state.knowledge.dungeon_reward_locations.decrement(reward);
+                    state.knowledge.update_source(Fact::DungeonRewardLocation { reward }, Source::Manual);
+                }
                 StoneWithLocation(stone) => state.ram.save.quest_items.toggle(QuestItems::from(stone)),
                 FreeReward | FortressMq | Mq(_) | Simple { .. } | Stone(_) => {}
                 BigPoeTriforce | BossKey { .. } | SongCheck { .. } => unimplemented!(),
@@ -2263,6 +2287,8 @@ pub enum LocationStyle {
     Normal,
     Dimmed,
     Mq,
+    /// The location is known differently by different sources, e.g. by the two runners on a restream double cell.
+    Contradiction,
 }
 
 #[cfg(feature = "rocket")]
@@ -2272,6 +2298,7 @@ impl LocationStyle {
             Self::Normal => "loc",
             Self::Dimmed => "loc dimmed",
             Self::Mq => "loc mq",
+            Self::Contradiction => "loc contradiction",
         }
     }
 }
@@ -0,0 +1,207 @@
+//! A registry of the randomizer versions the tracker has data for, so that each session can use the logic of the version its seed was generated with.
+//!
+//! Besides the data compiled into `ootr-static`, a registry contains the bundles exported using `oottracker-export-rando-data` which are found in its directory.
+//! The directory may also contain a `channels.json` file mapping release channels (see [`Channel`]) to the versions they currently point at.
+
+use {
+    std::{
+        collections::BTreeMap,
+        fmt,
+        fs::{
+            self,
+            File,
+        },
+        io::{
+            self,
+            BufReader,
+        },
+        path::Path,
+        sync::Arc,
+    },
+    itertools::Itertools as _,
+    serde::Deserialize,
+    wheel::FromArc,
+    ootr::bundle::Bundle,
+    ootr_dynamic::{
+        BundleRando,
+        BundleRandoErr,
+    },
+    crate::ui,
+};
+
+/// A release channel of the randomizer which sessions can follow instead of a fixed version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// The latest release of the main repository.
+    Release,
+    /// The latest version of the main repository's `Dev` branch.
+    Dev,
+    /// The latest version of Roman971's `Dev-R` branch. This is what the data compiled into `ootr-static` follows.
+    DevR,
+    /// The version used by a major tournament or race format, e.g. the RSL.
+    Tournament(String),
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Release => write!(f, "latest release"),
+            Channel::Dev => write!(f, "latest Dev"),
+            Channel::DevR => write!(f, "latest Dev-R"),
+            Channel::Tournament(name) => name.fmt(f),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChannelsJson {
+    release: Option<String>,
+    dev: Option<String>,
+    dev_r: Option<String>,
+    #[serde(default)]
+    tournaments: BTreeMap<String, String>,
+}
+
+#[derive(Debug, FromArc, Clone)]
+pub enum Error {
+    Bundle {
+        path: String,
+        error: BundleRandoErr,
+    },
+    #[from_arc]
+    Io(Arc<io::Error>),
+    #[from_arc]
+    Json(Arc<serde_json::Error>),
+    MissingHomeDir,
+    Static(ootr_static::RandoErr),
+    /// `channels.json` refers to a version for which there's no bundle.
+    UnknownVersion {
+        channel: Channel,
+        version: String,
+    },
+}
+
+impl From<ui::Error> for Error {
+    fn from(e: ui::Error) -> Error {
+        match e {
+            ui::Error::Io(e) => Error::Io(e),
+            ui::Error::Json(e) => Error::Json(e),
+            ui::Error::MissingHomeDir => Error::MissingHomeDir,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Bundle { path, error } => write!(f, "error loading rando data bundle {}: {}", path, error),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Json(e) => write!(f, "error reading channels.json: {}", e),
+            Error::MissingHomeDir => write!(f, "could not find your user folder"),
+            Error::Static(e) => write!(f, "error loading built-in rando data: {}", e),
+            Error::UnknownVersion { channel, version } => write!(f, "channels.json says {} is version {}, but there's no bundle for that version", channel, version),
+        }
+    }
+}
+
+/// The randomizer versions available to a tracker, and which channels point at which version.
+#[derive(Debug, Clone)]
+pub struct Registry {
+    versions: BTreeMap<String, Arc<BundleRando>>,
+    channels: Vec<(Channel, String)>,
+    builtin_version: String,
+}
+
+impl Registry {
+    /// A registry containing only the data compiled into `ootr-static`.
+    pub fn builtin() -> Result<Registry, Error> {
+        let builtin_version = ootr_static::Rando::RANDO_VERSION.to_owned();
+        let bundle = Bundle::from_rando(&ootr_static::Rando, builtin_version.clone()).map_err(Error::Static)?;
+        let rando = BundleRando::from_bundle(bundle).map_err(|error| Error::Bundle { path: format!("(built-in)"), error })?;
+        Ok(Registry {
+            versions: BTreeMap::from([(builtin_version.clone(), Arc::new(rando))]),
+            channels: vec![(Channel::DevR, builtin_version.clone())],
+            builtin_version,
+        })
+    }
+
+    /// The built-in data plus any bundles in the `rando-versions` folder of the tracker's data directory.
+    pub fn load_user() -> Result<Registry, Error> {
+        let dir = ui::dirs()?.data_dir().join("rando-versions");
+        if dir.exists() {
+            Registry::load(dir)
+        } else {
+            Registry::builtin()
+        }
+    }
+
+    /// The built-in data plus the bundles and `channels.json` in the given directory.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Registry, Error> {
+        let mut registry = Registry::builtin()?;
+        let mut channels_path = None;
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.file_name().map_or(false, |name| name == "channels.json") {
+                channels_path = Some(path);
+            } else if path.extension().map_or(false, |ext| ext == "json") {
+                let rando = BundleRando::load(&path).map_err(|error| Error::Bundle { path: path.display().to_string(), error })?;
+                registry.versions.insert(rando.version().to_owned(), Arc::new(rando));
+            }
+        }
+        if let Some(channels_path) = channels_path {
+            let ChannelsJson { release, dev, dev_r, tournaments } = serde_json::from_reader(BufReader::new(File::open(channels_path)?))?;
+            let channels = release.map(|version| (Channel::Release, version)).into_iter()
+                .chain(dev.map(|version| (Channel::Dev, version)))
+                .chain(dev_r.map(|version| (Channel::DevR, version)))
+                .chain(tournaments.into_iter().map(|(name, version)| (Channel::Tournament(name), version)));
+            for (channel, version) in channels {
+                if !registry.versions.contains_key(&version) { return Err(Error::UnknownVersion { channel, version }) }
+                registry.channels.retain(|(iter_channel, _)| *iter_channel != channel);
+                registry.channels.push((channel, version));
+            }
+        }
+        Ok(registry)
+    }
+
+    /// The data for the given randomizer version, if the registry has it.
+    pub fn get(&self, version: &str) -> Option<&Arc<BundleRando>> {
+        self.versions.get(version)
+    }
+
+    /// The data for the version the given channel currently points at.
+    pub fn channel(&self, channel: &Channel) -> Option<&Arc<BundleRando>> {
+        self.channels.iter().find(|(iter_channel, _)| iter_channel == channel).and_then(|(_, version)| self.get(version))
+    }
+
+    /// The data compiled into `ootr-static`, used for sessions whose version is unknown.
+    pub fn builtin_rando(&self) -> &Arc<BundleRando> {
+        &self.versions[&self.builtin_version]
+    }
+
+    /// The data for the version a seed was generated with, falling back to the built-in data if the registry doesn't have that version.
+    pub fn for_seed(&self, version: &str) -> &Arc<BundleRando> {
+        self.get(version).unwrap_or_else(|| self.builtin_rando())
+    }
+
+    /// The data for the version a ROM was patched with, given the version number from the ROM header.
+    ///
+    /// The header doesn't say which branch the version is from, so this falls back to the built-in data unless the registry has exactly one matching version.
+    pub fn for_rom_version(&self, major: u8, minor: u8, patch: u8) -> &Arc<BundleRando> {
+        let base_version = format!("{}.{}.{}", major, minor, patch);
+        self.versions.iter()
+            .filter(|(version, _)| version.split(' ').next() == Some(&*base_version))
+            .exactly_one()
+            .map_or_else(|_| self.builtin_rando(), |(_, rando)| rando)
+    }
+
+    /// All versions in the registry, sorted by version string.
+    pub fn versions(&self) -> impl Iterator<Item = &str> {
+        self.versions.keys().map(String::as_str)
+    }
+
+    /// The channels known to the registry and the versions they point at.
+    pub fn channels(&self) -> &[(Channel, String)] {
+        &self.channels
+    }
+}
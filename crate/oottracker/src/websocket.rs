@@ -107,6 +107,26 @@ pub enum ClientMessage {
         kind: u16,
         target_world: NonZeroU8,
     },
+    /// Replaces the room's knowledge about settings, tricks, and starting items with the contents of the given settings string.
+    SetSettingsString {
+        room: String,
+        settings_string: String,
+    },
+    /// Uses the data for the given randomizer version for the room's logic. The version must be known to the server's version registry.
+    SetRandoVersion {
+        room: String,
+        version: String,
+    },
+    /// Sets the randomizer version used to name items and locations in the multiworld room's notes, e.g. `6.2.205 R-1`.
+    MwSetRandoVersion {
+        room: String,
+        version: String,
+    },
+    /// Uses the data for the given randomizer version for the restream's logic. The version must be known to the server's version registry.
+    SetRestreamRandoVersion {
+        restream: String,
+        version: String,
+    },
 }
 
 #[derive(Protocol)]
@@ -0,0 +1,141 @@
+//! Loading weights files for the random settings script, so that the settings' possible values are known in random settings races.
+
+use {
+    std::{
+        collections::HashSet,
+        fmt,
+        fs::File,
+        io::{
+            self,
+            BufReader,
+        },
+        path::Path,
+        sync::Arc,
+    },
+    serde_json::{
+        Map,
+        Value as Json,
+    },
+    wheel::FromArc,
+    crate::knowledge::{
+        Knowledge,
+        Source,
+    },
+};
+
+/// Top-level keys of a weights file which don't contain setting weights.
+const NON_SETTING_KEYS: [&str; 3] = ["conditionals", "multiselect", "options"];
+
+#[derive(Debug, FromArc, Clone)]
+pub enum Error {
+    #[from_arc]
+    Io(Arc<io::Error>),
+    #[from_arc]
+    Json(Arc<serde_json::Error>),
+    ValueType {
+        setting: String,
+        value: Json,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Json(e) => e.fmt(f),
+            Error::ValueType { setting, value } => write!(f, "unexpected weights for setting {}: {}", setting, value),
+        }
+    }
+}
+
+/// Reads the weights file at the given path.
+pub fn load(path: impl AsRef<Path>) -> Result<Knowledge, Error> {
+    let file = File::open(path)?;
+    from_json(serde_json::from_reader(BufReader::new(file))?)
+}
+
+/// Narrows each setting to the values which have a nonzero weight in the given weights file.
+///
+/// Bool settings where both outcomes are possible are left unknown. Conditionals and multiselects are not considered.
+pub fn from_json(json: Json) -> Result<Knowledge, Error> {
+    let value_type = |setting: &str, value: &Json| Error::ValueType { setting: setting.to_owned(), value: value.clone() };
+    let mut weights = match json {
+        Json::Object(weights) => weights,
+        _ => return Err(value_type("", &json)),
+    };
+    // newer weights files have the setting weights in a separate object
+    if let Some(Json::Object(setting_weights)) = weights.remove("weights") {
+        weights = setting_weights;
+    } else {
+        weights.retain(|key, _| !NON_SETTING_KEYS.contains(&&**key));
+    }
+    let mut knowledge = Knowledge::default();
+    for (setting, setting_weights) in weights {
+        let setting_weights = setting_weights.as_object().ok_or_else(|| value_type(&setting, &setting_weights))?;
+        let possible = possible_values(setting_weights).ok_or_else(|| value_type(&setting, &Json::Object(setting_weights.clone())))?;
+        if setting_weights.keys().all(|value| value == "true" || value == "false") {
+            match (possible.contains("true"), possible.contains("false")) {
+                (true, false) => { knowledge.bool_settings.insert(setting, true); }
+                (false, true) => { knowledge.bool_settings.insert(setting, false); }
+                (_, _) => {} // both possible (or neither, which the random settings script would reject anyway)
+            }
+        } else {
+            knowledge.string_settings.insert(setting, possible);
+        }
+    }
+    Ok(knowledge.with_source(Source::Weights))
+}
+
+/// Returns the values with nonzero weight, or `None` if a weight isn't a number.
+fn possible_values(weights: &Map<String, Json>) -> Option<HashSet<String>> {
+    let mut possible = HashSet::default();
+    for (value, weight) in weights {
+        if weight.as_f64()? > 0.0 {
+            possible.insert(value.clone());
+        }
+    }
+    Some(possible)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        collect_mac::collect,
+        serde_json::json,
+        super::{
+            Error,
+            from_json,
+        },
+    };
+
+    #[test]
+    fn legacy_format() {
+        let knowledge = from_json(json!({
+            "options": {"tricks": true},
+            "open_forest": {"open": 1, "closed_deku": 0.5, "closed": 0},
+            "shuffle_kokiri_sword": {"true": 100, "false": 0},
+            "shuffle_ocarinas": {"true": 50, "false": 50},
+        })).unwrap();
+        assert_eq!(knowledge.string_settings["open_forest"], collect![format!("open"), format!("closed_deku")]);
+        assert!(knowledge.bool_settings["shuffle_kokiri_sword"]);
+        assert!(!knowledge.bool_settings.contains_key("shuffle_ocarinas"));
+        assert!(!knowledge.string_settings.contains_key("options"));
+    }
+
+    #[test]
+    fn weights_object() {
+        let knowledge = from_json(json!({
+            "options": {"tricks": true},
+            "weights": {"shuffle_kokiri_sword": {"true": 0, "false": 1}},
+        })).unwrap();
+        assert!(!knowledge.bool_settings["shuffle_kokiri_sword"]);
+        assert_eq!(knowledge.string_settings.len(), 0);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(from_json(json!([])), Err(Error::ValueType { .. })));
+        assert!(matches!(from_json(json!({"open_forest": "open"})), Err(Error::ValueType { .. })));
+        assert!(matches!(from_json(json!({"open_forest": {"open": "lots"}})), Err(Error::ValueType { .. })));
+    }
+}